@@ -13,10 +13,24 @@ pub struct Model {
     #[sea_orm(unique)]
     pub real_path: String,
     pub obsolete: bool,
+    pub content_type: Option<String>,
+    pub hash: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub encoding: Option<String>,
+    pub size: Option<i64>,
+    pub deployment_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::deployment::Entity",
+        from = "Column::DeploymentId",
+        to = "super::deployment::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Deployment,
     #[sea_orm(
         belongs_to = "super::subdomain::Entity",
         from = "Column::SubdomainId",
@@ -27,6 +41,12 @@ pub enum Relation {
     Subdomain,
 }
 
+impl Related<super::deployment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Deployment.def()
+    }
+}
+
 impl Related<super::subdomain::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Subdomain.def()
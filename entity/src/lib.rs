@@ -2,7 +2,19 @@
 
 pub mod prelude;
 
+pub mod access_rule;
+pub mod api_token;
+pub mod certificate;
+pub mod content_type;
+pub mod credential;
+pub mod deployment;
+pub mod domain;
 pub mod file;
+pub mod header;
+pub mod login_attempt;
 pub mod origin;
+pub mod redirect;
+pub mod refresh_token;
+pub mod session;
 pub mod subdomain;
 pub mod user;
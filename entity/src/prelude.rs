@@ -1,19 +1,37 @@
 pub use super::{
-    file::Entity as FileEntity, origin::Entity as OriginEntity, subdomain::Entity as SubdomainEntity,
-    user::Entity as UserEntity,
+    access_rule::Entity as AccessRuleEntity, api_token::Entity as ApiTokenEntity,
+    certificate::Entity as CertificateEntity, content_type::Entity as ContentTypeEntity,
+    credential::Entity as CredentialEntity, deployment::Entity as DeploymentEntity, domain::Entity as DomainEntity,
+    file::Entity as FileEntity, header::Entity as HeaderEntity, login_attempt::Entity as LoginAttemptEntity,
+    origin::Entity as OriginEntity, redirect::Entity as RedirectEntity, refresh_token::Entity as RefreshTokenEntity,
+    session::Entity as SessionEntity, subdomain::Entity as SubdomainEntity, user::Entity as UserEntity,
 };
 
 pub use super::{
-    file::Model as FileModel, origin::Model as OriginModel, subdomain::Model as SubdomainModel,
-    user::Model as UserModel,
+    access_rule::Model as AccessRuleModel, api_token::Model as ApiTokenModel, certificate::Model as CertificateModel,
+    content_type::Model as ContentTypeModel, credential::Model as CredentialModel,
+    deployment::Model as DeploymentModel, domain::Model as DomainModel, file::Model as FileModel,
+    header::Model as HeaderModel, login_attempt::Model as LoginAttemptModel, origin::Model as OriginModel,
+    redirect::Model as RedirectModel, refresh_token::Model as RefreshTokenModel, session::Model as SessionModel,
+    subdomain::Model as SubdomainModel, user::Model as UserModel,
 };
 
 pub use super::{
-    file::Column as FileColumn, origin::Column as OriginColumn, subdomain::Column as SubdomainColumn,
-    user::Column as UserColumn,
+    access_rule::Column as AccessRuleColumn, api_token::Column as ApiTokenColumn,
+    certificate::Column as CertificateColumn, content_type::Column as ContentTypeColumn,
+    credential::Column as CredentialColumn, deployment::Column as DeploymentColumn, domain::Column as DomainColumn,
+    file::Column as FileColumn, header::Column as HeaderColumn, login_attempt::Column as LoginAttemptColumn,
+    origin::Column as OriginColumn, redirect::Column as RedirectColumn, refresh_token::Column as RefreshTokenColumn,
+    session::Column as SessionColumn, subdomain::Column as SubdomainColumn, user::Column as UserColumn,
 };
 
 pub use super::{
-    file::ActiveModel as FileActiveModel, origin::ActiveModel as OriginActiveModel,
+    access_rule::ActiveModel as AccessRuleActiveModel, api_token::ActiveModel as ApiTokenActiveModel,
+    certificate::ActiveModel as CertificateActiveModel, content_type::ActiveModel as ContentTypeActiveModel,
+    credential::ActiveModel as CredentialActiveModel, deployment::ActiveModel as DeploymentActiveModel,
+    domain::ActiveModel as DomainActiveModel, file::ActiveModel as FileActiveModel,
+    header::ActiveModel as HeaderActiveModel, login_attempt::ActiveModel as LoginAttemptActiveModel,
+    origin::ActiveModel as OriginActiveModel, redirect::ActiveModel as RedirectActiveModel,
+    refresh_token::ActiveModel as RefreshTokenActiveModel, session::ActiveModel as SessionActiveModel,
     subdomain::ActiveModel as SubdomainActiveModel, user::ActiveModel as UserActiveModel,
 };
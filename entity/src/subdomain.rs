@@ -14,14 +14,42 @@ pub struct Model {
     pub name: String,
     #[sea_orm(unique)]
     pub archive_path: Option<String>,
+    pub spa: bool,
+    pub trailing_slash: String,
+    pub private: bool,
+    pub share_secret: Option<String>,
+    pub rate_limit: Option<i32>,
+    pub rate_burst: Option<i32>,
+    pub blocked: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub last_deployed_at: Option<DateTimeWithTimeZone>,
+    pub active_deployment_id: Option<i64>,
+    pub activated_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::access_rule::Entity")]
+    AccessRule,
+    #[sea_orm(has_many = "super::api_token::Entity")]
+    ApiToken,
+    #[sea_orm(has_many = "super::content_type::Entity")]
+    ContentType,
+    #[sea_orm(has_many = "super::credential::Entity")]
+    Credential,
+    #[sea_orm(has_many = "super::deployment::Entity")]
+    Deployment,
+    #[sea_orm(has_many = "super::domain::Entity")]
+    Domain,
     #[sea_orm(has_many = "super::file::Entity")]
     File,
+    #[sea_orm(has_many = "super::header::Entity")]
+    Header,
     #[sea_orm(has_many = "super::origin::Entity")]
     Origin,
+    #[sea_orm(has_many = "super::redirect::Entity")]
+    Redirect,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::OwnerId",
@@ -32,18 +60,66 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::access_rule::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AccessRule.def()
+    }
+}
+
+impl Related<super::api_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApiToken.def()
+    }
+}
+
+impl Related<super::content_type::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ContentType.def()
+    }
+}
+
+impl Related<super::credential::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Credential.def()
+    }
+}
+
+impl Related<super::deployment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Deployment.def()
+    }
+}
+
+impl Related<super::domain::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Domain.def()
+    }
+}
+
 impl Related<super::file::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::File.def()
     }
 }
 
+impl Related<super::header::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Header.def()
+    }
+}
+
 impl Related<super::origin::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Origin.def()
     }
 }
 
+impl Related<super::redirect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Redirect.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
@@ -13,10 +13,17 @@ pub struct Model {
     #[sea_orm(unique)]
     pub login: String,
     pub password: String,
+    pub is_admin: bool,
+    pub suspended_at: Option<DateTimeWithTimeZone>,
+    pub max_sites: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::api_token::Entity")]
+    ApiToken,
+    #[sea_orm(has_many = "super::session::Entity")]
+    Session,
     #[sea_orm(has_many = "super::subdomain::Entity")]
     Subdomain,
 }
@@ -27,10 +34,25 @@ impl Debug for Model {
             .field("id", &self.id)
             .field("login", &self.login)
             .field("password", &"***")
+            .field("is_admin", &self.is_admin)
+            .field("suspended_at", &self.suspended_at)
+            .field("max_sites", &self.max_sites)
             .finish()
     }
 }
 
+impl Related<super::api_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApiToken.def()
+    }
+}
+
+impl Related<super::session::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Session.def()
+    }
+}
+
 impl Related<super::subdomain::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Subdomain.def()
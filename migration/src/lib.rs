@@ -4,6 +4,34 @@ mod m20230927_162921_create_users;
 mod m20230929_081415_create_subdomains;
 mod m20230929_152215_create_file;
 mod m20231105_171000_create_origin;
+mod m20241010_120000_add_file_content_type;
+mod m20241011_120000_add_file_validators;
+mod m20241012_120000_add_file_encoding;
+mod m20241013_120000_create_header;
+mod m20241014_120000_create_redirect;
+mod m20241015_120000_add_subdomain_spa;
+mod m20241016_120000_add_subdomain_trailing_slash;
+mod m20241017_120000_create_domain;
+mod m20241018_120000_create_certificate;
+mod m20241019_120000_add_certificate_managed;
+mod m20241020_120000_create_credential;
+mod m20241021_120000_add_subdomain_visibility;
+mod m20241022_120000_create_access_rule;
+mod m20241023_120000_add_subdomain_rate_limit;
+mod m20241024_120000_create_login_attempt;
+mod m20241025_120000_create_api_token;
+mod m20241026_120000_create_session;
+mod m20241027_120000_create_refresh_token;
+mod m20241028_120000_add_user_admin;
+mod m20241029_120000_add_subdomain_blocked;
+mod m20241030_120000_add_subdomain_timestamps;
+mod m20241031_120000_add_file_size;
+mod m20241101_120000_create_deployment;
+mod m20241102_120000_add_deployment_references;
+mod m20241103_120000_add_deployment_expiration;
+mod m20241104_120000_create_content_type;
+mod m20241105_120000_add_redirect_force;
+mod m20241106_120000_add_subdomain_activation;
 
 pub struct Migrator;
 
@@ -15,6 +43,34 @@ impl MigratorTrait for Migrator {
             Box::new(m20230929_081415_create_subdomains::Migration),
             Box::new(m20230929_152215_create_file::Migration),
             Box::new(m20231105_171000_create_origin::Migration),
+            Box::new(m20241010_120000_add_file_content_type::Migration),
+            Box::new(m20241011_120000_add_file_validators::Migration),
+            Box::new(m20241012_120000_add_file_encoding::Migration),
+            Box::new(m20241013_120000_create_header::Migration),
+            Box::new(m20241014_120000_create_redirect::Migration),
+            Box::new(m20241015_120000_add_subdomain_spa::Migration),
+            Box::new(m20241016_120000_add_subdomain_trailing_slash::Migration),
+            Box::new(m20241017_120000_create_domain::Migration),
+            Box::new(m20241018_120000_create_certificate::Migration),
+            Box::new(m20241019_120000_add_certificate_managed::Migration),
+            Box::new(m20241020_120000_create_credential::Migration),
+            Box::new(m20241021_120000_add_subdomain_visibility::Migration),
+            Box::new(m20241022_120000_create_access_rule::Migration),
+            Box::new(m20241023_120000_add_subdomain_rate_limit::Migration),
+            Box::new(m20241024_120000_create_login_attempt::Migration),
+            Box::new(m20241025_120000_create_api_token::Migration),
+            Box::new(m20241026_120000_create_session::Migration),
+            Box::new(m20241027_120000_create_refresh_token::Migration),
+            Box::new(m20241028_120000_add_user_admin::Migration),
+            Box::new(m20241029_120000_add_subdomain_blocked::Migration),
+            Box::new(m20241030_120000_add_subdomain_timestamps::Migration),
+            Box::new(m20241031_120000_add_file_size::Migration),
+            Box::new(m20241101_120000_create_deployment::Migration),
+            Box::new(m20241102_120000_add_deployment_references::Migration),
+            Box::new(m20241103_120000_add_deployment_expiration::Migration),
+            Box::new(m20241104_120000_create_content_type::Migration),
+            Box::new(m20241105_120000_add_redirect_force::Migration),
+            Box::new(m20241106_120000_add_subdomain_activation::Migration),
         ]
     }
 }
@@ -0,0 +1,54 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Redirect::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Redirect::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Redirect::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Redirect::Table, Redirect::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Redirect::Source).string().not_null())
+                    .col(ColumnDef::new(Redirect::Query).string())
+                    .col(ColumnDef::new(Redirect::Destination).string().not_null())
+                    .col(ColumnDef::new(Redirect::Status).integer().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Redirect::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Redirect {
+    Table,
+    Id,
+    SubdomainId,
+    Source,
+    Query,
+    Destination,
+    Status,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? One of `ignore`, `always` or `never`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .add_column(
+                        ColumnDef::new(Subdomain::TrailingSlash)
+                            .string()
+                            .not_null()
+                            .default("ignore"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .drop_column(Subdomain::TrailingSlash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Subdomain {
+    Table,
+    TrailingSlash,
+}
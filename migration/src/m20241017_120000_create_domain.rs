@@ -0,0 +1,61 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Custom domain is attached to exactly one site
+        //? and is served only after ownership was verified
+        manager
+            .create_table(
+                Table::create()
+                    .table(Domain::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Domain::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Domain::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Domain::Table, Domain::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Domain::Name).string().not_null().unique_key())
+                    .col(ColumnDef::new(Domain::Token).string().not_null())
+                    .col(ColumnDef::new(Domain::Verified).boolean().not_null().default(false))
+                    .col(
+                        ColumnDef::new(Domain::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Domain::VerifiedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Domain::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Domain {
+    Table,
+    Id,
+    SubdomainId,
+    Name,
+    Token,
+    Verified,
+    CreatedAt,
+    VerifiedAt,
+}
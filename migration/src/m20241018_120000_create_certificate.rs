@@ -0,0 +1,70 @@
+use crate::m20241017_120000_create_domain::Domain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Each custom domain has at most one certificate
+        //? which is replaced on upload
+        manager
+            .create_table(
+                Table::create()
+                    .table(Certificate::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Certificate::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Certificate::DomainId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Certificate::Table, Certificate::DomainId)
+                            .to(Domain::Table, Domain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Certificate::Chain).text().not_null())
+                    .col(ColumnDef::new(Certificate::PrivateKey).text().not_null())
+                    .col(
+                        ColumnDef::new(Certificate::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Certificate::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Certificate::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Certificate {
+    Table,
+    Id,
+    DomainId,
+    Chain,
+    PrivateKey,
+    ExpiresAt,
+    CreatedAt,
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Managed certificates were issued with ACME and are renewed automatically
+        //? Uploaded certificates are never replaced by renewal task
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Certificate::Table)
+                    .add_column(ColumnDef::new(Certificate::Managed).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Certificate::Table)
+                    .drop_column(Certificate::Managed)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Certificate {
+    Table,
+    Managed,
+}
@@ -0,0 +1,57 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Site with at least one credential requires basic auth
+        manager
+            .create_table(
+                Table::create()
+                    .table(Credential::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Credential::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Credential::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Credential::Table, Credential::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Credential::Username).string().not_null())
+                    .col(ColumnDef::new(Credential::Password).string().not_null())
+                    .index(
+                        Index::create()
+                            .col(Credential::SubdomainId)
+                            .col(Credential::Username)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Credential::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Credential {
+    Table,
+    Id,
+    SubdomainId,
+    Username,
+    Password,
+}
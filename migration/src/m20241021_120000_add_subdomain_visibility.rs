@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Private site is served only with a signed share link
+        //? Secret is generated when the first link is minted
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .add_column(ColumnDef::new(Subdomain::Private).boolean().not_null().default(false))
+                    .add_column(ColumnDef::new(Subdomain::ShareSecret).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .drop_column(Subdomain::Private)
+                    .drop_column(Subdomain::ShareSecret)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Subdomain {
+    Table,
+    Private,
+    ShareSecret,
+}
@@ -0,0 +1,52 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Network is stored in CIDR notation
+        //? Action is one of `allow` or `deny`
+        manager
+            .create_table(
+                Table::create()
+                    .table(AccessRule::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AccessRule::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AccessRule::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AccessRule::Table, AccessRule::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(AccessRule::Network).string().not_null())
+                    .col(ColumnDef::new(AccessRule::Action).string().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AccessRule::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AccessRule {
+    Table,
+    Id,
+    SubdomainId,
+    Network,
+    Action,
+}
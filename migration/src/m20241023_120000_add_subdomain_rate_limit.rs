@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Overrides of server wide page limit in requests per minute
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .add_column(ColumnDef::new(Subdomain::RateLimit).integer().null())
+                    .add_column(ColumnDef::new(Subdomain::RateBurst).integer().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .drop_column(Subdomain::RateLimit)
+                    .drop_column(Subdomain::RateBurst)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Subdomain {
+    Table,
+    RateLimit,
+    RateBurst,
+}
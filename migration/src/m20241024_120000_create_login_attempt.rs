@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Failed logins are counted per login and per client address
+        //? Kind is one of `login` or `address`
+        manager
+            .create_table(
+                Table::create()
+                    .table(LoginAttempt::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(LoginAttempt::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(LoginAttempt::Kind).string().not_null())
+                    .col(ColumnDef::new(LoginAttempt::Subject).string().not_null())
+                    .col(ColumnDef::new(LoginAttempt::Failures).integer().not_null().default(0))
+                    .col(ColumnDef::new(LoginAttempt::LockedUntil).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(LoginAttempt::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .index(
+                        Index::create()
+                            .col(LoginAttempt::Kind)
+                            .col(LoginAttempt::Subject)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LoginAttempt::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoginAttempt {
+    Table,
+    Id,
+    Kind,
+    Subject,
+    Failures,
+    LockedUntil,
+    UpdatedAt,
+}
@@ -0,0 +1,74 @@
+use crate::{m20230927_162921_create_users::User, m20230929_081415_create_subdomains::Subdomain};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Only sha256 of the token is stored
+        //? Scopes are stored comma separated
+        //? Token can be restricted to a single site of the owner
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ApiToken::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ApiToken::OwnerId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ApiToken::Table, ApiToken::OwnerId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(ApiToken::Name).string().not_null())
+                    .col(ColumnDef::new(ApiToken::TokenHash).string().not_null().unique_key())
+                    .col(ColumnDef::new(ApiToken::Scopes).string().not_null())
+                    .col(ColumnDef::new(ApiToken::SubdomainId).big_integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ApiToken::Table, ApiToken::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(ApiToken::ExpiresAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(ApiToken::LastUsedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(ApiToken::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ApiToken {
+    Table,
+    Id,
+    OwnerId,
+    Name,
+    TokenHash,
+    Scopes,
+    SubdomainId,
+    ExpiresAt,
+    LastUsedAt,
+    CreatedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Suspended users can not login or use their tokens
+        //? Max sites overrides server wide limit for this user
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(ColumnDef::new(User::IsAdmin).boolean().not_null().default(false))
+                    .add_column(ColumnDef::new(User::SuspendedAt).timestamp_with_time_zone().null())
+                    .add_column(ColumnDef::new(User::MaxSites).big_integer().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::IsAdmin)
+                    .drop_column(User::SuspendedAt)
+                    .drop_column(User::MaxSites)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    IsAdmin,
+    SuspendedAt,
+    MaxSites,
+}
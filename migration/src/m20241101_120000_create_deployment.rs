@@ -0,0 +1,61 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Every upload creates a deployment
+        //? Settings from sero.toml are kept to be restored on rollback
+        manager
+            .create_table(
+                Table::create()
+                    .table(Deployment::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Deployment::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Deployment::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Deployment::Table, Deployment::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Deployment::ArchivePath).string())
+                    .col(ColumnDef::new(Deployment::Spa).boolean())
+                    .col(ColumnDef::new(Deployment::TrailingSlash).string())
+                    .col(
+                        ColumnDef::new(Deployment::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Deployment::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Deployment {
+    Table,
+    Id,
+    SubdomainId,
+    ArchivePath,
+    Spa,
+    TrailingSlash,
+    CreatedAt,
+}
@@ -0,0 +1,184 @@
+use crate::m20241101_120000_create_deployment::Deployment;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Files are kept when deployment is removed
+        //? as they are marked obsolete and removed by cleanup task
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(File::Table)
+                    .add_column(ColumnDef::new(File::DeploymentId).big_integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-file-deployment_id")
+                            .from_tbl(File::Table)
+                            .from_col(File::DeploymentId)
+                            .to_tbl(Deployment::Table)
+                            .to_col(Deployment::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        //? Rules are parsed from archive so they belong to its deployment
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Header::Table)
+                    .add_column(ColumnDef::new(Header::DeploymentId).big_integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-header-deployment_id")
+                            .from_tbl(Header::Table)
+                            .from_col(Header::DeploymentId)
+                            .to_tbl(Deployment::Table)
+                            .to_col(Deployment::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Redirect::Table)
+                    .add_column(ColumnDef::new(Redirect::DeploymentId).big_integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-redirect-deployment_id")
+                            .from_tbl(Redirect::Table)
+                            .from_col(Redirect::DeploymentId)
+                            .to_tbl(Deployment::Table)
+                            .to_col(Deployment::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        //? Site without active deployment has nothing to serve
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .add_column(ColumnDef::new(Subdomain::ActiveDeploymentId).big_integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-subdomain-active_deployment_id")
+                            .from_tbl(Subdomain::Table)
+                            .from_col(Subdomain::ActiveDeploymentId)
+                            .to_tbl(Deployment::Table)
+                            .to_col(Deployment::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        //? Already uploaded sites become their first deployment
+        let connection = manager.get_connection();
+
+        connection
+            .execute_unprepared(
+                "INSERT INTO deployment (subdomain_id, archive_path, created_at) \
+                 SELECT id, archive_path, COALESCE(last_deployed_at, created_at) FROM subdomain \
+                 WHERE archive_path IS NOT NULL",
+            )
+            .await?;
+
+        connection
+            .execute_unprepared(
+                "UPDATE subdomain SET active_deployment_id = deployment.id \
+                 FROM deployment WHERE deployment.subdomain_id = subdomain.id",
+            )
+            .await?;
+
+        connection
+            .execute_unprepared(
+                "UPDATE file SET deployment_id = subdomain.active_deployment_id \
+                 FROM subdomain WHERE file.subdomain_id = subdomain.id AND NOT file.obsolete",
+            )
+            .await?;
+
+        for table in ["header", "redirect"] {
+            connection
+                .execute_unprepared(&format!(
+                    "UPDATE {table} SET deployment_id = subdomain.active_deployment_id \
+                     FROM subdomain WHERE {table}.subdomain_id = subdomain.id"
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subdomain::Table)
+                    .drop_column(Subdomain::ActiveDeploymentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Redirect::Table)
+                    .drop_column(Redirect::DeploymentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Header::Table)
+                    .drop_column(Header::DeploymentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(File::Table)
+                    .drop_column(File::DeploymentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum File {
+    Table,
+    DeploymentId,
+}
+
+#[derive(DeriveIden)]
+enum Header {
+    Table,
+    DeploymentId,
+}
+
+#[derive(DeriveIden)]
+enum Redirect {
+    Table,
+    DeploymentId,
+}
+
+#[derive(DeriveIden)]
+enum Subdomain {
+    Table,
+    ActiveDeploymentId,
+}
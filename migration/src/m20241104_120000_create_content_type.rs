@@ -0,0 +1,58 @@
+use crate::m20230929_081415_create_subdomains::Subdomain;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Overrides Content-Type guessed from the extension
+        //? Extension is stored lowercase without leading dot
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContentType::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ContentType::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ContentType::SubdomainId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ContentType::Table, ContentType::SubdomainId)
+                            .to(Subdomain::Table, Subdomain::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(ContentType::Extension).string().not_null())
+                    .col(ColumnDef::new(ContentType::Value).string().not_null())
+                    .index(
+                        Index::create()
+                            .col(ContentType::SubdomainId)
+                            .col(ContentType::Extension)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ContentType::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ContentType {
+    Table,
+    Id,
+    SubdomainId,
+    Extension,
+    Value,
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //? Forced rule is applied even if requested file exists
+        //? Flag of rules saved before was not stored so they are not forced
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Redirect::Table)
+                    .add_column(ColumnDef::new(Redirect::Force).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Redirect::Table)
+                    .drop_column(Redirect::Force)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Redirect {
+    Table,
+    Force,
+}
@@ -0,0 +1,30 @@
+use crate::{services::access::error::ServiceError as AccessServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum AddAccessRuleError {
+    #[error(transparent)]
+    AccessServiceError(#[from] AccessServiceError),
+}
+
+impl From<AddAccessRuleError> for StatusCode {
+    fn from(value: AddAccessRuleError) -> Self {
+        match value {
+            AddAccessRuleError::AccessServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for AddAccessRuleError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
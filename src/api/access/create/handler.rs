@@ -0,0 +1,74 @@
+use super::{error::AddAccessRuleError, request::AddAccessRuleRequest, response::AddAccessRuleResponse};
+use crate::{extractors::*, services::access::service::Service as AccessService, state::State as AppState};
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+/// Adds a new access rule to a specified subdomain.
+///
+/// Clients from denied networks always get 403. Once there is at least one allow rule
+/// only clients from allowed networks are served. Address of the client is taken
+/// from `X-Forwarded-For` only if the request came through one of trusted proxies.
+#[utoipa::path(
+    post,
+    tag = "Access Rules Management",
+    operation_id = "Create access rule",
+    path = "/api/access",
+    request_body = AddAccessRuleRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 201, description = "The access rule was successfully added.",                                             body = AddAccessRuleResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or the network is invalid.",                      body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<AddAccessRuleRequest>,
+) -> Result<impl IntoResponse, AddAccessRuleError> {
+    tracing::trace!(
+        %payload.network,
+        ?payload.action,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Adding access rule for subdomain...",
+    );
+
+    let added_rule =
+        AccessService::add_rule_for(subdomain.id, payload.network, payload.action, state.connection()).await?;
+    tracing::trace!(
+        ?added_rule,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Access rule was successfully added to subdomain!",
+    );
+
+    let id = added_rule.id;
+    let network = added_rule.network;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/access/{id}"))],
+        Json(AddAccessRuleResponse {
+            id,
+            network,
+            action: payload.action,
+        }),
+    ))
+}
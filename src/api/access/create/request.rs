@@ -0,0 +1,11 @@
+use crate::services::access::models::AccessAction;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"network": "203.0.113.0/24", "action": "allow"}))]
+pub struct AddAccessRuleRequest {
+    /// Network in CIDR notation or a single IP address
+    pub network: String,
+    pub action: AccessAction,
+}
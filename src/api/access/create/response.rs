@@ -0,0 +1,15 @@
+use crate::services::access::models::AccessAction;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"id" : 42, "network": "203.0.113.0/24", "action": "allow"}))]
+pub struct AddAccessRuleResponse {
+    /// Automatically generated id for new rule
+    /// This can be used for further management
+    pub id: i64,
+    /// Normalized network of the rule
+    /// Single address is stored as network of one host
+    pub network: String,
+    pub action: AccessAction,
+}
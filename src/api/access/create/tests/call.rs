@@ -0,0 +1,45 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            access::create::{request::AddAccessRuleRequest, response::AddAccessRuleResponse},
+            tests::post,
+        },
+        services::access::models::AccessAction,
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn create<T, S, N>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        network: N,
+        action: AccessAction,
+    ) -> Result<AddAccessRuleResponse, (StatusCode, Details)>
+    where
+        T: AsRef<str> + Display,
+        S: AsRef<str> + Display,
+        N: Into<String>,
+    {
+        let rule = AddAccessRuleRequest {
+            network: network.into(),
+            action,
+        };
+
+        let response = post(client, "/api/access", Some(rule))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
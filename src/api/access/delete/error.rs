@@ -0,0 +1,34 @@
+use crate::{services::access::error::ServiceError as AccessServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DeleteAccessRuleError {
+    #[error(transparent)]
+    AccessServiceError(#[from] AccessServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<DeleteAccessRuleError> for StatusCode {
+    fn from(value: DeleteAccessRuleError) -> Self {
+        match value {
+            DeleteAccessRuleError::AccessServiceError(error) => Self::from(error),
+            DeleteAccessRuleError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DeleteAccessRuleError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
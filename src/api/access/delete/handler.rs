@@ -0,0 +1,64 @@
+use super::error::DeleteAccessRuleError;
+use crate::{extractors::*, services::access::service::Service as AccessService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Delete access rule by id for specified subdomain.
+///
+/// The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    delete,
+    tag = "Access Rules Management",
+    operation_id = "Delete access rule by id",
+    path = "/api/access/{id}",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the access rule to delete"),
+    ),
+    responses(
+        (status = 204, description = "Access rule was successfully deleted for subdomain."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                         body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                  body = Details),
+        (status = 403, description = "Forbidden: The rule is owned by another user.",                                               body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or rule was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                     body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(rule_id): Path<i64>,
+) -> Result<impl IntoResponse, DeleteAccessRuleError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(
+        %rule_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Deleting access rule for subdomain...",
+    );
+
+    let rows_affected = AccessService::delete_rule_of(subdomain.id, rule_id, &transaction).await?;
+
+    tracing::trace!(
+        %rule_id,
+        %subdomain.name,
+        %subdomain.id,
+        %rows_affected,
+        %user.id,
+        "Access rule was successfully deleted for subdomain. Committing changes...",
+    );
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
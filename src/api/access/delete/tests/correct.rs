@@ -0,0 +1,80 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            access::{create::tests::call::tests::create, delete::tests::call::tests::delete},
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+        services::access::models::AccessAction,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::net::SocketAddr;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app.into_make_service_with_connect_info::<SocketAddr>())
+            .expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Test client connects from loopback
+        let create_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "127.0.0.0/8",
+            AccessAction::Deny,
+        )
+        .await
+        .expect("Failed to create access rule!");
+
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+
+        let delete_response = delete(&client, &first_user_token, &first_random_subdomain, create_response.id).await;
+        assert!(delete_response.is_ok());
+
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+    }
+}
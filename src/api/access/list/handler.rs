@@ -0,0 +1,54 @@
+use super::{error::ListAccessRulesError, response::ListAccessRulesResponse};
+use crate::{extractors::*, services::access::service::Service as AccessService, state::State as AppState};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List all access rules for specified subdomain.
+///
+/// This endpoint allows users to list networks which are allowed or denied
+/// to reach their specified subdomains. The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    get,
+    tag = "Access Rules Management",
+    operation_id = "Get all access rules",
+    path = "/api/access",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 200, description = "Access rules were successfully retrieved for subdomain.",                             body = ListAccessRulesResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<impl IntoResponse, ListAccessRulesError> {
+    tracing::trace!(
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Retrieving access rules list for subdomain...",
+    );
+
+    let rules = AccessService::retrieve_rules_for(subdomain.id, state.connection()).await?;
+
+    tracing::trace!(
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        amount = rules.len(),
+        "Access rules list was successfully retrieved!",
+    );
+
+    Ok(Json(ListAccessRulesResponse { rules }))
+}
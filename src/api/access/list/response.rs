@@ -0,0 +1,10 @@
+use entity::prelude::AccessRuleModel;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"rules": [{"id": 42, "subdomain_id": 1, "network": "203.0.113.0/24", "action": "allow"}]}))]
+pub struct ListAccessRulesResponse {
+    /// List of retrieved rules in order of creation
+    pub rules: Vec<AccessRuleModel>,
+}
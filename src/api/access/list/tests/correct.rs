@@ -0,0 +1,78 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            access::{create::tests::call::tests::create, list::tests::call::tests::list},
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+        services::access::models::AccessAction,
+    };
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let list_response = list(&client, &first_user_token, &first_random_subdomain).await;
+        assert!(list_response.is_ok_and(|response| response.rules.is_empty()));
+
+        for (network, action) in [("10.0.0.0/8", AccessAction::Allow), ("10.6.6.6/32", AccessAction::Deny)] {
+            let create_response = create(&client, &first_user_token, &first_random_subdomain, network, action).await;
+            assert!(create_response.is_ok());
+        }
+
+        //* Rules are listed in order of creation
+        let rules = list(&client, &first_user_token, &first_random_subdomain)
+            .await
+            .expect("Failed to list access rules!")
+            .rules;
+        assert_eq!(
+            rules
+                .iter()
+                .map(|rule| (rule.network.as_str(), rule.action.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("10.0.0.0/8", "allow"), ("10.6.6.6/32", "deny")]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::state::State as AppState;
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod purge;
+pub mod retrieve;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(create::handler::implementation))
+        .route("/", get(list::handler::implementation))
+        .route("/", delete(purge::handler::implementation))
+        .route("/:id", delete(delete::handler::implementation))
+        .route("/:id", get(retrieve::handler::implementation))
+}
+
+// We need this as utoipa
+// currently does not support types
+// from external crates
+#[derive(ToSchema)]
+#[schema(as = AccessRuleModel)]
+pub struct AccessRuleModelSchema {
+    pub id: i64,
+    pub subdomain_id: i64,
+    /// Network in CIDR notation
+    pub network: String,
+    /// One of `allow` or `deny`
+    pub action: String,
+}
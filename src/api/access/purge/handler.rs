@@ -0,0 +1,52 @@
+use super::error::DeleteAccessRulesError;
+use crate::{extractors::*, services::access::service::Service as AccessService, state::State as AppState};
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+/// Delete all access rules for specified subdomain.
+///
+/// The site becomes reachable from any network. The action is authenticated using a JWT,
+/// and the subdomain must be owned by the user making the request.
+#[utoipa::path(
+    delete,
+    tag = "Access Rules Management",
+    operation_id = "Delete all access rules",
+    path = "/api/access",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 204, description = "Access rules were successfully deleted for subdomain."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<impl IntoResponse, DeleteAccessRulesError> {
+    tracing::trace!(
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Deleting all access rules for subdomain...",
+    );
+
+    let rows_affected = AccessService::delete_rules_for(subdomain.id, state.connection()).await?;
+    tracing::trace!(
+        %rows_affected,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Access rules were successfully deleted!",
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
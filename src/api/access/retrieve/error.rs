@@ -0,0 +1,30 @@
+use crate::{services::access::error::ServiceError as AccessServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetAccessRuleError {
+    #[error(transparent)]
+    AccessServiceError(#[from] AccessServiceError),
+}
+
+impl From<GetAccessRuleError> for StatusCode {
+    fn from(value: GetAccessRuleError) -> Self {
+        match value {
+            GetAccessRuleError::AccessServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for GetAccessRuleError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
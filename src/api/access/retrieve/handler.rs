@@ -0,0 +1,58 @@
+use super::{error::GetAccessRuleError, response::GetAccessRuleResponse};
+use crate::{extractors::*, services::access::service::Service as AccessService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+/// Get specified access rule [by id] for specified subdomain.
+///
+/// The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    get,
+    tag = "Access Rules Management",
+    operation_id = "Get access rule by id",
+    path = "/api/access/{id}",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the access rule to retrieve"),
+    ),
+    responses(
+        (status = 200, description = "Access rule was successfully retrieved.",                                                     body = GetAccessRuleResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                         body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                  body = Details),
+        (status = 403, description = "Forbidden: The subdomain or rule is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or rule was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                     body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(rule_id): Path<i64>,
+) -> Result<impl IntoResponse, GetAccessRuleError> {
+    tracing::trace!(
+        %rule_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Retrieving access rule by id for subdomain...",
+    );
+    let rule = AccessService::fetch_rule_of(subdomain.id, rule_id, state.connection()).await?;
+    tracing::trace!(
+        ?rule,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Access rule was successfully retrieved by id",
+    );
+
+    Ok(Json(GetAccessRuleResponse { rule }))
+}
@@ -0,0 +1,9 @@
+use entity::access_rule::Model as AccessRuleModel;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"rule": {"id": 42, "subdomain_id": 1, "network": "203.0.113.0/24", "action": "allow"}}))]
+pub struct GetAccessRuleResponse {
+    pub rule: AccessRuleModel,
+}
@@ -0,0 +1,19 @@
+use crate::state::State as AppState;
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// Answers HTTP-01 challenges of certificates being issued
+#[tracing::instrument(skip(state))]
+pub async fn implementation(State(state): State<Arc<AppState>>, Path(token): Path<String>) -> Response {
+    match state.challenges().key_authorization(&token) {
+        Some(key_authorization) => {
+            tracing::trace!("Answering ACME challenge");
+            ([(header::CONTENT_TYPE, "application/octet-stream")], key_authorization).into_response()
+        }
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
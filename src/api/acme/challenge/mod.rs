@@ -0,0 +1,3 @@
+pub mod handler;
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::services::acme::challenges::{Challenges, MAX_RETRY_AFTER, RETRY_AFTER};
+
+    #[test]
+    fn backoff() {
+        let challenges = Challenges::default();
+        let name = "failing.example.com";
+
+        //* Name which has never failed
+        assert!(challenges.can_order(name));
+
+        //* Failed order is postponed and delay is doubled
+        assert_eq!(challenges.order_failed(name), RETRY_AFTER);
+        assert!(!challenges.can_order(name));
+        assert!(challenges.can_order("another.example.com"));
+
+        assert_eq!(challenges.order_failed(name), RETRY_AFTER * 2);
+
+        //* Up to a day
+        for _ in 0..10 {
+            challenges.order_failed(name);
+        }
+        assert_eq!(challenges.order_failed(name), MAX_RETRY_AFTER);
+
+        //* Success forgets failures
+        challenges.order_succeeded(name);
+        assert!(challenges.can_order(name));
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use crate::{api::tests::get, app};
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let token = Uuid::new_v4().simple().to_string();
+        let key_authorization = format!("{token}.thumbprint");
+
+        //* Unknown token
+        let unknown_challenge_response = get(&client, format!("/.well-known/acme-challenge/{token}")).await;
+        assert_eq!(unknown_challenge_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Pending challenge is answered with key authorization
+        state.challenges().insert(&token, &key_authorization);
+
+        let challenge_response = get(&client, format!("/.well-known/acme-challenge/{token}")).await;
+        assert_eq!(challenge_response.status_code(), StatusCode::OK);
+        assert_eq!(challenge_response.text(), key_authorization);
+
+        //* Challenge is not answered after validation
+        state.challenges().remove(&token);
+
+        let removed_challenge_response = get(&client, format!("/.well-known/acme-challenge/{token}")).await;
+        assert_eq!(removed_challenge_response.status_code(), StatusCode::NOT_FOUND);
+    }
+}
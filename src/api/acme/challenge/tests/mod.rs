@@ -0,0 +1,3 @@
+pub mod backoff;
+pub mod correct;
+pub mod pebble;
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::{app, services::acme::service::Service as AcmeService, tls::certificate};
+    use uuid::Uuid;
+
+    //? Requires local Pebble instance which does not validate challenges:
+    //?   docker run -e PEBBLE_VA_ALWAYS_VALID=1 -p 14000:14000 ghcr.io/letsencrypt/pebble
+    //? Pebble certificate authority must be trusted with SSL_CERT_FILE
+    //?   PEBBLE_DIRECTORY=https://localhost:14000/dir SSL_CERT_FILE=pebble.minica.pem cargo test -- --ignored
+    #[tokio::test]
+    #[ignore = "requires Pebble"]
+    async fn pebble() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let directory = std::env::var("PEBBLE_DIRECTORY").expect("PEBBLE_DIRECTORY must be set!");
+
+        let (_, state) = app().await.expect("Failed to initialize application!");
+
+        let account = AcmeService::account(&state, &directory)
+            .await
+            .expect("Failed to register account!");
+
+        //* Account credentials are reused
+        AcmeService::account(&state, &directory)
+            .await
+            .expect("Failed to load account!");
+
+        //* Issued certificate is valid for requested name
+        let name = format!("{}.example.com", Uuid::new_v4());
+        let (chain, private_key) = AcmeService::issue(&account, &[name.clone()], state.challenges())
+            .await
+            .expect("Failed to issue certificate!");
+
+        let parsed = certificate::parse(chain, private_key).expect("Issued certificate must be valid!");
+        assert!(parsed.covers(&name));
+
+        //* Base domain certificate is stored and not issued again until it is due
+        let issued = AcmeService::renew_base_domain(&state, &account, "sero.test")
+            .await
+            .expect("Failed to issue certificate for base domain!");
+        let loaded = AcmeService::renew_base_domain(&state, &account, "sero.test")
+            .await
+            .expect("Failed to load certificate for base domain!");
+        assert!(issued.covers("sero.test"));
+        assert_eq!(issued.not_after, loaded.not_after);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::admin::error::ServiceError as AdminServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DisableSiteError {
+    #[error(transparent)]
+    AdminServiceError(#[from] AdminServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<DisableSiteError> for StatusCode {
+    fn from(value: DisableSiteError) -> Self {
+        match value {
+            DisableSiteError::AdminServiceError(error) => Self::from(error),
+            DisableSiteError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DisableSiteError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,46 @@
+use super::error::DisableSiteError;
+use crate::{extractors::*, services::admin::service::Service as AdminService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Force disable any site.
+///
+/// Blocked site can not be enabled by its owner until it is enabled here.
+#[utoipa::path(
+    patch,
+    tag = "Administration",
+    operation_id = "Disable any site",
+    path = "/api/admin/sites/{id}/disable",
+    params(
+        ("id" = i64, Path, description = "Id of the site to disable"),
+    ),
+    responses(
+        (status = 204, description = "Site was successfully disabled."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 404, description = "Not Found: The site was not found.",                                  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+    Path(subdomain_id): Path<i64>,
+) -> Result<impl IntoResponse, DisableSiteError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%admin.id, %subdomain_id, "Disabling site...");
+
+    let subdomain = AdminService::block_site(subdomain_id, true, &transaction).await?;
+
+    tracing::trace!(%admin.id, %subdomain.id, %subdomain.name, "Site was successfully disabled. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,34 @@
+use crate::{services::admin::error::ServiceError as AdminServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum EnableSiteError {
+    #[error(transparent)]
+    AdminServiceError(#[from] AdminServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<EnableSiteError> for StatusCode {
+    fn from(value: EnableSiteError) -> Self {
+        match value {
+            EnableSiteError::AdminServiceError(error) => Self::from(error),
+            EnableSiteError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for EnableSiteError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,46 @@
+use super::error::EnableSiteError;
+use crate::{extractors::*, services::admin::service::Service as AdminService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Enable site disabled by administrator.
+///
+/// Owner can disable and enable the site again as usual.
+#[utoipa::path(
+    patch,
+    tag = "Administration",
+    operation_id = "Enable any site",
+    path = "/api/admin/sites/{id}/enable",
+    params(
+        ("id" = i64, Path, description = "Id of the site to enable"),
+    ),
+    responses(
+        (status = 204, description = "Site was successfully enabled."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 404, description = "Not Found: The site was not found.",                                  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+    Path(subdomain_id): Path<i64>,
+) -> Result<impl IntoResponse, EnableSiteError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%admin.id, %subdomain_id, "Enabling site...");
+
+    let subdomain = AdminService::block_site(subdomain_id, false, &transaction).await?;
+
+    tracing::trace!(%admin.id, %subdomain.id, %subdomain.name, "Site was successfully enabled. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod handler;
+#[cfg(test)]
+pub mod tests;
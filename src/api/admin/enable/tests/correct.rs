@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            admin::{
+                disable::tests::call::tests::disable_site, enable::tests::call::tests::enable_site,
+                sites::tests::call::tests::sites, tests::admin_token,
+            },
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                disable::tests::call::tests::disable, enable::tests::call::tests::enable,
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let admin_token = admin_token(&client).await;
+
+        let first_site_id = sites(&client, &admin_token)
+            .await
+            .expect("Failed to list sites!")
+            .sites
+            .into_iter()
+            .find(|site| site.name == first_random_subdomain)
+            .expect("Uploaded site is missing!")
+            .id;
+
+        disable_site(&client, first_site_id, &admin_token)
+            .await
+            .expect("Failed to disable site!");
+
+        //* Regular users are not allowed to unblock sites
+        let forbidden_enable_response = enable_site(&client, first_site_id, &first_user_token).await;
+        assert!(forbidden_enable_response.is_err());
+        assert!(enable(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .is_err());
+
+        enable_site(&client, first_site_id, &admin_token)
+            .await
+            .expect("Failed to enable site!");
+
+        let enabled_site = sites(&client, &admin_token)
+            .await
+            .expect("Failed to list sites!")
+            .sites
+            .into_iter()
+            .find(|site| site.id == first_site_id)
+            .expect("Enabled site is missing!");
+        assert!(!enabled_site.blocked);
+        assert!(enabled_site.enabled);
+
+        //* Owner manages the site as usual again
+        assert!(disable(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .is_ok());
+        assert!(enable(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .is_ok());
+
+        //* Unknown site
+        let unknown_enable_response = enable_site(&client, i64::MAX, &admin_token).await;
+        assert_eq!(
+            unknown_enable_response.expect_err("Unknown site was enabled!").0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
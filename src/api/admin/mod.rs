@@ -0,0 +1,95 @@
+use crate::state::State as AppState;
+use axum::{
+    routing::{delete, get, patch},
+    Router,
+};
+use chrono::{DateTime, FixedOffset};
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub mod disable;
+pub mod enable;
+pub mod sites;
+pub mod teardown;
+pub mod update;
+pub mod users;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/users", get(users::handler::implementation))
+        .route("/users/:id", patch(update::handler::implementation))
+        .route("/sites", get(sites::handler::implementation))
+        .route("/sites/:id", delete(teardown::handler::implementation))
+        .route("/sites/:id/disable", patch(disable::handler::implementation))
+        .route("/sites/:id/enable", patch(enable::handler::implementation))
+}
+
+/// User as seen by administrator
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct UserResponse {
+    pub id: i64,
+    pub login: String,
+    pub admin: bool,
+    /// Suspended users can not login or use their tokens
+    pub suspended_at: Option<DateTime<FixedOffset>>,
+    /// Overrides `MAX_SITES_PER_USER` for this user
+    pub max_sites: Option<u64>,
+}
+
+impl From<UserModel> for UserResponse {
+    fn from(user: UserModel) -> Self {
+        Self {
+            id: user.id,
+            login: user.login,
+            admin: user.is_admin,
+            suspended_at: user.suspended_at,
+            max_sites: user.max_sites.map(|max_sites| max_sites.max(0) as u64),
+        }
+    }
+}
+
+/// Site of any user as seen by administrator
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct SiteResponse {
+    pub id: i64,
+    pub name: String,
+    pub owner_id: i64,
+    pub owner_login: Option<String>,
+    pub enabled: bool,
+    /// Site was disabled by administrator and can not be enabled by owner
+    pub blocked: bool,
+}
+
+impl From<(SubdomainModel, Option<UserModel>)> for SiteResponse {
+    fn from((subdomain, owner): (SubdomainModel, Option<UserModel>)) -> Self {
+        Self {
+            id: subdomain.id,
+            name: subdomain.name,
+            owner_id: subdomain.owner_id,
+            owner_login: owner.map(|owner| owner.login),
+            enabled: subdomain.enabled,
+            blocked: subdomain.blocked,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::api::auth::login::{request::LoginRequest, tests::call::test::login};
+    use axum_test::TestServer as TestClient;
+
+    /// Logs in as administrator bootstrapped from `.env.tests`
+    pub async fn admin_token(client: &TestClient) -> String {
+        let admin_login_request = LoginRequest {
+            login: std::env::var("ADMIN_LOGIN").expect("ADMIN_LOGIN is not set!"),
+            password: std::env::var("ADMIN_PASSWORD").expect("ADMIN_PASSWORD is not set!"),
+        };
+
+        login(client, &admin_login_request)
+            .await
+            .expect("Failed to login as administrator!")
+            .token
+    }
+}
@@ -0,0 +1,39 @@
+use super::{error::ListSitesError, response::ListSitesResponse};
+use crate::{
+    api::admin::SiteResponse, extractors::*, services::admin::service::Service as AdminService,
+    state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List sites of all users.
+///
+/// Available only to administrators.
+#[utoipa::path(
+    get,
+    tag = "Administration",
+    operation_id = "Get all sites",
+    path = "/api/admin/sites",
+    responses(
+        (status = 200, description = "Sites were successfully retrieved.",                                   body = ListSitesResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+) -> Result<impl IntoResponse, ListSitesError> {
+    tracing::trace!(%admin.id, "Retrieving sites list...");
+
+    let sites = AdminService::retrieve_sites(state.connection()).await?;
+
+    tracing::trace!(%admin.id, amount = sites.len(), "Sites list was successfully retrieved!");
+
+    Ok(Json(ListSitesResponse {
+        sites: sites.into_iter().map(SiteResponse::from).collect(),
+    }))
+}
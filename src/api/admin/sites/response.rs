@@ -0,0 +1,10 @@
+use crate::api::admin::SiteResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"sites": [{"id": 42, "name": "docs", "owner_id": 7, "owner_login": "someone", "enabled": true, "blocked": false}]}))]
+pub struct ListSitesResponse {
+    /// Sites of every user in order of creation
+    pub sites: Vec<SiteResponse>,
+}
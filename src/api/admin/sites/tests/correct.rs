@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            admin::{sites::tests::call::tests::sites, tests::admin_token},
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Regular users are not allowed to see sites of others
+        let forbidden_sites_response = sites(&client, &first_user_token).await;
+        assert_eq!(
+            forbidden_sites_response
+                .expect_err("Regular user was able to list sites!")
+                .0,
+            StatusCode::FORBIDDEN
+        );
+
+        let admin_token = admin_token(&client).await;
+
+        let sites_response = sites(&client, &admin_token).await.expect("Failed to list sites!");
+        let first_site = sites_response
+            .sites
+            .iter()
+            .find(|site| site.name == first_random_subdomain)
+            .expect("Uploaded site is missing!");
+        assert_eq!(first_site.owner_login, Some(first_user_login.to_string()));
+        assert!(first_site.enabled);
+        assert!(!first_site.blocked);
+    }
+}
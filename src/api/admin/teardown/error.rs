@@ -0,0 +1,40 @@
+use crate::{
+    services::{admin::error::ServiceError as AdminServiceError, domain::error::ServiceError as DomainServiceError},
+    Details,
+};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TeardownSiteError {
+    #[error(transparent)]
+    AdminServiceError(#[from] AdminServiceError),
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<TeardownSiteError> for StatusCode {
+    fn from(value: TeardownSiteError) -> Self {
+        match value {
+            TeardownSiteError::AdminServiceError(error) => Self::from(error),
+            TeardownSiteError::DomainServiceError(error) => Self::from(error),
+            TeardownSiteError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for TeardownSiteError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
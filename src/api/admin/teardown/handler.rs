@@ -0,0 +1,68 @@
+use super::error::TeardownSiteError;
+use crate::{
+    extractors::*,
+    services::{admin::service::Service as AdminService, domain::service::Service as DomainService},
+    state::State as AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Remove site of any user.
+///
+/// Files of the site are marked as obsolete and removed by cleanup task.
+#[utoipa::path(
+    delete,
+    tag = "Administration",
+    operation_id = "Teardown any site",
+    path = "/api/admin/sites/{id}",
+    params(
+        ("id" = i64, Path, description = "Id of the site to remove"),
+    ),
+    responses(
+        (status = 204, description = "Site was successfully removed."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 404, description = "Not Found: The site was not found.",                                  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+    Path(subdomain_id): Path<i64>,
+) -> Result<impl IntoResponse, TeardownSiteError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%admin.id, %subdomain_id, "Removing site...");
+
+    //? Domains are removed together with the site
+    let domains = DomainService::retrieve_domains_for(subdomain_id, &transaction).await?;
+    let (amount_of_files_to_be_removed, archives) = AdminService::teardown_site(subdomain_id, &transaction).await?;
+
+    tracing::trace!(%admin.id, %subdomain_id, %amount_of_files_to_be_removed, "Site was successfully removed. Committing changes...");
+
+    transaction.commit().await?;
+    state.limiter().forget_overrides();
+
+    //? Certificates of removed domains must not be presented anymore
+    for domain in domains.iter() {
+        state.certificates().remove(&domain.name);
+    }
+
+    //? Archives are removed only after the transaction
+    //? is committed so they are never lost on failure
+    for archive_path in archives.iter() {
+        tokio::fs::remove_file(archive_path)
+            .await
+            .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"))
+            .ok();
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
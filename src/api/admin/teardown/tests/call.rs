@@ -0,0 +1,21 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{api::tests::delete, Details};
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn teardown_site<T>(client: &TestClient, site_id: i64, token: T) -> Result<(), (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = delete(client, format!("/api/admin/sites/{site_id}"), Option::<()>::None)
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
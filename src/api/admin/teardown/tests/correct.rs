@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            admin::{
+                sites::tests::call::tests::sites, teardown::tests::call::tests::teardown_site, tests::admin_token,
+            },
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let admin_token = admin_token(&client).await;
+
+        let first_site_id = sites(&client, &admin_token)
+            .await
+            .expect("Failed to list sites!")
+            .sites
+            .into_iter()
+            .find(|site| site.name == first_random_subdomain)
+            .expect("Uploaded site is missing!")
+            .id;
+
+        //* Regular users are not allowed to remove sites of others
+        let forbidden_teardown_response = teardown_site(&client, first_site_id, &first_user_token).await;
+        assert!(forbidden_teardown_response.is_err());
+
+        teardown_site(&client, first_site_id, &admin_token)
+            .await
+            .expect("Failed to remove site!");
+
+        let removed_page_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(removed_page_response.status_code(), StatusCode::NOT_FOUND);
+
+        assert!(!sites(&client, &admin_token)
+            .await
+            .expect("Failed to list sites!")
+            .sites
+            .iter()
+            .any(|site| site.id == first_site_id));
+
+        //* Site can not be removed twice
+        let removed_teardown_response = teardown_site(&client, first_site_id, &admin_token).await;
+        assert_eq!(
+            removed_teardown_response
+                .expect_err("Removed site was removed again!")
+                .0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::admin::error::ServiceError as AdminServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum UpdateUserError {
+    #[error(transparent)]
+    AdminServiceError(#[from] AdminServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<UpdateUserError> for StatusCode {
+    fn from(value: UpdateUserError) -> Self {
+        match value {
+            UpdateUserError::AdminServiceError(error) => Self::from(error),
+            UpdateUserError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for UpdateUserError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,61 @@
+use super::{error::UpdateUserError, request::UpdateUserRequest};
+use crate::{
+    api::admin::UserResponse,
+    extractors::*,
+    services::admin::{parameters::UpdateUserParameters, service::Service as AdminService},
+    state::State as AppState,
+};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Suspend user, change its role or override its limits.
+///
+/// Suspension revokes all sessions of the user. Personal API tokens
+/// are rejected until the user is unsuspended. Sites keep being served,
+/// disable them separately if needed.
+#[utoipa::path(
+    patch,
+    tag = "Administration",
+    operation_id = "Update user",
+    path = "/api/admin/users/{id}",
+    params(
+        ("id" = i64, Path, description = "Id of the user to update"),
+    ),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "User was successfully updated.",                                       body = UserResponse),
+        (status = 400, description = "Bad Request: Administrators can not suspend or demote themselves.",   body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 404, description = "Not Found: The user was not found.",                                  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+    Path(user_id): Path<i64>,
+    Json(payload): Json<UpdateUserRequest>,
+) -> Result<Json<UserResponse>, UpdateUserError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%admin.id, %user_id, "Updating user...");
+
+    let parameters = UpdateUserParameters {
+        admin: payload.admin,
+        suspended: payload.suspended,
+        max_sites: payload.max_sites,
+    };
+
+    let user = AdminService::update_user(admin.id, user_id, parameters, &transaction).await?;
+
+    tracing::trace!(%admin.id, %user_id, "User was successfully updated. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(Json(UserResponse::from(user)))
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"suspended": true}))]
+pub struct UpdateUserRequest {
+    /// Grant or revoke administrator role
+    /// Omitted field keeps current value
+    pub admin: Option<bool>,
+    /// Suspended user can not login and all of its sessions are revoked
+    pub suspended: Option<bool>,
+    /// Sites the user can own. Zero falls back to `MAX_SITES_PER_USER`
+    pub max_sites: Option<u64>,
+}
@@ -0,0 +1,32 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            admin::{update::request::UpdateUserRequest, UserResponse},
+            tests::patch,
+        },
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn update_user<T>(
+        client: &TestClient,
+        user_id: i64,
+        request: &UpdateUserRequest,
+        token: T,
+    ) -> Result<UserResponse, (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = patch(client, format!("/api/admin/users/{user_id}"), Some(request))
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
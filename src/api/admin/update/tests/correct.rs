@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            admin::{
+                tests::admin_token,
+                update::{request::UpdateUserRequest, tests::call::tests::update_user},
+                users::tests::call::tests::users,
+            },
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let admin_token = admin_token(&client).await;
+
+        let first_user_id = users(&client, &admin_token)
+            .await
+            .expect("Failed to list users!")
+            .users
+            .into_iter()
+            .find(|user| user.login == first_user_login.to_string())
+            .expect("Registered user is missing!")
+            .id;
+
+        //* Limit override
+        let limit_request = UpdateUserRequest {
+            max_sites: Some(3),
+            ..Default::default()
+        };
+        let limited_user = update_user(&client, first_user_id, &limit_request, &admin_token)
+            .await
+            .expect("Failed to override limit!");
+        assert_eq!(limited_user.max_sites, Some(3));
+
+        //* Zero resets the override
+        let reset_request = UpdateUserRequest {
+            max_sites: Some(0),
+            ..Default::default()
+        };
+        let reset_user = update_user(&client, first_user_id, &reset_request, &admin_token)
+            .await
+            .expect("Failed to reset limit!");
+        assert_eq!(reset_user.max_sites, None);
+
+        //* Suspension rejects issued tokens and further logins
+        let suspend_request = UpdateUserRequest {
+            suspended: Some(true),
+            ..Default::default()
+        };
+        let suspended_user = update_user(&client, first_user_id, &suspend_request, &admin_token)
+            .await
+            .expect("Failed to suspend user!");
+        assert!(suspended_user.suspended_at.is_some());
+
+        let suspended_users_response = users(&client, &first_user_token).await;
+        assert!(suspended_users_response.is_err());
+
+        let suspended_login_response = login(&client, &first_user_login_request).await;
+        assert_eq!(
+            suspended_login_response
+                .expect_err("Suspended user was able to login!")
+                .0,
+            StatusCode::FORBIDDEN
+        );
+
+        //* Unsuspended user can login again
+        let unsuspend_request = UpdateUserRequest {
+            suspended: Some(false),
+            ..Default::default()
+        };
+        let unsuspended_user = update_user(&client, first_user_id, &unsuspend_request, &admin_token)
+            .await
+            .expect("Failed to unsuspend user!");
+        assert!(unsuspended_user.suspended_at.is_none());
+        assert!(login(&client, &first_user_login_request).await.is_ok());
+
+        //* Administrators can not lock themselves out
+        let admin_id = users(&client, &admin_token)
+            .await
+            .expect("Failed to list users!")
+            .users
+            .into_iter()
+            .find(|user| Some(user.login.clone()) == std::env::var("ADMIN_LOGIN").ok())
+            .expect("Administrator is missing!")
+            .id;
+
+        let self_suspension_response = update_user(&client, admin_id, &suspend_request, &admin_token).await;
+        assert_eq!(
+            self_suspension_response
+                .expect_err("Administrator was able to suspend itself!")
+                .0,
+            StatusCode::BAD_REQUEST
+        );
+
+        //* Regular users are not allowed to update users
+        let forbidden_update_response = update_user(&client, admin_id, &suspend_request, &first_user_token).await;
+        assert!(forbidden_update_response.is_err());
+
+        //* Unknown user
+        let unknown_user_response = update_user(&client, i64::MAX, &limit_request, &admin_token).await;
+        assert_eq!(
+            unknown_user_response.expect_err("Unknown user was updated!").0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
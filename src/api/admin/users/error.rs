@@ -0,0 +1,30 @@
+use crate::{services::admin::error::ServiceError as AdminServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListUsersError {
+    #[error(transparent)]
+    AdminServiceError(#[from] AdminServiceError),
+}
+
+impl From<ListUsersError> for StatusCode {
+    fn from(value: ListUsersError) -> Self {
+        match value {
+            ListUsersError::AdminServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ListUsersError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,39 @@
+use super::{error::ListUsersError, response::ListUsersResponse};
+use crate::{
+    api::admin::UserResponse, extractors::*, services::admin::service::Service as AdminService,
+    state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List all users of the instance.
+///
+/// Available only to administrators.
+#[utoipa::path(
+    get,
+    tag = "Administration",
+    operation_id = "Get all users",
+    path = "/api/admin/users",
+    responses(
+        (status = 200, description = "Users were successfully retrieved.",                                   body = ListUsersResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.", body = Details),
+        (status = 403, description = "Forbidden: The user is not an administrator.",                        body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AdminJWT(admin): AdminJWT,
+) -> Result<impl IntoResponse, ListUsersError> {
+    tracing::trace!(%admin.id, "Retrieving users list...");
+
+    let users = AdminService::retrieve_users(state.connection()).await?;
+
+    tracing::trace!(%admin.id, amount = users.len(), "Users list was successfully retrieved!");
+
+    Ok(Json(ListUsersResponse {
+        users: users.into_iter().map(UserResponse::from).collect(),
+    }))
+}
@@ -0,0 +1,10 @@
+use crate::api::admin::UserResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"users": [{"id": 1, "login": "admin", "admin": true, "suspended_at": null, "max_sites": null}]}))]
+pub struct ListUsersResponse {
+    /// Every user of the instance in order of registration
+    pub users: Vec<UserResponse>,
+}
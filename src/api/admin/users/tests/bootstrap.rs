@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::auth::registration::{request::RegistrationRequest, tests::call::tests::registration},
+        app,
+        services::admin::{error::ServiceError, service::Service as AdminService},
+    };
+    use axum_test::TestServer as TestClient;
+    use entity::prelude::*;
+    use sea_orm::prelude::*;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn bootstrap() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4().to_string();
+        let first_user_password = Uuid::new_v4().to_string();
+
+        //* Login is taken before administrator is bootstrapped
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.clone(),
+            password: first_user_password.clone(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Existing user is not promoted with another password
+        let bootstrap_response =
+            AdminService::bootstrap(&first_user_login, Some("configured"), state.connection()).await;
+        assert!(matches!(
+            bootstrap_response,
+            Err(ServiceError::AdministratorPasswordMismatch)
+        ));
+
+        let user = UserEntity::find()
+            .filter(UserColumn::Login.eq(&first_user_login))
+            .one(state.connection())
+            .await
+            .expect("Failed to find user!")
+            .expect("User must exist!");
+        assert!(!user.is_admin);
+
+        //* But it is with its own one
+        let bootstrap_response =
+            AdminService::bootstrap(&first_user_login, Some(&first_user_password), state.connection()).await;
+        assert!(bootstrap_response.is_ok_and(|user| user.is_some_and(|user| user.is_admin)));
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            admin::{tests::admin_token, users::tests::call::tests::users},
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        //* Regular users are not allowed to see other users
+        let forbidden_users_response = users(&client, &first_user_token).await;
+        assert_eq!(
+            forbidden_users_response
+                .expect_err("Regular user was able to list users!")
+                .0,
+            StatusCode::FORBIDDEN
+        );
+
+        let admin_token = admin_token(&client).await;
+
+        let users_response = users(&client, &admin_token).await.expect("Failed to list users!");
+        let first_user = users_response
+            .users
+            .iter()
+            .find(|user| user.login == first_user_login.to_string())
+            .expect("Registered user is missing!");
+        assert!(!first_user.admin);
+        assert!(first_user.suspended_at.is_none());
+        assert!(users_response
+            .users
+            .iter()
+            .any(|user| user.admin && Some(user.login.clone()) == std::env::var("ADMIN_LOGIN").ok()));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod bootstrap;
+pub mod call;
+pub mod correct;
@@ -0,0 +1,25 @@
+use super::response::JwksResponse;
+use crate::state::State as AppState;
+use axum::{extract::State, Json};
+use std::sync::Arc;
+
+/// Get public keys of JWTs.
+///
+/// Other services can verify tokens issued by sero with these keys.
+/// Key is selected by `kid` header of the token. Keys of previous
+/// signing keys are listed until they are removed from configuration.
+#[utoipa::path(
+    get,
+    tag  = "Account management",
+    operation_id = "Get JSON Web Key Set",
+    path = "/.well-known/jwks.json",
+    responses(
+        (status = 200, description = "Public keys were successfully retrieved.", body = JwksResponse),
+    ),
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(State(state): State<Arc<AppState>>) -> Json<JwksResponse> {
+    Json(JwksResponse {
+        keys: state.keys().jwks(),
+    })
+}
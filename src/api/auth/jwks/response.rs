@@ -0,0 +1,10 @@
+use crate::services::auth::keys::Jwk;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"keys": [{"kty": "OKP", "use": "sig", "alg": "EdDSA", "kid": "Gk2qvlP0e0aYtS3L", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}]}))]
+pub struct JwksResponse {
+    /// Public keys JWTs are verified with. Empty if tokens are signed with secret
+    pub keys: Vec<Jwk>,
+}
@@ -0,0 +1,13 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::api::{auth::jwks::response::JwksResponse, tests::get};
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+
+    pub async fn jwks(client: &TestClient) -> JwksResponse {
+        let response = get(client, "/.well-known/jwks.json").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        response.json()
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::auth::{
+            jwks::tests::call::tests::jwks,
+            login::{request::LoginRequest, tests::call::test::login},
+            registration::{request::RegistrationRequest, tests::call::tests::registration},
+        },
+        app,
+        services::auth::parameters::TokenClaims,
+    };
+    use axum_test::TestServer as TestClient;
+    use jsonwebtoken::{jwk::Jwk, Algorithm, DecodingKey, Validation};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        //* Signing key goes first, retired RSA key follows
+        let jwks_response = jwks(&client).await;
+        assert_eq!(jwks_response.keys.len(), 2);
+        assert_eq!(jwks_response.keys[0].alg, "EdDSA");
+        assert_eq!(jwks_response.keys[0].crv.as_deref(), Some("Ed25519"));
+        assert_eq!(jwks_response.keys[1].alg, "RS256");
+        assert!(jwks_response.keys[1].n.is_some());
+
+        //* Token refers to signing key
+        let header = jsonwebtoken::decode_header(&first_user_token).expect("Failed to decode header!");
+        assert_eq!(header.alg, Algorithm::EdDSA);
+        assert_eq!(header.kid.as_deref(), Some(jwks_response.keys[0].kid.as_str()));
+
+        //* Token can be verified only with published key
+        let jwk: Jwk = serde_json::from_value(serde_json::to_value(&jwks_response.keys[0]).expect("to never fail"))
+            .expect("Failed to parse published key!");
+        let key = DecodingKey::from_jwk(&jwk).expect("Failed to build key from published key!");
+
+        let claims = jsonwebtoken::decode::<TokenClaims>(&first_user_token, &key, &Validation::new(Algorithm::EdDSA))
+            .expect("Failed to verify token with published key!")
+            .claims;
+        assert!(claims.sid.is_some());
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::auth::{
+            login::{request::LoginRequest, tests::call::test::login},
+            registration::{request::RegistrationRequest, tests::call::tests::registration},
+            sessions::tests::call::tests::sessions,
+        },
+        app,
+        services::auth::{
+            keys::JwtKeys,
+            parameters::{JwtDecodingParameters, JwtGenerationParameters},
+            service::Service as AuthService,
+        },
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use rcgen::{KeyPair, PKCS_ED25519};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn rotation() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let claims = AuthService::decode_jwt(JwtDecodingParameters {
+            token: first_user_token.as_str(),
+            secret: state.configuration().jwt_secret(),
+            keys: state.keys(),
+        })
+        .expect("Failed to decode token!");
+        let session_id = claims.sid.expect("Session id is missing!");
+
+        let sign = |keys: &JwtKeys| {
+            let parameters = JwtGenerationParameters {
+                secret: state.configuration().jwt_secret(),
+                ttl: state.configuration().jwt_ttl_seconds(),
+                keys,
+            };
+
+            AuthService::generate_jwt(claims.sub, session_id, parameters).expect("Failed to generate token!")
+        };
+
+        //* Token of retired key is accepted while its public key is configured
+        let retired_key = tokio::fs::read("./assets/keys/rsa.key")
+            .await
+            .expect("Failed to read retired key!");
+        let retired_keys = JwtKeys::parse(Some(retired_key), &[] as &[Vec<u8>]).expect("Failed to parse retired key!");
+        assert!(state
+            .keys()
+            .find(&retired_keys.signing().expect("to never fail").kid)
+            .is_some());
+
+        let retired_token = sign(&retired_keys);
+        assert!(sessions(&client, &retired_token).await.is_ok());
+
+        //* Token signed with secret is accepted for backward compatibility
+        let legacy_token = sign(&JwtKeys::default());
+        assert!(jsonwebtoken::decode_header(&legacy_token)
+            .expect("Failed to decode header!")
+            .kid
+            .is_none());
+        assert!(sessions(&client, &legacy_token).await.is_ok());
+
+        //* Token of unknown key is rejected
+        let unknown_key = KeyPair::generate_for(&PKCS_ED25519).expect("Failed to generate key!");
+        let unknown_keys =
+            JwtKeys::parse(Some(unknown_key.serialize_pem()), &[] as &[Vec<u8>]).expect("Failed to parse unknown key!");
+
+        let unknown_token = sign(&unknown_keys);
+        let unknown_token_response = sessions(&client, &unknown_token).await;
+        assert_eq!(
+            unknown_token_response
+                .expect_err("Token of unknown key was accepted!")
+                .0,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+}
@@ -1,15 +1,22 @@
-use crate::{services::auth::error::ServiceError as AuthServiceError, Details};
+use super::response::LockoutDetails;
+use crate::{
+    services::{auth::error::ServiceError as AuthServiceError, session::error::ServiceError as SessionServiceError},
+    Details,
+};
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::Utc;
 use validator::ValidationErrors;
 
 #[derive(thiserror::Error, Debug)]
 pub enum LoginError {
     #[error(transparent)]
     AuthServiceError(#[from] AuthServiceError),
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
     #[error("Login or password does not match validation rules!")]
     ValidationError(#[from] ValidationErrors),
 }
@@ -18,6 +25,7 @@ impl From<LoginError> for StatusCode {
     fn from(value: LoginError) -> Self {
         match value {
             LoginError::AuthServiceError(error) => Self::from(error),
+            LoginError::SessionServiceError(error) => Self::from(error),
             LoginError::ValidationError(_) => Self::BAD_REQUEST,
         }
     }
@@ -26,6 +34,20 @@ impl From<LoginError> for StatusCode {
 impl IntoResponse for LoginError {
     fn into_response(self) -> Response {
         let reason = self.to_string();
+
+        //? Clients can tell when to try again
+        if let LoginError::AuthServiceError(AuthServiceError::LoginIsLocked(locked_until)) = self {
+            let retry_after = (locked_until - Utc::now()).num_seconds().max(1);
+            tracing::warn!(%reason, %locked_until, "Login is locked!");
+
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(LockoutDetails { reason, locked_until }),
+            )
+                .into_response();
+        }
+
         let status_code: StatusCode = self.into();
         tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
         (status_code, Json(Details { reason })).into_response()
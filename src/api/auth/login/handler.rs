@@ -1,10 +1,16 @@
 use super::{error::LoginError, request::LoginRequest, response::LoginResponse};
 use crate::{
-    auth::parameters::{JwtGenerationParameters, UserCredentials},
-    services::auth::service::Service as AuthService,
+    auth::parameters::{JwtGenerationParameters, LockoutParameters, UserCredentials},
+    extractors::*,
+    http::client,
+    services::{auth::service::Service as AuthService, session::service::Service as SessionService},
     state::State as AppState,
 };
-use axum::{extract::State, Json};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap},
+    Json,
+};
 use std::sync::Arc;
 use validator::Validate;
 
@@ -12,6 +18,12 @@ use validator::Validate;
 ///
 /// This endpoint allows users to login to sero server. The TTL for token is set by
 /// the owner of the server by `JWT_TTL` env.
+///
+/// Every login starts a session. Refresh token of the session is exchanged
+/// for a new JWT at `/api/auth/refresh` until the session is revoked.
+///
+/// Login and client are locked out for a while after too many failed attempts.
+/// Lockout is doubled with every next failure.
 #[utoipa::path(
     post,
     tag  = "Account management",
@@ -21,13 +33,17 @@ use validator::Validate;
     responses(
         (status = 200, description = "User was successfully authenticated.",         body = LoginResponse),
         (status = 400, description = "Bad request or bad credentials. See details.", body = Details),
+        (status = 403, description = "User is suspended by administrator.",          body = Details),
         (status = 404, description = "Login was not found.",                         body = Details),
+        (status = 429, description = "Too many failed attempts. See Retry-After.",   body = LockoutDetails),
         (status = 500, description = "Some error occurred on the server.",           body = Details),
     ),
 )]
 #[tracing::instrument(skip(state))]
 pub async fn implementation(
     State(state): State<Arc<AppState>>,
+    ClientAddress(address): ClientAddress,
+    headers: HeaderMap,
     Json(credentials): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, LoginError> {
     //? I am validating here for
@@ -42,16 +58,43 @@ pub async fn implementation(
         password: credentials.password,
     };
 
-    let user = AuthService::login(credentials, state.connection()).await?;
+    //? Behind untrusted proxy all clients share its address
+    //? and one of them could lock out everyone else
+    let is_reliable = client::is_reliable(&headers, state.configuration().trusted_proxies());
+
+    let lockout = LockoutParameters {
+        address: address.filter(|_| is_reliable),
+        attempts_per_login: state.configuration().login_attempts_per_login(),
+        attempts_per_address: state.configuration().login_attempts_per_address(),
+        lockout_seconds: state.configuration().login_lockout_seconds(),
+    };
+
+    let user = AuthService::login(credentials, lockout, state.connection()).await?;
     tracing::trace!(?user, "User was successfully found!");
 
     let parameters = JwtGenerationParameters {
         secret: state.configuration().jwt_secret(),
         ttl: state.configuration().jwt_ttl_seconds(),
+        keys: state.keys(),
     };
 
-    let token = AuthService::generate_jwt(user.id, parameters)?;
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|user_agent| user_agent.to_str().ok())
+        .map(str::to_owned);
+
+    let (session, refresh_token) = SessionService::start_for(
+        user.id,
+        user_agent,
+        address.map(|address| address.to_string()),
+        state.configuration().refresh_ttl_seconds(),
+        state.connection(),
+    )
+    .await?;
+    tracing::trace!(%session.id, "Session was successfully started!");
+
+    let token = AuthService::generate_jwt(user.id, session.id, parameters)?;
     tracing::trace!("Token was successfully generated!");
 
-    Ok(Json(LoginResponse { token }))
+    Ok(Json(LoginResponse { token, refresh_token }))
 }
@@ -1,10 +1,23 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 /// The JWT token generated for authentication purposes.
 #[derive(Deserialize, Serialize, ToSchema, Debug)]
-#[schema(example = json!({"token": "ferwfwerfwer.fwerfwerfwerfwer.fwerfewfr"}))]
+#[schema(example = json!({"token": "ferwfwerfwer.fwerfwerfwerfwer.fwerfewfr", "refresh_token": "0f4c3e0d4f7a4a0b9f1e2d3c4b5a6978"}))]
 pub struct LoginResponse {
     /// Token in JWT format
     pub token: String,
+    /// Token which is exchanged for a new pair of tokens.
+    /// It can be used only once
+    pub refresh_token: String,
+}
+
+/// Login was locked out after too many failed attempts
+#[derive(Deserialize, Serialize, ToSchema, Debug)]
+#[schema(example = json!({"reason": "Too many failed login attempts!", "locked_until": "2024-10-24T12:00:00Z"}))]
+pub struct LockoutDetails {
+    pub reason: String,
+    /// Login attempts are rejected until this moment
+    pub locked_until: DateTime<Utc>,
 }
@@ -0,0 +1,58 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            tests::post,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use futures::future::join_all;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn concurrent() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let correct_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let wrong_request = LoginRequest {
+            login: first_user_login.into(),
+            password: Uuid::new_v4().into(),
+        };
+
+        //* Concurrent failures are all counted and none of them fails.
+        //* Attempts checked after threshold was reached are locked
+        let wrong_responses = join_all((0..10).map(|_| login(&client, &wrong_request))).await;
+        for wrong_response in wrong_responses {
+            assert!(wrong_response.is_err_and(|error| {
+                error.0 == StatusCode::BAD_REQUEST || error.0 == StatusCode::TOO_MANY_REQUESTS
+            }));
+        }
+
+        //* Threshold was reached so correct password is rejected
+        let locked_response = post(&client, "/api/auth/login", Some(&correct_request)).await;
+        assert_eq!(locked_response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, response::LockoutDetails, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            tests::post,
+        },
+        app,
+    };
+    use axum::http::{header, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn lockout() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let correct_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let wrong_request = LoginRequest {
+            login: first_user_login.into(),
+            password: Uuid::new_v4().into(),
+        };
+
+        //* Counter is reset on success
+        for _ in 0..4 {
+            let wrong_response = login(&client, &wrong_request).await;
+            assert!(wrong_response.is_err_and(|error| error.0 == StatusCode::BAD_REQUEST));
+        }
+        let correct_response = login(&client, &correct_request).await;
+        assert!(correct_response.is_ok());
+
+        //* Threshold is reached on the fifth failure
+        for _ in 0..5 {
+            let wrong_response = login(&client, &wrong_request).await;
+            assert!(wrong_response.is_err_and(|error| error.0 == StatusCode::BAD_REQUEST));
+        }
+
+        //* Even correct password is rejected during lockout
+        let locked_response = post(&client, "/api/auth/login", Some(&correct_request)).await;
+        assert_eq!(locked_response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+
+        let retry_after = locked_response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<i64>().ok())
+            .expect("Retry-After header must be set");
+        assert!((1..=60).contains(&retry_after));
+
+        let details = locked_response.json::<LockoutDetails>();
+        assert!(details.locked_until > chrono::Utc::now());
+    }
+}
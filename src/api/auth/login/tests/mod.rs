@@ -1,4 +1,6 @@
 pub mod bad_credentials;
 pub mod call;
+pub mod concurrent;
 pub mod correct;
+pub mod lockout;
 pub mod not_found;
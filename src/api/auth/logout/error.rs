@@ -0,0 +1,34 @@
+use crate::{services::session::error::ServiceError as SessionServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum LogoutError {
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<LogoutError> for StatusCode {
+    fn from(value: LogoutError) -> Self {
+        match value {
+            LogoutError::SessionServiceError(error) => Self::from(error),
+            LogoutError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for LogoutError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
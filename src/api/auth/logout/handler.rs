@@ -0,0 +1,36 @@
+use super::error::LogoutError;
+use crate::{extractors::*, services::session::service::Service as SessionService, state::State as AppState};
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Logout from the current session.
+///
+/// The session is revoked so its JWT and refresh token stop working right away.
+#[utoipa::path(
+    post,
+    tag  = "Account management",
+    operation_id = "Logout",
+    path = "/api/auth/logout",
+    responses(
+        (status = 204, description = "Session was successfully revoked."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SessionJWT { user, session }: SessionJWT,
+) -> Result<impl IntoResponse, LogoutError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%session.id, %user.id, "Revoking current session...");
+
+    SessionService::revoke_session_of(user.id, session.id, &transaction).await?;
+
+    tracing::trace!(%session.id, %user.id, "Session was successfully revoked. Committing changes...");
+    transaction.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,21 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{api::tests::post, Details};
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn logout<T>(client: &TestClient, token: T) -> Result<(), (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = post(client, "/api/auth/logout", Option::<()>::None)
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::auth::{
+            login::{request::LoginRequest, tests::call::test::login},
+            logout::tests::call::tests::logout,
+            refresh::{request::RefreshRequest, tests::call::tests::refresh},
+            registration::{request::RegistrationRequest, tests::call::tests::registration},
+            sessions::tests::call::tests::sessions,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_login_response = first_user_login_response.expect("to never fail");
+        let first_user_token = first_user_login_response.token;
+        let first_user_refresh_token = first_user_login_response.refresh_token;
+
+        let logout_response = logout(&client, &first_user_token).await;
+        assert!(logout_response.is_ok());
+
+        //* JWT is rejected before it expires
+        let sessions_response = sessions(&client, &first_user_token).await;
+        assert_eq!(
+            sessions_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+
+        //* Refresh token is rejected too
+        let refresh_request = RefreshRequest {
+            refresh_token: first_user_refresh_token,
+        };
+        let refresh_response = refresh(&client, &refresh_request).await;
+        assert_eq!(
+            refresh_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+}
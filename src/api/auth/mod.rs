@@ -1,12 +1,24 @@
 use crate::state::State as AppState;
-use axum::{routing::post, Router};
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
 use std::sync::Arc;
 
+pub mod jwks;
 pub mod login;
+pub mod logout;
+pub mod refresh;
 pub mod registration;
+pub mod sessions;
+pub mod terminate;
 
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/login", post(login::handler::implementation))
+        .route("/logout", post(logout::handler::implementation))
+        .route("/refresh", post(refresh::handler::implementation))
         .route("/registration", post(registration::handler::implementation))
+        .route("/sessions", get(sessions::handler::implementation))
+        .route("/sessions/:id", delete(terminate::handler::implementation))
 }
@@ -0,0 +1,35 @@
+use crate::{
+    services::{auth::error::ServiceError as AuthServiceError, session::error::ServiceError as SessionServiceError},
+    Details,
+};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum RefreshError {
+    #[error(transparent)]
+    AuthServiceError(#[from] AuthServiceError),
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+}
+
+impl From<RefreshError> for StatusCode {
+    fn from(value: RefreshError) -> Self {
+        match value {
+            RefreshError::AuthServiceError(error) => Self::from(error),
+            RefreshError::SessionServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for RefreshError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
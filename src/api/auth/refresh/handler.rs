@@ -0,0 +1,50 @@
+use super::{error::RefreshError, request::RefreshRequest, response::RefreshResponse};
+use crate::{
+    auth::parameters::JwtGenerationParameters,
+    services::{auth::service::Service as AuthService, session::service::Service as SessionService},
+    state::State as AppState,
+};
+use axum::{extract::State, Json};
+use std::sync::Arc;
+
+/// Exchange refresh token for a new JWT and a new refresh token.
+///
+/// Every refresh token can be used only once. If a used token is presented
+/// again the whole session is revoked as the token was most likely stolen.
+#[utoipa::path(
+    post,
+    tag  = "Account management",
+    operation_id = "Refresh",
+    path = "/api/auth/refresh",
+    request_body = RefreshRequest,
+    responses(
+        (status = 200, description = "Tokens were successfully refreshed.",                      body = RefreshResponse),
+        (status = 401, description = "Refresh token is invalid, reused or session has ended.",   body = Details),
+        (status = 500, description = "Some error occurred on the server.",                       body = Details),
+    ),
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<RefreshRequest>,
+) -> Result<Json<RefreshResponse>, RefreshError> {
+    //? Reuse must revoke the session even though request fails
+    //? so no transaction is used here
+    let (session, refresh_token) = SessionService::refresh(
+        &payload.refresh_token,
+        state.configuration().refresh_ttl_seconds(),
+        state.connection(),
+    )
+    .await?;
+    tracing::trace!(%session.id, %session.user_id, "Session was successfully refreshed!");
+
+    let parameters = JwtGenerationParameters {
+        secret: state.configuration().jwt_secret(),
+        ttl: state.configuration().jwt_ttl_seconds(),
+        keys: state.keys(),
+    };
+
+    let token = AuthService::generate_jwt(session.user_id, session.id, parameters)?;
+
+    Ok(Json(RefreshResponse { token, refresh_token }))
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"refresh_token": "0f4c3e0d4f7a4a0b9f1e2d3c4b5a6978"}))]
+pub struct RefreshRequest {
+    /// Refresh token received on login or on the previous refresh
+    pub refresh_token: String,
+}
+
+impl Debug for RefreshRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshRequest").field("refresh_token", &"***").finish()
+    }
+}
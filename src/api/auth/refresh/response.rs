@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug)]
+#[schema(example = json!({"token": "ferwfwerfwer.fwerfwerfwerfwer.fwerfewfr", "refresh_token": "0f4c3e0d4f7a4a0b9f1e2d3c4b5a6978"}))]
+pub struct RefreshResponse {
+    /// Token in JWT format
+    pub token: String,
+    /// Replacement of the used refresh token
+    pub refresh_token: String,
+}
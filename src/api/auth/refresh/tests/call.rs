@@ -0,0 +1,24 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::refresh::{request::RefreshRequest, response::RefreshResponse},
+            tests::post,
+        },
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+
+    pub async fn refresh(
+        client: &TestClient,
+        request: &RefreshRequest,
+    ) -> Result<RefreshResponse, (StatusCode, Details)> {
+        let response = post(client, "/api/auth/refresh", Some(request)).await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
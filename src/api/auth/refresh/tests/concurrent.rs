@@ -0,0 +1,79 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::auth::{
+            login::{request::LoginRequest, tests::call::test::login},
+            refresh::{request::RefreshRequest, tests::call::tests::refresh},
+            registration::{request::RegistrationRequest, tests::call::tests::registration},
+            sessions::tests::call::tests::sessions,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use futures::future::join_all;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn concurrent() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_login_response = first_user_login_response.expect("to never fail");
+        let first_user_token = first_user_login_response.token;
+
+        let refresh_request = RefreshRequest {
+            refresh_token: first_user_login_response.refresh_token,
+        };
+
+        //* Only one of parallel refreshes with the same token succeeds
+        let refresh_responses = join_all((0..5).map(|_| refresh(&client, &refresh_request))).await;
+
+        let (refreshed, rejected): (Vec<_>, Vec<_>) = refresh_responses.into_iter().partition(Result::is_ok);
+        assert!(refreshed.len() <= 1);
+        assert!(rejected
+            .iter()
+            .all(|response| matches!(response, Err((StatusCode::UNAUTHORIZED, _)))));
+
+        //* Reuse was detected so the whole session is revoked
+        for refreshed in refreshed.into_iter().flatten() {
+            let refresh_response = refresh(
+                &client,
+                &RefreshRequest {
+                    refresh_token: refreshed.refresh_token,
+                },
+            )
+            .await;
+            assert_eq!(
+                refresh_response.map_err(|(status, _)| status),
+                Err(StatusCode::UNAUTHORIZED)
+            );
+        }
+
+        let sessions_response = sessions(&client, &first_user_token).await;
+        assert_eq!(
+            sessions_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{services::session::error::ServiceError as SessionServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListSessionsError {
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+}
+
+impl From<ListSessionsError> for StatusCode {
+    fn from(value: ListSessionsError) -> Self {
+        match value {
+            ListSessionsError::SessionServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ListSessionsError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,41 @@
+use super::{
+    error::ListSessionsError,
+    response::{ListSessionsResponse, SessionResponse},
+};
+use crate::{extractors::*, services::session::service::Service as SessionService, state::State as AppState};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List active sessions of the user.
+///
+/// Session of the token used for the request is marked as current.
+#[utoipa::path(
+    get,
+    tag  = "Account management",
+    operation_id = "Get all sessions",
+    path = "/api/auth/sessions",
+    responses(
+        (status = 200, description = "Sessions were successfully retrieved.",                                 body = ListSessionsResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SessionJWT { user, session }: SessionJWT,
+) -> Result<impl IntoResponse, ListSessionsError> {
+    tracing::trace!(%user.id, "Retrieving sessions list...");
+
+    let sessions = SessionService::retrieve_sessions_for(user.id, state.connection()).await?;
+
+    tracing::trace!(%user.id, amount = sessions.len(), "Sessions list was successfully retrieved!");
+
+    Ok(Json(ListSessionsResponse {
+        sessions: sessions
+            .into_iter()
+            .map(|listed| SessionResponse::new(listed, session.id))
+            .collect(),
+    }))
+}
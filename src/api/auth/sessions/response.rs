@@ -0,0 +1,38 @@
+use chrono::{DateTime, FixedOffset};
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SessionResponse {
+    pub id: i64,
+    pub user_agent: Option<String>,
+    /// Address of the client on login
+    pub address: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub refreshed_at: DateTime<FixedOffset>,
+    pub expires_at: DateTime<FixedOffset>,
+    /// Session of the token used for this request
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn new(session: SessionModel, current: i64) -> Self {
+        Self {
+            current: session.id == current,
+            id: session.id,
+            user_agent: session.user_agent,
+            address: session.address,
+            created_at: session.created_at,
+            refreshed_at: session.refreshed_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"sessions": [{"id": 42, "user_agent": "curl/8.5.0", "address": "203.0.113.7", "created_at": "2024-10-26T12:00:00+00:00", "refreshed_at": "2024-10-26T12:00:00+00:00", "expires_at": "2024-11-25T12:00:00+00:00", "current": true}]}))]
+pub struct ListSessionsResponse {
+    /// Active sessions in order of creation
+    pub sessions: Vec<SessionResponse>,
+}
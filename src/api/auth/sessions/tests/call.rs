@@ -0,0 +1,22 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{auth::sessions::response::ListSessionsResponse, tests::get},
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn sessions<T>(client: &TestClient, token: T) -> Result<ListSessionsResponse, (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = get(client, "/api/auth/sessions").authorization_bearer(token).await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
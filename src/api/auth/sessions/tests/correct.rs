@@ -0,0 +1,66 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::auth::{
+            login::{request::LoginRequest, tests::call::test::login},
+            registration::{request::RegistrationRequest, tests::call::tests::registration},
+            sessions::tests::call::tests::sessions,
+        },
+        app,
+    };
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_login_response = first_user_login_response.expect("to never fail");
+        let first_user_token = first_user_login_response.token;
+        let first_user_refresh_token = first_user_login_response.refresh_token;
+
+        //* Second device
+        let second_login_response = login(&client, &first_user_login_request)
+            .await
+            .expect("Failed to login!");
+
+        let sessions_response = sessions(&client, &first_user_token)
+            .await
+            .expect("Failed to list sessions!");
+        assert_eq!(sessions_response.sessions.len(), 2);
+        assert!(sessions_response.sessions[0].current);
+        assert!(!sessions_response.sessions[1].current);
+
+        let sessions_response = sessions(&client, &second_login_response.token)
+            .await
+            .expect("Failed to list sessions!");
+        assert!(!sessions_response.sessions[0].current);
+        assert!(sessions_response.sessions[1].current);
+
+        //* Refresh token is never listed
+        assert!(!serde_json::to_string(&sessions_response)
+            .expect("Failed to serialize sessions!")
+            .contains(&first_user_refresh_token));
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::list::tests::call::tests::list,
+        },
+        app,
+        services::auth::{
+            parameters::{JwtDecodingParameters, TokenClaims},
+            service::Service as AuthService,
+        },
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use chrono::{Duration, Utc};
+    use jsonwebtoken::{EncodingKey, Header};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn sessionless() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let claims = AuthService::decode_jwt(JwtDecodingParameters {
+            token: first_user_token.as_str(),
+            secret: state.configuration().jwt_secret(),
+            keys: state.keys(),
+        })
+        .expect("Failed to decode token!");
+
+        //* Token bound to a session is accepted
+        let list_response = list(&client, &first_user_token, 1, 20).await;
+        assert!(list_response.is_ok());
+
+        //* Validly signed token without session can not be revoked so it is rejected
+        let sessionless_claims = TokenClaims {
+            sub: claims.sub,
+            iat: Utc::now().timestamp(),
+            exp: (Utc::now() + Duration::try_minutes(1).expect("never fails")).timestamp(),
+            sid: None,
+        };
+        let sessionless_token = jsonwebtoken::encode(
+            &Header::default(),
+            &sessionless_claims,
+            &EncodingKey::from_secret(state.configuration().jwt_secret().as_bytes()),
+        )
+        .expect("Failed to encode token!");
+
+        let list_response = list(&client, &sessionless_token, 1, 20).await;
+        assert_eq!(
+            list_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::session::error::ServiceError as SessionServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TerminateSessionError {
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<TerminateSessionError> for StatusCode {
+    fn from(value: TerminateSessionError) -> Self {
+        match value {
+            TerminateSessionError::SessionServiceError(error) => Self::from(error),
+            TerminateSessionError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for TerminateSessionError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
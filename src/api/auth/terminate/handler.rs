@@ -0,0 +1,47 @@
+use super::error::TerminateSessionError;
+use crate::{extractors::*, services::session::service::Service as SessionService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Revoke session by id.
+///
+/// This is useful to sign out a lost device. JWT and refresh token
+/// of the session stop working right away.
+#[utoipa::path(
+    delete,
+    tag  = "Account management",
+    operation_id = "Revoke session by id",
+    path = "/api/auth/sessions/{id}",
+    params(
+        ("id" = i64, Path, description = "Id of the session to revoke"),
+    ),
+    responses(
+        (status = 204, description = "Session was successfully revoked."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid, expired or revoked.",  body = Details),
+        (status = 403, description = "Forbidden: The session belongs to another user.",                     body = Details),
+        (status = 404, description = "Not Found: The session was not found.",                               body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AuthJWT(user): AuthJWT,
+    Path(session_id): Path<i64>,
+) -> Result<impl IntoResponse, TerminateSessionError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%session_id, %user.id, "Revoking session...");
+
+    SessionService::revoke_session_of(user.id, session_id, &transaction).await?;
+
+    tracing::trace!(%session_id, %user.id, "Session was successfully revoked. Committing changes...");
+    transaction.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
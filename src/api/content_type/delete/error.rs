@@ -0,0 +1,34 @@
+use crate::{services::content_type::error::ServiceError as ContentTypeServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DeleteContentTypeError {
+    #[error(transparent)]
+    ContentTypeServiceError(#[from] ContentTypeServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<DeleteContentTypeError> for StatusCode {
+    fn from(value: DeleteContentTypeError) -> Self {
+        match value {
+            DeleteContentTypeError::ContentTypeServiceError(error) => Self::from(error),
+            DeleteContentTypeError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DeleteContentTypeError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,63 @@
+use super::error::DeleteContentTypeError;
+use crate::{extractors::*, services::content_type::service::Service as ContentTypeService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Delete content type override by extension for specified subdomain.
+///
+/// Files with the extension are served with the type guessed on upload again.
+#[utoipa::path(
+    delete,
+    tag = "Content Types Management",
+    operation_id = "Delete content type by extension",
+    path = "/api/content-type/{extension}",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("extension" = String, Path, description = "Extension of the override to delete"),
+    ),
+    responses(
+        (status = 204, description = "Content type was successfully deleted for subdomain."),
+        (status = 400, description = "The 'x-subdomain' header is missing or extension is invalid.",                                        body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or content type was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(extension): Path<String>,
+) -> Result<impl IntoResponse, DeleteContentTypeError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(
+        %extension,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Deleting content type for subdomain...",
+    );
+
+    let rows_affected = ContentTypeService::delete_for(subdomain.id, &extension, &transaction).await?;
+
+    tracing::trace!(
+        %extension,
+        %subdomain.name,
+        %subdomain.id,
+        %rows_affected,
+        %user.id,
+        "Content type was successfully deleted for subdomain. Committing changes...",
+    );
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            content_type::{delete::tests::call::tests::delete, set::tests::call::tests::set},
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let set_response = set(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "html",
+            "text/plain; charset=utf-8",
+        )
+        .await;
+        assert!(set_response.is_ok());
+
+        let delete_response = delete(&client, &first_user_token, &first_random_subdomain, "html").await;
+        assert_eq!(delete_response, Ok(()));
+
+        //* Guessed type is served again
+        let guessed_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(
+            guessed_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+
+        let second_delete_response = delete(&client, &first_user_token, &first_random_subdomain, "html").await;
+        assert_eq!(
+            second_delete_response.expect_err("Deleted override was found!").0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{services::content_type::error::ServiceError as ContentTypeServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListContentTypesError {
+    #[error(transparent)]
+    ContentTypeServiceError(#[from] ContentTypeServiceError),
+}
+
+impl From<ListContentTypesError> for StatusCode {
+    fn from(value: ListContentTypesError) -> Self {
+        match value {
+            ListContentTypesError::ContentTypeServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ListContentTypesError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,53 @@
+use super::{error::ListContentTypesError, response::ListContentTypesResponse};
+use crate::{extractors::*, services::content_type::service::Service as ContentTypeService, state::State as AppState};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List all content type overrides for specified subdomain.
+///
+/// The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    get,
+    tag = "Content Types Management",
+    operation_id = "Get all content types",
+    path = "/api/content-type",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 200, description = "Content types were successfully retrieved for subdomain.",                            body = ListContentTypesResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<impl IntoResponse, ListContentTypesError> {
+    tracing::trace!(
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Retrieving content types list for subdomain...",
+    );
+
+    let content_types = ContentTypeService::retrieve_for(subdomain.id, state.connection()).await?;
+
+    tracing::trace!(
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        amount = content_types.len(),
+        "Content types list was successfully retrieved!",
+    );
+
+    Ok(Json(ListContentTypesResponse { content_types }))
+}
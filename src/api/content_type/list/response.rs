@@ -0,0 +1,10 @@
+use entity::prelude::ContentTypeModel;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"content_types": [{"id": 42, "subdomain_id": 1, "extension": "wasm", "value": "application/wasm"}]}))]
+pub struct ListContentTypesResponse {
+    /// Overrides of the site ordered by extension
+    pub content_types: Vec<ContentTypeModel>,
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            content_type::{list::tests::call::tests::list, set::tests::call::tests::set},
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+    };
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let list_response = list(&client, &first_user_token, &first_random_subdomain).await;
+        assert!(list_response.is_ok_and(|response| response.content_types.is_empty()));
+
+        for (extension, content_type) in [("wasm", "application/wasm"), ("data", "application/json")] {
+            let set_response = set(
+                &client,
+                &first_user_token,
+                &first_random_subdomain,
+                extension,
+                content_type,
+            )
+            .await;
+            assert!(set_response.is_ok());
+        }
+
+        //* Overrides are listed in order of extension
+        let content_types = list(&client, &first_user_token, &first_random_subdomain)
+            .await
+            .expect("Failed to list content types!")
+            .content_types;
+        assert_eq!(
+            content_types
+                .iter()
+                .map(|content_type| (content_type.extension.as_str(), content_type.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("data", "application/json"), ("wasm", "application/wasm")]
+        );
+    }
+}
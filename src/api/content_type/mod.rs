@@ -0,0 +1,32 @@
+use crate::state::State as AppState;
+use axum::{
+    routing::{delete, get, put},
+    Router,
+};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub mod delete;
+pub mod list;
+pub mod set;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", put(set::handler::implementation))
+        .route("/", get(list::handler::implementation))
+        .route("/:extension", delete(delete::handler::implementation))
+}
+
+// We need this as utoipa
+// currently does not support types
+// from external crates
+#[derive(ToSchema)]
+#[schema(as = ContentTypeModel)]
+pub struct ContentTypeModelSchema {
+    pub id: i64,
+    pub subdomain_id: i64,
+    /// Lowercase extension without leading dot
+    pub extension: String,
+    /// Value of `Content-Type` header
+    pub value: String,
+}
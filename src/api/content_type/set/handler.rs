@@ -0,0 +1,63 @@
+use super::{error::SetContentTypeError, request::SetContentTypeRequest};
+use crate::{extractors::*, services::content_type::service::Service as ContentTypeService, state::State as AppState};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// Sets content type of files with extension for a specified subdomain.
+///
+/// Files with the extension are served with this `Content-Type`
+/// instead of the one guessed on upload. Existing override
+/// of the extension is replaced. Change applies at once without redeploy.
+#[utoipa::path(
+    put,
+    tag = "Content Types Management",
+    operation_id = "Set content type",
+    path = "/api/content-type",
+    request_body = SetContentTypeRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 200, description = "The content type was successfully set.",                                              body = ContentTypeModel),
+        (status = 400, description = "The 'x-subdomain' header is missing or extension or content type is invalid.",        body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<SetContentTypeRequest>,
+) -> Result<impl IntoResponse, SetContentTypeError> {
+    tracing::trace!(
+        %payload.extension,
+        %payload.content_type,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Setting content type for subdomain...",
+    );
+
+    let content_type = ContentTypeService::set_for(
+        subdomain.id,
+        payload.extension,
+        payload.content_type,
+        state.connection(),
+    )
+    .await?;
+    tracing::trace!(
+        ?content_type,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Content type was successfully set for subdomain!",
+    );
+
+    Ok(Json(content_type))
+}
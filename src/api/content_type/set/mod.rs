@@ -0,0 +1,5 @@
+pub mod error;
+pub mod handler;
+pub mod request;
+#[cfg(test)]
+pub mod tests;
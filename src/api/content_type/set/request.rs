@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"extension": "wasm", "content_type": "application/wasm"}))]
+pub struct SetContentTypeRequest {
+    /// Extension of files to serve with the content type
+    /// Leading dot is optional and case is ignored
+    pub extension: String,
+    /// Value of `Content-Type` header including parameters such as charset
+    pub content_type: String,
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{content_type::set::request::SetContentTypeRequest, tests::put},
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use entity::prelude::ContentTypeModel;
+    use std::fmt::Display;
+
+    pub async fn set<T, S, E, V>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        extension: E,
+        content_type: V,
+    ) -> Result<ContentTypeModel, (StatusCode, Details)>
+    where
+        T: Display,
+        S: AsRef<str> + Display,
+        E: Into<String>,
+        V: Into<String>,
+    {
+        let request = SetContentTypeRequest {
+            extension: extension.into(),
+            content_type: content_type.into(),
+        };
+
+        let response = put(client, "/api/content-type", Some(request))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
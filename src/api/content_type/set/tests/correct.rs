@@ -0,0 +1,120 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            content_type::set::tests::call::tests::set,
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let guessed_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(
+            guessed_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+
+        //* Extension is normalized
+        let set_response = set(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            ".HTML",
+            "text/plain; charset=utf-8",
+        )
+        .await
+        .expect("Failed to set content type!");
+        assert_eq!(set_response.extension, "html");
+
+        //* Override is applied without redeploy
+        let overridden_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(overridden_page_response.status_code(), StatusCode::OK);
+        assert_eq!(
+            overridden_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
+
+        //* Existing override is replaced
+        let replaced_response = set(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "html",
+            "application/xhtml+xml",
+        )
+        .await
+        .expect("Failed to replace content type!");
+        assert_eq!(replaced_response.id, set_response.id);
+
+        let replaced_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(
+            replaced_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("application/xhtml+xml"))
+        );
+
+        for (extension, content_type) in [
+            ("html", "not a content type"),
+            ("a/b", "text/plain"),
+            ("", "text/plain"),
+        ] {
+            let invalid_response = set(
+                &client,
+                &first_user_token,
+                &first_random_subdomain,
+                extension,
+                content_type,
+            )
+            .await;
+            assert_eq!(
+                invalid_response.expect_err("Invalid override was set!").0,
+                StatusCode::BAD_REQUEST
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::error::ChallengeError;
+use crate::{http::host, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{OriginalUri, Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// Answers verification requests for custom domains pointing to this server
+#[tracing::instrument(skip(state, headers))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, ChallengeError> {
+    let Some(host) = host::host(&headers, &uri) else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    Ok(
+        match DomainService::is_challenge_of(&host, &token, state.connection()).await? {
+            true => {
+                tracing::trace!(%host, "Answering challenge for custom domain");
+                ([(header::CONTENT_TYPE, "text/plain")], token).into_response()
+            }
+            false => StatusCode::NOT_FOUND.into_response(),
+        },
+    )
+}
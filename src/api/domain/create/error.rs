@@ -0,0 +1,30 @@
+use crate::{services::domain::error::ServiceError as DomainServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum AddDomainError {
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
+}
+
+impl From<AddDomainError> for StatusCode {
+    fn from(value: AddDomainError) -> Self {
+        match value {
+            AddDomainError::DomainServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for AddDomainError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,81 @@
+use super::{error::AddDomainError, request::AddDomainRequest, response::AddDomainResponse};
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+/// Adds a custom domain to a specified subdomain.
+///
+/// The domain is not served until its ownership is verified. To verify it point the domain
+/// to this server so it serves returned token from `/.well-known/sero-challenge/<token>`
+/// and call the verification endpoint. Unverified domain can be claimed by another site
+/// once `DOMAIN_CLAIM_TTL_SECONDS` have passed. The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    post,
+    tag = "Custom Domains Management",
+    operation_id = "Create domain",
+    path = "/api/domain",
+    request_body = AddDomainRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 201, description = "The domain was successfully added and waits for verification.",                        body = AddDomainResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or domain is invalid or belongs to base domain.",  body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                           body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                   body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",   body = Details),
+        (status = 409, description = "Conflict: The domain is verified or recently claimed by some subdomain.",              body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<AddDomainRequest>,
+) -> Result<impl IntoResponse, AddDomainError> {
+    tracing::trace!(
+        %payload.domain,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Adding custom domain for subdomain...",
+    );
+
+    let added_domain = DomainService::add_domain_for(
+        subdomain.id,
+        payload.domain,
+        state.configuration().base_domains(),
+        state.configuration().domain_claim_ttl_seconds(),
+        state.connection(),
+    )
+    .await?;
+    tracing::trace!(
+        ?added_domain,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Custom domain was successfully added to subdomain!",
+    );
+
+    let id = added_domain.id;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/domain/{id}"))],
+        Json(AddDomainResponse {
+            id,
+            domain: added_domain.name,
+            token: added_domain.token,
+        }),
+    ))
+}
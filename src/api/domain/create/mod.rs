@@ -0,0 +1,6 @@
+pub mod error;
+pub mod handler;
+pub mod request;
+pub mod response;
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"domain": "docs.example.com"}))]
+pub struct AddDomainRequest {
+    /// Custom domain to be attached to subdomain
+    pub domain: String,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"id": 42, "domain": "docs.example.com", "token": "0f8d1b5c1e6e4c7a9d6f3a2b1c0d9e8f"}))]
+pub struct AddDomainResponse {
+    /// Automatically generated id for new domain
+    /// This can be used for further management
+    pub id: i64,
+    /// Normalized domain name
+    pub domain: String,
+    /// Token which must be served from
+    /// `http://<domain>/.well-known/sero-challenge/<token>`
+    /// to verify ownership of the domain
+    pub token: String,
+}
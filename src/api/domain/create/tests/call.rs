@@ -0,0 +1,40 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            domain::create::{request::AddDomainRequest, response::AddDomainResponse},
+            tests::post,
+        },
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn create<T, S, D>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        domain: D,
+    ) -> Result<AddDomainResponse, (StatusCode, Details)>
+    where
+        T: AsRef<str> + Display,
+        S: AsRef<str> + Display,
+        D: Into<String>,
+    {
+        let domain = AddDomainRequest { domain: domain.into() };
+
+        let response = post(client, "/api/domain", Some(domain))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
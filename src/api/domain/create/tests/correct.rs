@@ -0,0 +1,159 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            domain::create::tests::call::tests::create,
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use chrono::{Duration, Utc};
+    use entity::prelude::*;
+    use sea_orm::{prelude::*, sea_query::Expr};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let second_user_login = Uuid::new_v4();
+        let second_user_password = Uuid::new_v4();
+
+        let second_user_registration_request = RegistrationRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+        let second_user_registration_response = registration(&client, &second_user_registration_request).await;
+        assert!(second_user_registration_response.is_ok());
+
+        let second_user_login_request = LoginRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+        let second_user_login_response = login(&client, &second_user_login_request).await;
+        assert!(second_user_login_response.is_ok());
+        let second_user_token = second_user_login_response.expect("to never fail").token;
+
+        let second_random_subdomain = Uuid::new_v4().to_string();
+
+        let second_correct_upload_response = upload(
+            &client,
+            &second_user_token,
+            &second_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(second_correct_upload_response, Ok(()));
+
+        //* Domain is normalized and waits for verification
+        let random_domain = format!("{}.example.com", Uuid::new_v4());
+        let add_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            format!("{}.", random_domain.to_uppercase()),
+        )
+        .await
+        .expect("Failed to add domain!");
+        assert_eq!(add_domain_response.domain, random_domain);
+        assert!(!add_domain_response.token.is_empty());
+
+        //* Domain can be attached only to one site
+        let taken_domain_response = create(&client, &second_user_token, &second_random_subdomain, &random_domain).await;
+        assert_eq!(
+            taken_domain_response.map_err(|(status, _)| status),
+            Err(StatusCode::CONFLICT)
+        );
+
+        //* Unverified claim expires and the domain can be claimed again
+        DomainEntity::update_many()
+            .col_expr(DomainColumn::CreatedAt, Expr::value(Utc::now() - Duration::days(2)))
+            .filter(DomainColumn::Id.eq(add_domain_response.id))
+            .exec(state.connection())
+            .await
+            .expect("Failed to backdate domain claim!");
+
+        let reclaimed_domain_response = create(&client, &second_user_token, &second_random_subdomain, &random_domain)
+            .await
+            .expect("Failed to claim expired domain!");
+        assert_ne!(reclaimed_domain_response.id, add_domain_response.id);
+        assert_ne!(reclaimed_domain_response.token, add_domain_response.token);
+
+        //* Invalid domains
+        for invalid_domain in ["localhost", "not a domain", "-example.com", "127.0.0.1", "example..com"] {
+            let invalid_domain_response =
+                create(&client, &first_user_token, &first_random_subdomain, invalid_domain).await;
+            assert_eq!(
+                invalid_domain_response.map_err(|(status, _)| status),
+                Err(StatusCode::BAD_REQUEST)
+            );
+        }
+
+        //* Hosts under base domain are routed to subdomains already
+        let base_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            format!("{second_random_subdomain}.sero.test"),
+        )
+        .await;
+        assert_eq!(
+            base_domain_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        //* Subdomain of another user
+        let foreign_subdomain_response = create(
+            &client,
+            &first_user_token,
+            &second_random_subdomain,
+            format!("{}.example.com", Uuid::new_v4()),
+        )
+        .await;
+        assert_eq!(
+            foreign_subdomain_response.map_err(|(status, _)| status),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use super::error::DeleteDomainError;
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Delete custom domain by id for specified subdomain.
+///
+/// The domain stops being served right after deletion. The action is authenticated using a JWT,
+/// and the subdomain must be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    delete,
+    tag = "Custom Domains Management",
+    operation_id = "Delete domain by id",
+    path = "/api/domain/{id}",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the domain to delete"),
+    ),
+    responses(
+        (status = 204, description = "Domain was successfully deleted for subdomain."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                           body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                    body = Details),
+        (status = 403, description = "Forbidden: The domain is owned by another user.",                                               body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or domain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                       body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(domain_id): Path<i64>,
+) -> Result<impl IntoResponse, DeleteDomainError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(
+        %domain_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Deleting custom domain of subdomain...",
+    );
+
+    let domain = DomainService::fetch_domain_of(subdomain.id, domain_id, &transaction).await?;
+    let rows_affected = DomainService::delete_domain_of(subdomain.id, domain_id, &transaction).await?;
+
+    transaction.commit().await?;
+    state.certificates().remove(&domain.name);
+    tracing::trace!(
+        %domain_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        %rows_affected,
+        "Custom domain was successfully deleted. Changes were successfully committed!",
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
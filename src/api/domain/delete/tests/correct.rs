@@ -0,0 +1,118 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            domain::{
+                create::tests::call::tests::create, delete::tests::call::tests::delete, list::tests::call::tests::list,
+            },
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let first_random_domain = format!("{}.example.com", Uuid::new_v4());
+        let second_random_domain = format!("{}.example.org", Uuid::new_v4());
+
+        let first_add_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &first_random_domain,
+        )
+        .await
+        .expect("Failed to add domain!");
+        let second_add_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &second_random_domain,
+        )
+        .await
+        .expect("Failed to add domain!");
+
+        //* Delete one of domains
+        let delete_domain_response = delete(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            first_add_domain_response.id,
+        )
+        .await;
+        assert!(delete_domain_response.is_ok());
+
+        let list_domains_response = list(&client, &first_user_token, &first_random_subdomain)
+            .await
+            .expect("Failed to list domains!");
+        assert_eq!(list_domains_response.domains.len(), 1);
+        assert_eq!(list_domains_response.domains[0].id, second_add_domain_response.id);
+
+        //* Deleted domain is not found anymore
+        let delete_domain_response = delete(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            first_add_domain_response.id,
+        )
+        .await;
+        assert_eq!(
+            delete_domain_response.map_err(|(status, _)| status),
+            Err(StatusCode::NOT_FOUND)
+        );
+
+        //* Domain can be added again after deletion
+        let add_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &first_random_domain,
+        )
+        .await;
+        assert!(add_domain_response.is_ok());
+    }
+}
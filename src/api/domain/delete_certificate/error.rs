@@ -0,0 +1,34 @@
+use crate::{services::domain::error::ServiceError as DomainServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DeleteCertificateError {
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<DeleteCertificateError> for StatusCode {
+    fn from(value: DeleteCertificateError) -> Self {
+        match value {
+            DeleteCertificateError::DomainServiceError(error) => Self::from(error),
+            DeleteCertificateError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DeleteCertificateError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,61 @@
+use super::error::DeleteCertificateError;
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Delete TLS certificate of custom domain.
+///
+/// TLS listener falls back to the default certificate for the domain. The action is authenticated using a JWT,
+/// and the subdomain must be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    delete,
+    tag = "Custom Domains Management",
+    operation_id = "Delete domain certificate",
+    path = "/api/domain/{id}/certificate",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the domain"),
+    ),
+    responses(
+        (status = 204, description = "Certificate was successfully deleted."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                                           body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                                    body = Details),
+        (status = 403, description = "Forbidden: The domain is owned by another user.",                                                               body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or domain or certificate was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                                       body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(domain_id): Path<i64>,
+) -> Result<impl IntoResponse, DeleteCertificateError> {
+    let transaction = state.connection().begin().await?;
+
+    let domain = DomainService::clear_certificate_of(subdomain.id, domain_id, &transaction).await?;
+
+    transaction.commit().await?;
+    state.certificates().remove(&domain.name);
+
+    //? Domain without certificate gets one issued if ACME is enabled
+    state.challenges().request_renewal();
+
+    tracing::trace!(
+        %domain.name,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Certificate of custom domain was successfully deleted!",
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,47 @@
+use super::{error::ListDomainsError, response::ListDomainsResponse};
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List all custom domains of specified subdomain.
+///
+/// Both verified and pending domains are listed. The action is authenticated using a JWT,
+/// and the subdomain must be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    get,
+    tag = "Custom Domains Management",
+    operation_id = "Get all domains",
+    path = "/api/domain",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+    ),
+    responses(
+        (status = 200, description = "Domains were successfully retrieved.",                                                body = ListDomainsResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<impl IntoResponse, ListDomainsError> {
+    tracing::trace!(%subdomain.name, %subdomain.id, %user.id, "Retrieving custom domains of subdomain...");
+
+    let domains = DomainService::retrieve_domains_for(subdomain.id, state.connection()).await?;
+    tracing::trace!(
+        amount = domains.len(),
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Custom domains were successfully retrieved!",
+    );
+
+    Ok(Json(ListDomainsResponse { domains }))
+}
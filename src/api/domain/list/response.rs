@@ -0,0 +1,18 @@
+use entity::prelude::DomainModel;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"domains": [{
+    "id": 42,
+    "subdomain_id": 1,
+    "name": "docs.example.com",
+    "token": "0f8d1b5c1e6e4c7a9d6f3a2b1c0d9e8f",
+    "verified": true,
+    "created_at": "2024-10-17T12:00:00+00:00",
+    "verified_at": "2024-10-17T12:05:00+00:00"
+}]}))]
+pub struct ListDomainsResponse {
+    /// List of retrieved custom domains
+    pub domains: Vec<DomainModel>,
+}
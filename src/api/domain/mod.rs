@@ -0,0 +1,43 @@
+use crate::state::State as AppState;
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+use chrono::{DateTime, FixedOffset};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub mod challenge;
+pub mod create;
+pub mod delete;
+pub mod delete_certificate;
+pub mod list;
+pub mod retrieve;
+pub mod upload_certificate;
+pub mod verify;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(create::handler::implementation))
+        .route("/", get(list::handler::implementation))
+        .route("/:id", get(retrieve::handler::implementation))
+        .route("/:id", delete(delete::handler::implementation))
+        .route("/:id/verify", post(verify::handler::implementation))
+        .route("/:id/certificate", put(upload_certificate::handler::implementation))
+        .route("/:id/certificate", delete(delete_certificate::handler::implementation))
+}
+
+// We need this as utoipa
+// currently does not support types
+// from external crates
+#[derive(ToSchema)]
+#[schema(as = DomainModel)]
+pub struct DomainModelSchema {
+    pub id: i64,
+    pub subdomain_id: i64,
+    pub name: String,
+    pub token: String,
+    pub verified: bool,
+    pub created_at: DateTime<FixedOffset>,
+    pub verified_at: Option<DateTime<FixedOffset>>,
+}
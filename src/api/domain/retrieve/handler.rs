@@ -0,0 +1,58 @@
+use super::{error::GetDomainError, response::GetDomainResponse};
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+/// Get specified custom domain [by id] of specified subdomain.
+///
+/// The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    get,
+    tag = "Custom Domains Management",
+    operation_id = "Get domain by id",
+    path = "/api/domain/{id}",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the domain to retrieve"),
+    ),
+    responses(
+        (status = 200, description = "Domain was successfully retrieved.",                                                            body = GetDomainResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                           body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                    body = Details),
+        (status = 403, description = "Forbidden: The subdomain or domain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or domain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                       body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(domain_id): Path<i64>,
+) -> Result<impl IntoResponse, GetDomainError> {
+    tracing::trace!(
+        %domain_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Retrieving custom domain by id for subdomain...",
+    );
+    let domain = DomainService::fetch_domain_of(subdomain.id, domain_id, state.connection()).await?;
+    tracing::trace!(
+        ?domain,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Custom domain was successfully retrieved by id",
+    );
+
+    Ok(Json(GetDomainResponse { domain }))
+}
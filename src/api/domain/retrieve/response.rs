@@ -0,0 +1,18 @@
+use entity::prelude::DomainModel;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"domain": {
+    "id": 42,
+    "subdomain_id": 1,
+    "name": "docs.example.com",
+    "token": "0f8d1b5c1e6e4c7a9d6f3a2b1c0d9e8f",
+    "verified": false,
+    "created_at": "2024-10-17T12:00:00+00:00",
+    "verified_at": null
+}}))]
+pub struct GetDomainResponse {
+    /// Retrieved custom domain
+    pub domain: DomainModel,
+}
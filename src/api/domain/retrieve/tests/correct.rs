@@ -0,0 +1,76 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            domain::{create::tests::call::tests::create, retrieve::tests::call::tests::retrieve},
+            site::upload::tests::call::tests::upload,
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let random_domain = format!("{}.example.com", Uuid::new_v4());
+        let create_response = create(&client, &first_user_token, &first_random_subdomain, &random_domain)
+            .await
+            .expect("Failed to add domain!");
+
+        let retrieve_response = retrieve(&client, &first_user_token, &first_random_subdomain, create_response.id)
+            .await
+            .expect("Failed to retrieve domain!");
+        assert_eq!(retrieve_response.domain.name, random_domain);
+        assert_eq!(retrieve_response.domain.token, create_response.token);
+        assert!(!retrieve_response.domain.verified);
+
+        //* Unknown domain
+        let retrieve_response = retrieve(&client, &first_user_token, &first_random_subdomain, i64::MAX).await;
+        assert_eq!(
+            retrieve_response.map_err(|(status, _)| status),
+            Err(StatusCode::NOT_FOUND)
+        );
+    }
+}
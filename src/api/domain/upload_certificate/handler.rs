@@ -0,0 +1,86 @@
+use super::{error::UploadCertificateError, request::UploadCertificateRequest, response::UploadCertificateResponse};
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Upload TLS certificate for custom domain.
+///
+/// Previous certificate of the domain is replaced. Certificate of verified domain is served
+/// by TLS listener right away and pending domain starts using it after verification.
+/// Uploaded certificate is never replaced by automatic issuance.
+/// The action is authenticated using a JWT, and the subdomain must be owned by the user making the request.
+/// This will be checked by the server.
+#[utoipa::path(
+    put,
+    tag = "Custom Domains Management",
+    operation_id = "Upload domain certificate",
+    path = "/api/domain/{id}/certificate",
+    request_body = UploadCertificateRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the domain"),
+    ),
+    responses(
+        (status = 200, description = "Certificate was successfully stored.",                                                          body = UploadCertificateResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or certificate is invalid or not valid for the domain.",     body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                    body = Details),
+        (status = 403, description = "Forbidden: The domain is owned by another user.",                                               body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or domain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                       body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state, payload))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(domain_id): Path<i64>,
+    Json(payload): Json<UploadCertificateRequest>,
+) -> Result<impl IntoResponse, UploadCertificateError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(
+        %domain_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Storing certificate of custom domain...",
+    );
+
+    let (domain, parsed) = DomainService::set_certificate_of(
+        subdomain.id,
+        domain_id,
+        payload.certificate,
+        payload.private_key,
+        false,
+        &transaction,
+    )
+    .await?;
+
+    transaction.commit().await?;
+    tracing::trace!(
+        %domain.name,
+        %domain.verified,
+        not_after = %parsed.not_after,
+        %subdomain.id,
+        %user.id,
+        "Certificate was successfully stored!",
+    );
+
+    //? Pending domain must not be served
+    if domain.verified {
+        state.certificates().insert(&domain.name, parsed.key);
+    }
+
+    Ok(Json(UploadCertificateResponse {
+        domain: domain.name,
+        names: parsed.names,
+        expires_at: parsed.not_after,
+    }))
+}
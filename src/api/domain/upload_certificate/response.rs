@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"domain": "docs.example.com", "names": ["docs.example.com"], "expires_at": "2025-10-17T12:00:00Z"}))]
+pub struct UploadCertificateResponse {
+    pub domain: String,
+    /// DNS names the certificate is valid for
+    pub names: Vec<String>,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            domain::upload_certificate::{request::UploadCertificateRequest, response::UploadCertificateResponse},
+            tests::put,
+        },
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::{fmt::Display, path::Path};
+
+    pub async fn upload_certificate<T, S, P>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        id: i64,
+        certificate: P,
+        private_key: P,
+    ) -> Result<UploadCertificateResponse, (StatusCode, Details)>
+    where
+        T: Display,
+        S: AsRef<str> + Display,
+        P: AsRef<Path>,
+    {
+        let request = UploadCertificateRequest {
+            certificate: tokio::fs::read_to_string(certificate)
+                .await
+                .expect("Failed to read certificate!"),
+            private_key: tokio::fs::read_to_string(private_key)
+                .await
+                .expect("Failed to read private key!"),
+        };
+
+        let response = put(client, &format!("/api/domain/{id}/certificate"), Some(request))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
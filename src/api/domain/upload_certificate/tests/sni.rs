@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            domain::{
+                create::tests::call::tests::create, delete_certificate::tests::call::tests::delete_certificate,
+                upload_certificate::tests::call::tests::upload_certificate,
+            },
+            site::{teardown::tests::call::tests::teardown, upload::tests::call::tests::upload},
+        },
+        app,
+        services::domain::{challenge::Challenger, service::Service as DomainService},
+        tls,
+    };
+    use axum_test::TestServer as TestClient;
+    use entity::prelude::*;
+    use sea_orm::prelude::*;
+    use std::{io, net::SocketAddr, path::Path, sync::Arc};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::watch,
+    };
+    use tokio_rustls::{
+        client::TlsStream,
+        rustls::{pki_types::ServerName, ClientConfig, RootCertStore},
+        TlsConnector,
+    };
+    use uuid::Uuid;
+
+    //? Client trusts only test certificate authority
+    //? so handshake fails if served certificate does not match the name
+    async fn handshake(address: SocketAddr, name: &str) -> io::Result<TlsStream<TcpStream>> {
+        let authority = tokio::fs::read("./assets/certificates/ca.pem").await?;
+
+        let mut roots = RootCertStore::empty();
+        for certificate in rustls_pemfile::certs(&mut authority.as_slice()) {
+            roots.add(certificate?).map_err(io::Error::other)?;
+        }
+
+        let configuration = ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let server_name = ServerName::try_from(name.to_owned()).map_err(io::Error::other)?;
+
+        TlsConnector::from(Arc::new(configuration))
+            .connect(server_name, TcpStream::connect(address).await?)
+            .await
+    }
+
+    async fn leaf<P>(path: P) -> Vec<u8>
+    where
+        P: AsRef<Path>,
+    {
+        let chain = tokio::fs::read(path).await.expect("Failed to read certificate!");
+        rustls_pemfile::certs(&mut chain.as_slice())
+            .next()
+            .expect("Certificate must be present!")
+            .expect("Failed to parse certificate!")
+            .to_vec()
+    }
+
+    fn served(stream: &TlsStream<TcpStream>) -> Vec<u8> {
+        stream
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|chain| chain.first())
+            .map(|certificate| certificate.to_vec())
+            .unwrap_or_default()
+    }
+
+    async fn request(mut stream: TlsStream<TcpStream>, host: &str) -> String {
+        stream
+            .write_all(format!("GET /some/index.html HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n").as_bytes())
+            .await
+            .expect("Failed to send request!");
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await.ok();
+        String::from_utf8_lossy(&response).into_owned()
+    }
+
+    #[tokio::test]
+    async fn sni() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app.clone()).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Plain listener answers verification challenges
+        let plain_listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind plain listener!");
+        let plain_address = plain_listener.local_addr().expect("Failed to get plain address!");
+        let plain_app = app.clone();
+        tokio::spawn(async move { axum::serve(plain_listener, plain_app).await });
+
+        let tls_listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind tls listener!");
+        let tls_address = tls_listener.local_addr().expect("Failed to get tls address!");
+        //? Sender is kept until the end of the test so listener is never shut down
+        let (_shutdown_sender, shutdown) = watch::channel(());
+        tokio::spawn(tls::server::serve(
+            tls_listener,
+            state.certificates().clone(),
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+            shutdown,
+        ));
+
+        //* Default certificate is served for base domain
+        let base_host = format!("{first_random_subdomain}.sero.test");
+        let stream = handshake(tls_address, &base_host)
+            .await
+            .expect("Handshake with default certificate failed!");
+        assert_eq!(served(&stream), leaf("./assets/certificates/base.pem").await);
+        assert!(request(stream, &base_host).await.starts_with("HTTP/1.1 200"));
+
+        //* Verified custom domain without certificate gets default one
+        let random_domain = format!("{}.example.com", Uuid::new_v4());
+        let add_domain_response = create(&client, &first_user_token, &first_random_subdomain, &random_domain)
+            .await
+            .expect("Failed to add domain!");
+
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&first_random_subdomain))
+            .one(state.connection())
+            .await
+            .expect("Failed to find subdomain!")
+            .expect("Subdomain must exist!");
+
+        DomainService::verify_domain_of(
+            subdomain.id,
+            add_domain_response.id,
+            &Challenger::with_address(plain_address),
+            state.connection(),
+        )
+        .await
+        .expect("Failed to verify domain!");
+
+        assert!(handshake(tls_address, &random_domain).await.is_err());
+
+        //* Uploaded certificate is served without restart
+        let upload_certificate_response = upload_certificate(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            add_domain_response.id,
+            "./assets/certificates/example.pem",
+            "./assets/certificates/example.key",
+        )
+        .await;
+        assert!(upload_certificate_response.is_ok());
+
+        let stream = handshake(tls_address, &random_domain)
+            .await
+            .expect("Handshake with uploaded certificate failed!");
+        assert_eq!(served(&stream), leaf("./assets/certificates/example.pem").await);
+        assert!(request(stream, &random_domain).await.starts_with("HTTP/1.1 200"));
+
+        //* Deleted certificate is not served anymore
+        let delete_certificate_response = delete_certificate(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            add_domain_response.id,
+        )
+        .await;
+        assert!(delete_certificate_response.is_ok());
+
+        assert!(handshake(tls_address, &random_domain).await.is_err());
+
+        //* Certificate is not served after the site is removed
+        let upload_certificate_response = upload_certificate(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            add_domain_response.id,
+            "./assets/certificates/example.pem",
+            "./assets/certificates/example.key",
+        )
+        .await;
+        assert!(upload_certificate_response.is_ok());
+        assert!(handshake(tls_address, &random_domain).await.is_ok());
+
+        let teardown_response = teardown(&client, &first_random_subdomain, &first_user_token).await;
+        assert!(teardown_response.is_ok());
+
+        assert!(handshake(tls_address, &random_domain).await.is_err());
+    }
+}
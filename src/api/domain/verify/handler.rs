@@ -0,0 +1,75 @@
+use super::{error::VerifyDomainError, response::VerifyDomainResponse};
+use crate::{extractors::*, services::domain::service::Service as DomainService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+/// Verify ownership of custom domain.
+///
+/// The server requests `http://<domain>/.well-known/sero-challenge/<token>` and expects
+/// the token issued for the domain in response body. Domain pointing to this server
+/// passes the check as the server answers challenges itself. Verified domain is served
+/// as the subdomain. The action is authenticated using a JWT, and the subdomain must
+/// be owned by the user making the request. This will be checked by the server.
+#[utoipa::path(
+    post,
+    tag = "Custom Domains Management",
+    operation_id = "Verify domain by id",
+    path = "/api/domain/{id}/verify",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "'x-subdomain' header represents the name of the subdomain on which the action is to be performed."),
+        ("id" = i64, Path, description = "Id of the domain to verify"),
+    ),
+    responses(
+        (status = 200, description = "Domain was successfully verified.",                                                             body = VerifyDomainResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                           body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                                    body = Details),
+        (status = 403, description = "Forbidden: The domain is owned by another user.",                                               body = Details),
+        (status = 404, description = "Not Found: The login or subdomain or domain was not found. See details for more information.",  body = Details),
+        (status = 422, description = "Unprocessable Entity: The token could not be fetched from the domain or did not match.",        body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                                       body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(domain_id): Path<i64>,
+) -> Result<impl IntoResponse, VerifyDomainError> {
+    tracing::trace!(
+        %domain_id,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Verifying custom domain of subdomain...",
+    );
+
+    let domain =
+        DomainService::verify_domain_of(subdomain.id, domain_id, state.challenger(), state.connection()).await?;
+    tracing::trace!(
+        %domain.name,
+        %subdomain.name,
+        %subdomain.id,
+        %user.id,
+        "Custom domain was successfully verified!",
+    );
+
+    //? Certificate uploaded before verification starts being served now.
+    //? Only domain without certificate gets one issued if ACME is enabled
+    match DomainService::certificate_for(&domain, state.connection()).await? {
+        Some(parsed) => state.certificates().insert(&domain.name, parsed.key),
+        None => state.challenges().request_renewal(),
+    }
+
+    Ok(Json(VerifyDomainResponse {
+        id: domain.id,
+        domain: domain.name,
+        verified_at: domain.verified_at,
+    }))
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"id": 42, "domain": "docs.example.com", "verified_at": "2024-10-17T12:05:00+00:00"}))]
+pub struct VerifyDomainResponse {
+    pub id: i64,
+    pub domain: String,
+    /// Time of the last successful verification
+    pub verified_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            domain::{
+                create::tests::call::tests::create, list::tests::call::tests::list, verify::tests::call::tests::verify,
+            },
+            site::upload::tests::call::tests::upload,
+            tests::get,
+        },
+        app,
+        services::domain::{challenge::Challenger, service::Service as DomainService},
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use entity::prelude::*;
+    use sea_orm::prelude::*;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app.clone()).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let random_domain = format!("{}.example.com", Uuid::new_v4());
+        let add_domain_response = create(&client, &first_user_token, &first_random_subdomain, &random_domain)
+            .await
+            .expect("Failed to add domain!");
+
+        //* Pending domain is not served
+        let pending_page_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&random_domain).expect("Failed to convert domain to header value!"),
+            )
+            .await;
+        assert_eq!(pending_page_response.status_code(), StatusCode::BAD_REQUEST);
+
+        //* Server answers challenge for the domain it was issued for
+        let challenge_response = get(
+            &client,
+            format!("/.well-known/sero-challenge/{}", add_domain_response.token),
+        )
+        .add_header(
+            header::HOST,
+            HeaderValue::from_str(&random_domain).expect("Failed to convert domain to header value!"),
+        )
+        .await;
+        assert_eq!(challenge_response.status_code(), StatusCode::OK);
+        assert_eq!(challenge_response.text(), add_domain_response.token);
+
+        let wrong_challenge_response = get(&client, "/.well-known/sero-challenge/wrong")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&random_domain).expect("Failed to convert domain to header value!"),
+            )
+            .await;
+        assert_eq!(wrong_challenge_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Unresolvable domain fails verification
+        let unresolvable_domain = format!("{}.sero.invalid", Uuid::new_v4());
+        let unresolvable_domain_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &unresolvable_domain,
+        )
+        .await
+        .expect("Failed to add domain!");
+
+        let verify_response = verify(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            unresolvable_domain_response.id,
+        )
+        .await;
+        assert_eq!(
+            verify_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+
+        //* Local stand-in plays the role of the server domain points to
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind stand-in server!");
+        let address = listener.local_addr().expect("Failed to get stand-in address!");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&first_random_subdomain))
+            .one(state.connection())
+            .await
+            .expect("Failed to find subdomain!")
+            .expect("Subdomain must exist!");
+
+        let verified_domain = DomainService::verify_domain_of(
+            subdomain.id,
+            add_domain_response.id,
+            &Challenger::with_address(address),
+            state.connection(),
+        )
+        .await
+        .expect("Failed to verify domain!");
+        assert!(verified_domain.verified);
+        assert!(verified_domain.verified_at.is_some());
+
+        let list_domains_response = list(&client, &first_user_token, &first_random_subdomain)
+            .await
+            .expect("Failed to list domains!");
+        assert!(list_domains_response
+            .domains
+            .iter()
+            .any(|domain| domain.id == add_domain_response.id && domain.verified));
+
+        //* Verified domain is served as the site
+        let verified_page_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&format!("{}:8080", random_domain.to_uppercase()))
+                    .expect("Failed to convert domain to header value!"),
+            )
+            .await;
+        assert_eq!(verified_page_response.status_code(), StatusCode::OK);
+    }
+}
@@ -2,15 +2,26 @@ use crate::state::State as AppState;
 use axum::{http::StatusCode, routing::get, Router};
 use std::sync::Arc;
 
+pub mod access;
+pub mod acme;
+pub mod admin;
 pub mod auth;
+pub mod content_type;
+pub mod domain;
 pub mod origin;
 pub mod site;
+pub mod token;
 
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
+        .nest("/access", access::router())
+        .nest("/admin", admin::router())
         .nest("/auth", auth::router())
+        .nest("/content-type", content_type::router())
+        .nest("/domain", domain::router())
         .nest("/origin", origin::router())
         .nest("/site", site::router())
+        .nest("/token", token::router())
         .route("/health", get(|| async { StatusCode::OK }))
 }
 
@@ -37,6 +48,7 @@ pub mod tests {
 
     impl_call!(post);
     impl_call!(patch);
+    impl_call!(put);
     impl_call!(delete);
 
     pub fn get<U>(client: &TestClient, url: U) -> TestRequest
@@ -0,0 +1,34 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConfigureError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+}
+
+impl From<ConfigureError> for StatusCode {
+    fn from(value: ConfigureError) -> Self {
+        match value {
+            ConfigureError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
+            ConfigureError::SiteServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ConfigureError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,64 @@
+use super::{error::ConfigureError, request::ConfigureRequest};
+use crate::{
+    api::site::settings::response::SettingsResponse, extractors::*, services::site::service::Service as SiteService,
+    site::parameters::ConfigureParameters, state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Changes settings of a specific site identified by the `x-subdomain` header.
+///
+/// Only provided settings are changed. Settings from `sero.toml`
+/// in the next uploaded archive will override these values.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Configure site",
+    patch,
+    path = "/api/site/settings",
+    request_body = ConfigureRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Settings were successfully changed",                                                   body = SettingsResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<ConfigureRequest>,
+) -> Result<impl IntoResponse, ConfigureError> {
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Configuring site...");
+    let transaction = state.connection().begin().await?;
+
+    let parameters = ConfigureParameters {
+        subdomain_id: subdomain.id,
+        spa: payload.spa,
+        trailing_slash: payload.trailing_slash,
+        private: payload.private,
+        rate_limit: payload.rate_limit,
+        rate_burst: payload.rate_burst,
+    };
+    let subdomain = SiteService::configure(parameters, &transaction).await?;
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Site was successfully configured. Committing changes...");
+
+    transaction.commit().await?;
+    state.limiter().forget_overrides();
+    Ok(Json(SettingsResponse::from(subdomain)))
+}
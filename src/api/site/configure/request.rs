@@ -0,0 +1,20 @@
+use crate::site::models::TrailingSlash;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"spa": true, "trailing_slash": "always", "private": false}))]
+pub struct ConfigureRequest {
+    /// Serve index.html for unknown extension-less paths
+    /// Omitted field keeps current value
+    pub spa: Option<bool>,
+    /// Canonical form of pages resolved as `path.html` or `path/index.html`
+    pub trailing_slash: Option<TrailingSlash>,
+    /// Serve pages only to visitors with a share link
+    pub private: Option<bool>,
+    /// Requests per minute to pages from a single visitor
+    /// Zero falls back to the server wide limit
+    pub rate_limit: Option<u32>,
+    /// Requests a visitor can make at once. Zero means the same as rate limit
+    pub rate_burst: Option<u32>,
+}
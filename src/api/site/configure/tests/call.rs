@@ -0,0 +1,36 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            site::{configure::request::ConfigureRequest, settings::response::SettingsResponse},
+            tests::patch,
+        },
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn configure<S, T>(
+        client: &TestClient,
+        subdomain: S,
+        token: T,
+        request: &ConfigureRequest,
+    ) -> Result<SettingsResponse, (StatusCode, Details)>
+    where
+        S: AsRef<str>,
+        T: Display,
+    {
+        let response = patch(client, "/api/site/settings", Some(request))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
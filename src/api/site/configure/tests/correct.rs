@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                configure::{request::ConfigureRequest, tests::call::tests::configure},
+                page::tests::call::tests::page,
+                settings::{response::SettingsResponse, tests::call::tests::settings},
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+        site::models::TrailingSlash,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn spa() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-404.html.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Mode is disabled by default
+        let settings_response = settings(&client, &first_random_subdomain, &first_user_token).await;
+        assert_eq!(
+            settings_response,
+            Ok(SettingsResponse {
+                spa: false,
+                trailing_slash: TrailingSlash::Ignore,
+                private: false,
+                rate_limit: None,
+                rate_burst: None
+            })
+        );
+
+        let route_response = page(&client, "/dashboard/settings", &first_random_subdomain).await;
+        assert_eq!(route_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Enabling mode serves index.html for routes
+        let enable_request = ConfigureRequest {
+            spa: Some(true),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &enable_request).await;
+        assert_eq!(
+            configure_response,
+            Ok(SettingsResponse {
+                spa: true,
+                trailing_slash: TrailingSlash::Ignore,
+                private: false,
+                rate_limit: None,
+                rate_burst: None
+            })
+        );
+
+        let route_response = page(&client, "/dashboard/settings", &first_random_subdomain).await;
+        assert_eq!(route_response.status_code(), StatusCode::OK);
+
+        let index_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(route_response.text(), index_response.text());
+
+        //* Missing assets are still not found
+        let asset_response = page(&client, "/assets/missing.js", &first_random_subdomain).await;
+        assert_eq!(asset_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Omitted setting keeps its value
+        let configure_response = configure(
+            &client,
+            &first_random_subdomain,
+            &first_user_token,
+            &ConfigureRequest::default(),
+        )
+        .await;
+        assert_eq!(
+            configure_response,
+            Ok(SettingsResponse {
+                spa: true,
+                trailing_slash: TrailingSlash::Ignore,
+                private: false,
+                rate_limit: None,
+                rate_burst: None
+            })
+        );
+
+        //* Disabling mode brings not found pages back
+        let disable_request = ConfigureRequest {
+            spa: Some(false),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &disable_request).await;
+        assert_eq!(
+            configure_response,
+            Ok(SettingsResponse {
+                spa: false,
+                trailing_slash: TrailingSlash::Ignore,
+                private: false,
+                rate_limit: None,
+                rate_burst: None
+            })
+        );
+
+        let route_response = page(&client, "/dashboard/settings", &first_random_subdomain).await;
+        assert_eq!(route_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Settings are available only to owner
+        let second_user_login = Uuid::new_v4();
+        let second_user_password = Uuid::new_v4();
+
+        let second_user_registration_request = RegistrationRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+
+        let second_user_login_request = LoginRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+
+        let second_user_registration_response = registration(&client, &second_user_registration_request).await;
+        assert!(second_user_registration_response.is_ok());
+
+        let second_user_login_response = login(&client, &second_user_login_request).await;
+        assert!(second_user_login_response.is_ok());
+
+        let second_user_token = second_user_login_response.expect("never fails").token;
+
+        let configure_response = configure(&client, &first_random_subdomain, &second_user_token, &enable_request).await;
+        assert_eq!(
+            configure_response.map_err(|(status, _)| status),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{services::deployment::error::ServiceError as DeploymentServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListDeploymentsError {
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
+}
+
+impl From<ListDeploymentsError> for StatusCode {
+    fn from(value: ListDeploymentsError) -> Self {
+        match value {
+            ListDeploymentsError::DeploymentServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ListDeploymentsError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,47 @@
+use super::{
+    error::ListDeploymentsError,
+    response::{DeploymentResponse, ListDeploymentsResponse},
+};
+use crate::{extractors::*, services::deployment::service::Service as DeploymentService, state::State as AppState};
+use axum::{extract::State, Json};
+use std::sync::Arc;
+
+/// List retained deployments of the specified subdomain.
+///
+/// Every upload creates a deployment. The amount of retained
+/// deployments is configured with `MAX_DEPLOYMENTS` env.
+#[utoipa::path(
+    get,
+    tag = "Actions",
+    operation_id = "List deployments",
+    path = "/api/site/deployments",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Deployments were successfully retrieved.",                                   body = ListDeploymentsResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",        body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                 body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                         body = Details),
+        (status = 404, description = "Not Found: The subdomain was not found.",                                    body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                    body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<Json<ListDeploymentsResponse>, ListDeploymentsError> {
+    tracing::trace!(%user.id, %subdomain.name, "Retrieving deployments...");
+
+    let deployments = DeploymentService::list(subdomain.id, state.connection()).await?;
+
+    tracing::trace!(%user.id, %subdomain.name, amount = deployments.len(), "Deployments were successfully retrieved!");
+
+    Ok(Json(ListDeploymentsResponse {
+        deployments: deployments.into_iter().map(DeploymentResponse::from).collect(),
+    }))
+}
@@ -0,0 +1,35 @@
+use crate::services::deployment::models::DeploymentSummary;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeploymentResponse {
+    pub id: i64,
+    /// Deployment which is currently served
+    pub active: bool,
+    /// Deployed files without precompressed variants
+    pub files: u64,
+    /// Total size of deployed files in bytes
+    pub bytes: u64,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<DeploymentSummary> for DeploymentResponse {
+    fn from(summary: DeploymentSummary) -> Self {
+        Self {
+            id: summary.deployment.id,
+            active: summary.active,
+            files: summary.files,
+            bytes: summary.bytes,
+            created_at: summary.deployment.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"deployments": [{"id": 2, "active": true, "files": 12, "bytes": 48213, "created_at": "2024-11-01T12:00:00+00:00"}, {"id": 1, "active": false, "files": 11, "bytes": 40117, "created_at": "2024-10-31T12:00:00+00:00"}]}))]
+pub struct ListDeploymentsResponse {
+    /// Retained deployments from the newest to the oldest
+    pub deployments: Vec<DeploymentResponse>,
+}
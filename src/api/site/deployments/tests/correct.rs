@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{deployments::tests::call::tests::deployments, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let first_deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(first_deployments_response.deployments.len(), 1);
+
+        let first_deployment = &first_deployments_response.deployments[0];
+        assert!(first_deployment.active);
+        assert_eq!(first_deployment.files, 2);
+        assert_eq!(first_deployment.bytes, 2 * 1222400);
+
+        //* Every upload creates a deployment which becomes active
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert_eq!(second_correct_upload_response, Ok(()));
+
+        let second_deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(second_deployments_response.deployments.len(), 2);
+
+        let (newest, previous) = (
+            &second_deployments_response.deployments[0],
+            &second_deployments_response.deployments[1],
+        );
+        assert!(newest.active);
+        assert_eq!(newest.files, 1);
+        assert!(!previous.active);
+        assert_eq!(previous.id, first_deployment.id);
+        assert_eq!(previous.files, 2);
+
+        //* Only MAX_DEPLOYMENTS are retained
+        for _ in 0..2 {
+            let correct_upload_response = upload(
+                &client,
+                &first_user_token,
+                &first_random_subdomain,
+                "./assets/zips/correct-3.zip",
+            )
+            .await;
+            assert_eq!(correct_upload_response, Ok(()));
+        }
+
+        let retained_deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(retained_deployments_response.deployments.len(), 3);
+        assert!(retained_deployments_response.deployments[0].active);
+        assert!(retained_deployments_response
+            .deployments
+            .iter()
+            .all(|deployment| deployment.id != first_deployment.id));
+    }
+}
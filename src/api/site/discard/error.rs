@@ -0,0 +1,34 @@
+use crate::{services::deployment::error::ServiceError as DeploymentServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DiscardError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
+}
+
+impl From<DiscardError> for StatusCode {
+    fn from(value: DiscardError) -> Self {
+        match value {
+            DiscardError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            DiscardError::DeploymentServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for DiscardError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,60 @@
+use super::error::DiscardError;
+use crate::{extractors::*, services::deployment::service::Service as DeploymentService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Discard preview of the specified subdomain before it expires.
+///
+/// Preview is no longer served and its files
+/// are removed by the cleanup task.
+#[utoipa::path(
+    delete,
+    tag = "Actions",
+    operation_id = "Discard preview",
+    path = "/api/site/previews/{id}",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+        ("id" = i64, Path, description = "Id of the preview to discard"),
+      ),
+    responses(
+        (status = 204, description = "Preview was successfully discarded."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",  body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",           body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                   body = Details),
+        (status = 404, description = "Not Found: The subdomain or preview was not found.",                   body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(deployment_id): Path<i64>,
+) -> Result<impl IntoResponse, DiscardError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%user.id, %subdomain.name, %deployment_id, "Discarding preview...");
+
+    let preview = DeploymentService::discard(subdomain.id, deployment_id, &transaction).await?;
+
+    tracing::trace!(%user.id, %subdomain.name, %preview.id, "Preview was successfully discarded. Committing changes...");
+
+    transaction.commit().await?;
+
+    //? Archive is removed only after the transaction is committed
+    if let Some(archive_path) = preview.archive_path.as_ref() {
+        tokio::fs::remove_file(archive_path)
+            .await
+            .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"))
+            .ok();
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
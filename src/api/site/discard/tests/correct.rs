@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments, discard::tests::call::tests::discard,
+                page::tests::call::tests::page, preview::tests::call::tests::preview,
+                previews::tests::call::tests::previews, upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let preview_response = preview(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await
+        .expect("Failed to upload preview!");
+
+        let discard_response = discard(&client, &first_random_subdomain, &first_user_token, preview_response.id).await;
+        assert_eq!(discard_response, Ok(()));
+
+        //* Discarded preview is no longer served
+        let discarded_page_response = page(&client, "/some/index.html", &preview_response.name).await;
+        assert_eq!(discarded_page_response.status_code(), StatusCode::NOT_FOUND);
+
+        let previews_response = previews(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list previews!");
+        assert!(previews_response.previews.is_empty());
+
+        //* Regular deployments are not previews
+        let deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        let discard_deployment_response = discard(
+            &client,
+            &first_random_subdomain,
+            &first_user_token,
+            deployments_response.deployments[0].id,
+        )
+        .await;
+        assert_eq!(
+            discard_deployment_response.expect_err("Deployment was discarded!").0,
+            StatusCode::NOT_FOUND
+        );
+
+        let production_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(production_page_response.status_code(), StatusCode::OK);
+    }
+}
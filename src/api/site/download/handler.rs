@@ -1,15 +1,23 @@
 use super::error::DownloadError;
 use crate::{
-    extractors::*, services::site::service::Service as SiteService, site::parameters::ActionParameters,
+    extractors::*,
+    http::{conditional, range},
+    services::site::service::Service as SiteService,
+    site::parameters::ActionParameters,
     state::State as AppState,
 };
-use axum::{body::Body, extract::State, response::IntoResponse};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use tokio::fs;
-use tokio_util::io::ReaderStream;
 
 /// Download site of the specified subdomain.
 /// Returns a zip file which was uploaded by user (last)
+/// Supports `Range` requests so interrupted downloads can be resumed.
 #[utoipa::path(
     tag = "Actions",
     operation_id = "Download site",
@@ -22,6 +30,8 @@ use tokio_util::io::ReaderStream;
       ),
     responses(
         (status = 200, description = "Site was successfully downloaded", body = String, content_type = "application/octet-stream"),
+        (status = 206, description = "Requested ranges of the archive", body = String, content_type = "application/octet-stream"),
+        (status = 416, description = "Requested ranges can not be satisfied"),
         (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
         (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
         (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
@@ -30,10 +40,11 @@ use tokio_util::io::ReaderStream;
     ),
     security(("Bearer-JWT" = []))
 )]
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, headers))]
 pub async fn implementation(
     State(state): State<Arc<AppState>>,
     SubdomainOwned { user, subdomain }: SubdomainOwned,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, DownloadError> {
     let parameters = ActionParameters {
         subdomain_id: subdomain.id,
@@ -50,14 +61,34 @@ pub async fn implementation(
                    %user.id,
                    %path, "Site archive filepath was successfully retrieved!");
 
-    Ok(Body::from_stream(ReaderStream::new(
-        fs::File::open(&path).await.inspect_err(|cause| {
-            tracing::info!(%cause, 
-                           %subdomain.name, 
-                           %subdomain.id, 
-                           %user.id,
-                           %path, 
-                           "Site archive filepath was successfully retrieved!")
-        })?,
-    )))
+    //? Modification time is used as validator for If-Range
+    let last_modified = fs::metadata(&path)
+        .await
+        .and_then(|metadata| metadata.modified())
+        .map(DateTime::<Utc>::from)
+        .ok();
+
+    let mut response = range::respond(
+        StatusCode::OK,
+        &headers,
+        &path,
+        String::from("application/octet-stream"),
+        None,
+        last_modified,
+    )
+    .await
+    .inspect_err(|cause| {
+        tracing::info!(%cause, 
+                       %subdomain.name, 
+                       %subdomain.id, 
+                       %user.id,
+                       %path, 
+                       "Failed to open site archive!")
+    })?;
+
+    if let Some(Ok(last_modified)) = last_modified.map(|date| conditional::format_http_date(date).parse()) {
+        response.headers_mut().insert(header::LAST_MODIFIED, last_modified);
+    }
+
+    Ok(response)
 }
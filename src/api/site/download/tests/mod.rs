@@ -1,3 +1,4 @@
 pub mod call;
 pub mod cant_download_removed;
 pub mod correct_many;
+pub mod range;
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::upload::tests::call::tests::upload,
+            tests::get,
+        },
+        app,
+    };
+    use axum::http::{header, HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fs;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn range() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_upload_zip_path = "./assets/zips/correct-1.zip";
+        let first_zip_buffer = fs::read(first_upload_zip_path).unwrap();
+
+        let first_user_first_zip_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            first_upload_zip_path,
+        )
+        .await;
+        assert!(first_user_first_zip_upload_response.is_ok());
+
+        //* Full download advertises ranges support
+        let full_download_response = get(&client, "/api/site")
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(&first_random_subdomain).expect("Failed to convert subdomain to header value!"),
+            )
+            .authorization_bearer(&first_user_token)
+            .await;
+        assert_eq!(full_download_response.status_code(), StatusCode::OK);
+        assert_eq!(
+            full_download_response.headers().get(header::ACCEPT_RANGES),
+            Some(&HeaderValue::from_static("bytes"))
+        );
+        assert_eq!(
+            full_download_response.headers().get(header::CONTENT_LENGTH),
+            Some(&HeaderValue::from(first_zip_buffer.len()))
+        );
+
+        //* Resuming download from the middle
+        let middle = first_zip_buffer.len() / 2;
+
+        let resumed_download_response = get(&client, "/api/site")
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(&first_random_subdomain).expect("Failed to convert subdomain to header value!"),
+            )
+            .add_header(
+                header::RANGE,
+                HeaderValue::from_str(&format!("bytes={middle}-")).expect("Failed to convert range to header value!"),
+            )
+            .authorization_bearer(&first_user_token)
+            .await;
+        assert_eq!(resumed_download_response.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(
+            resumed_download_response.as_bytes().as_ref(),
+            &first_zip_buffer[middle..]
+        );
+    }
+}
@@ -23,7 +23,7 @@ use std::sync::Arc;
     responses(
         (status = 204, description = "Site was successfully enabled"),
         (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
-        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user or blocked by administrator.",      body = Details),
         (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
         (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
         (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
@@ -0,0 +1,34 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::io;
+
+#[derive(thiserror::Error, Debug)]
+pub enum FileError {
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+    #[error(transparent)]
+    IoError(#[from] io::Error),
+}
+
+impl From<FileError> for StatusCode {
+    fn from(value: FileError) -> Self {
+        match value {
+            FileError::SiteServiceError(error) => Self::from(error),
+            FileError::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for FileError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
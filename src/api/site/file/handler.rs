@@ -0,0 +1,91 @@
+use super::{error::FileError, request::FileQuery};
+use crate::{
+    extractors::*,
+    http::{conditional, range},
+    services::{
+        archive::service::Service as ArchiveService,
+        site::{parameters::FileSearchParameters, service::Service as SiteService},
+    },
+    state::State as AppState,
+};
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Retrieve contents of a deployed file of the specified subdomain.
+///
+/// File is returned as it was uploaded even if the site is disabled.
+#[utoipa::path(
+    get,
+    tag = "Actions",
+    operation_id = "Retrieve file",
+    path = "/api/site/file",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+        FileQuery,
+      ),
+    responses(
+        (status = 200, description = "File was successfully retrieved",                                                    body = String, content_type = "application/octet-stream"),
+        (status = 206, description = "Requested ranges of the file",                                                       body = String, content_type = "application/octet-stream"),
+        (status = 416, description = "Requested ranges can not be satisfied"),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 404, description = "Not Found: The subdomain or file was not found. See details for more information.",   body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state, headers))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Query(query): Query<FileQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, FileError> {
+    let parameters = FileSearchParameters {
+        path: query.path.as_str(),
+        subdomain_id: subdomain.id,
+    };
+
+    tracing::trace!(%user.id, %subdomain.name, %query.path, "Retrieving deployed file...");
+
+    let file = SiteService::deployed(parameters, state.connection()).await?;
+
+    //? Files uploaded before content types were stored
+    //? do not have one so it is guessed from the path
+    let content_type = file
+        .content_type
+        .clone()
+        .unwrap_or_else(|| ArchiveService::content_type(&file.user_path));
+
+    //? Validators are required to evaluate If-Range
+    let etag = file.hash.as_ref().map(conditional::etag);
+
+    let response = range::respond(
+        StatusCode::OK,
+        &headers,
+        &file.real_path,
+        content_type,
+        etag.as_deref(),
+        Some(file.created_at.with_timezone(&Utc)),
+    )
+    .await
+    .inspect_err(|cause| {
+        tracing::info!(%cause,
+                       %subdomain.name,
+                       %user.id,
+                       %file.real_path,
+                       "Failed to open deployed file!")
+    })?;
+
+    tracing::trace!(%user.id, %subdomain.name, %query.path, "Deployed file was successfully retrieved!");
+
+    Ok(response)
+}
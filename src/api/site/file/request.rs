@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FileQuery {
+    /// Path of the file inside of uploaded archive
+    #[param(example = "index.html")]
+    pub path: String,
+}
@@ -0,0 +1,32 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{api::tests::get, Details};
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use bytes::Bytes;
+    use std::fmt::Display;
+
+    pub async fn file<S, T>(
+        client: &TestClient,
+        subdomain: S,
+        token: T,
+        path: &str,
+    ) -> Result<Bytes, (StatusCode, Details)>
+    where
+        S: AsRef<str>,
+        T: Display,
+    {
+        let response = get(client, format!("/api/site/file?path={path}"))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.as_bytes().to_owned()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
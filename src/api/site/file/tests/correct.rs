@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                disable::tests::call::tests::disable, file::tests::call::tests::file,
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let index_response = file(&client, &first_random_subdomain, &first_user_token, "some/index.html")
+            .await
+            .expect("Failed to retrieve file!");
+        assert_eq!(index_response.len(), 1222400);
+
+        //* Only exact paths are resolved
+        let missing_response = file(&client, &first_random_subdomain, &first_user_token, "some").await;
+        assert_eq!(
+            missing_response.expect_err("Directory was resolved to a file!").0,
+            StatusCode::NOT_FOUND
+        );
+
+        //* Files are available while the site is disabled
+        let disable_response = disable(&client, &first_random_subdomain, &first_user_token).await;
+        assert_eq!(disable_response, Ok(()));
+
+        let disabled_index_response = file(&client, &first_random_subdomain, &first_user_token, "index.html")
+            .await
+            .expect("Failed to retrieve file of disabled site!");
+        assert_eq!(disabled_index_response.len(), 1222400);
+
+        //* Files are only available to the owner
+        let second_user_login = Uuid::new_v4();
+        let second_user_password = Uuid::new_v4();
+
+        let second_user_registration_request = RegistrationRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+        assert!(registration(&client, &second_user_registration_request).await.is_ok());
+
+        let second_user_login_request = LoginRequest {
+            login: second_user_login.into(),
+            password: second_user_password.into(),
+        };
+        let second_user_token = login(&client, &second_user_login_request)
+            .await
+            .expect("Failed to login!")
+            .token;
+
+        let foreign_response = file(&client, &first_random_subdomain, &second_user_token, "index.html").await;
+        assert_eq!(
+            foreign_response.expect_err("File was retrieved by another user!").0,
+            StatusCode::FORBIDDEN
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use validator::ValidationErrors;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListFilesError {
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+    #[error(transparent)]
+    ValidationError(#[from] ValidationErrors),
+}
+
+impl From<ListFilesError> for StatusCode {
+    fn from(value: ListFilesError) -> Self {
+        match value {
+            ListFilesError::SiteServiceError(error) => Self::from(error),
+            ListFilesError::ValidationError(_) => Self::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for ListFilesError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
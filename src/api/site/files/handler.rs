@@ -0,0 +1,74 @@
+use super::{
+    error::ListFilesError,
+    request::ListFilesQuery,
+    response::{DeployedFileResponse, ListFilesResponse},
+};
+use crate::{
+    extractors::*,
+    services::site::{parameters::ListFilesParameters, service::Service as SiteService},
+    state::State as AppState,
+};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PER_PAGE: u64 = 20;
+
+/// List deployed files of the specified subdomain.
+///
+/// Files are returned page by page in order of their paths.
+/// Precompressed variants are not listed.
+#[utoipa::path(
+    get,
+    tag = "Actions",
+    operation_id = "List files",
+    path = "/api/site/files",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+        ListFilesQuery,
+      ),
+    responses(
+        (status = 200, description = "Files were successfully retrieved.",                                                  body = ListFilesResponse),
+        (status = 400, description = "Bad Request: Page or page size is out of range or 'x-subdomain' header is invalid.", body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The subdomain was not found.",                                             body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Query(query): Query<ListFilesQuery>,
+) -> Result<Json<ListFilesResponse>, ListFilesError> {
+    query.validate()?;
+
+    let parameters = ListFilesParameters {
+        subdomain_id: subdomain.id,
+        prefix: query.prefix,
+        page: query.page.unwrap_or(1),
+        per_page: query.per_page.unwrap_or(DEFAULT_PER_PAGE),
+    };
+
+    tracing::trace!(%user.id, %subdomain.name, ?parameters, "Retrieving files list...");
+
+    let page = parameters.page;
+    let per_page = parameters.per_page;
+    let (files, total) = SiteService::files(parameters, state.connection()).await?;
+
+    tracing::trace!(%user.id, %subdomain.name, amount = files.len(), %total, "Files list was successfully retrieved!");
+
+    Ok(Json(ListFilesResponse {
+        files: files.into_iter().map(DeployedFileResponse::from).collect(),
+        page,
+        per_page,
+        total,
+    }))
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListFilesQuery {
+    /// Only files with path starting with prefix are listed
+    pub prefix: Option<String>,
+    /// Page to return starting from 1. Defaults to 1
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub page: Option<u64>,
+    /// Amount of files per page from 1 to 100. Defaults to 20
+    #[validate(range(min = 1, max = 100))]
+    #[param(minimum = 1, maximum = 100)]
+    pub per_page: Option<u64>,
+}
@@ -0,0 +1,36 @@
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeployedFileResponse {
+    /// Path of the file inside of uploaded archive
+    pub path: String,
+    /// Size in bytes. Unknown for files uploaded before sizes were stored
+    pub size: Option<u64>,
+    pub content_type: Option<String>,
+    /// Hash of the file contents which is also used as ETag
+    pub hash: Option<String>,
+}
+
+impl From<FileModel> for DeployedFileResponse {
+    fn from(file: FileModel) -> Self {
+        Self {
+            path: file.user_path,
+            size: file.size.map(|size| size.max(0) as u64),
+            content_type: file.content_type,
+            hash: file.hash,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"files": [{"path": "index.html", "size": 1024, "content_type": "text/html; charset=utf-8", "hash": "3f0a1b"}], "page": 1, "per_page": 20, "total": 1}))]
+pub struct ListFilesResponse {
+    /// Deployed files in order of their paths
+    pub files: Vec<DeployedFileResponse>,
+    pub page: u64,
+    pub per_page: u64,
+    /// Amount of files on all pages
+    pub total: u64,
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{site::files::response::ListFilesResponse, tests::get},
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn files<S, T>(
+        client: &TestClient,
+        subdomain: S,
+        token: T,
+        prefix: &str,
+        page: u64,
+        per_page: u64,
+    ) -> Result<ListFilesResponse, (StatusCode, Details)>
+    where
+        S: AsRef<str>,
+        T: Display,
+    {
+        let response = get(
+            client,
+            format!("/api/site/files?prefix={prefix}&page={page}&per_page={per_page}"),
+        )
+        .add_header(
+            HeaderName::from_static("x-subdomain"),
+            HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+        )
+        .authorization_bearer(token)
+        .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
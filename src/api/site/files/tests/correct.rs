@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{files::tests::call::tests::files, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Precompressed variants and sero.toml are not listed
+        let all_files_response = files(&client, &first_random_subdomain, &first_user_token, "", 1, 20)
+            .await
+            .expect("Failed to list files!");
+        assert_eq!(all_files_response.total, 2);
+
+        let paths: Vec<_> = all_files_response.files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, ["index.html", "some/index.html"]);
+
+        let index = &all_files_response.files[0];
+        assert_eq!(index.size, Some(1222400));
+        assert!(index.hash.is_some());
+        assert!(index
+            .content_type
+            .as_ref()
+            .is_some_and(|content_type| content_type.starts_with("text/html")));
+
+        //* Files are filtered by prefix
+        let prefixed_files_response = files(&client, &first_random_subdomain, &first_user_token, "some/", 1, 20)
+            .await
+            .expect("Failed to list files!");
+        assert_eq!(prefixed_files_response.total, 1);
+        assert_eq!(prefixed_files_response.files[0].path, "some/index.html");
+
+        //* Wildcards in prefix are matched literally
+        let wildcard_files_response = files(&client, &first_random_subdomain, &first_user_token, "%25", 1, 20)
+            .await
+            .expect("Failed to list files!");
+        assert_eq!(wildcard_files_response.total, 0);
+
+        //* Files are paginated
+        let second_page_response = files(&client, &first_random_subdomain, &first_user_token, "", 2, 1)
+            .await
+            .expect("Failed to list files!");
+        assert_eq!(second_page_response.total, 2);
+        assert_eq!(second_page_response.files.len(), 1);
+        assert_eq!(second_page_response.files[0].path, "some/index.html");
+
+        let invalid_page_response = files(&client, &first_random_subdomain, &first_user_token, "", 0, 20).await;
+        assert_eq!(
+            invalid_page_response.expect_err("Zero page was accepted!").0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use validator::ValidationErrors;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListSitesError {
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+    #[error(transparent)]
+    ValidationError(#[from] ValidationErrors),
+}
+
+impl From<ListSitesError> for StatusCode {
+    fn from(value: ListSitesError) -> Self {
+        match value {
+            ListSitesError::SiteServiceError(error) => Self::from(error),
+            ListSitesError::ValidationError(_) => Self::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for ListSitesError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,66 @@
+use super::{
+    error::ListSitesError,
+    request::ListSitesQuery,
+    response::{ListOwnedSitesResponse, OwnedSiteResponse},
+};
+use crate::{
+    extractors::*,
+    services::site::{parameters::ListParameters, service::Service as SiteService},
+    state::State as AppState,
+};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+const DEFAULT_PER_PAGE: u64 = 20;
+
+/// List sites owned by the user.
+///
+/// Sites are returned page by page in order of creation together with
+/// statistics of their deployed files.
+#[utoipa::path(
+    get,
+    tag = "Actions",
+    operation_id = "List sites",
+    path = "/api/site/list",
+    params(ListSitesQuery),
+    responses(
+        (status = 200, description = "Sites were successfully retrieved.",                           body = ListOwnedSitesResponse),
+        (status = 400, description = "Bad Request: Page or page size is out of range.",              body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",   body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",      body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AuthJWT(user): AuthJWT,
+    Query(query): Query<ListSitesQuery>,
+) -> Result<Json<ListOwnedSitesResponse>, ListSitesError> {
+    query.validate()?;
+
+    let parameters = ListParameters {
+        owner_id: user.id,
+        page: query.page.unwrap_or(1),
+        per_page: query.per_page.unwrap_or(DEFAULT_PER_PAGE),
+    };
+
+    tracing::trace!(%user.id, ?parameters, "Retrieving sites list...");
+
+    let page = parameters.page;
+    let per_page = parameters.per_page;
+    let (sites, total) = SiteService::list(parameters, state.connection()).await?;
+
+    tracing::trace!(%user.id, amount = sites.len(), %total, "Sites list was successfully retrieved!");
+
+    Ok(Json(ListOwnedSitesResponse {
+        sites: sites.into_iter().map(OwnedSiteResponse::from).collect(),
+        page,
+        per_page,
+        total,
+    }))
+}
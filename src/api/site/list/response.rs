@@ -0,0 +1,45 @@
+use crate::services::site::models::SiteSummary;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct OwnedSiteResponse {
+    pub name: String,
+    pub enabled: bool,
+    /// Deployed files without precompressed variants
+    pub files: u64,
+    /// Total size of deployed files in bytes
+    pub bytes: u64,
+    /// Size of the last uploaded archive in bytes
+    pub archive_size: Option<u64>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub last_deployed_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<SiteSummary> for OwnedSiteResponse {
+    fn from(summary: SiteSummary) -> Self {
+        Self {
+            name: summary.subdomain.name,
+            enabled: summary.subdomain.enabled,
+            files: summary.files,
+            bytes: summary.bytes,
+            archive_size: summary.archive_size,
+            created_at: summary.subdomain.created_at,
+            updated_at: summary.subdomain.updated_at,
+            last_deployed_at: summary.subdomain.last_deployed_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"sites": [{"name": "docs", "enabled": true, "files": 12, "bytes": 48213, "archive_size": 20311, "created_at": "2024-10-30T12:00:00+00:00", "updated_at": "2024-10-30T12:00:00+00:00", "last_deployed_at": "2024-10-30T12:00:00+00:00"}], "page": 1, "per_page": 20, "total": 1}))]
+pub struct ListOwnedSitesResponse {
+    /// Sites of the user in order of creation
+    pub sites: Vec<OwnedSiteResponse>,
+    pub page: u64,
+    pub per_page: u64,
+    /// Amount of sites on all pages
+    pub total: u64,
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{site::list::response::ListOwnedSitesResponse, tests::get},
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn list<T>(
+        client: &TestClient,
+        token: T,
+        page: u64,
+        per_page: u64,
+    ) -> Result<ListOwnedSitesResponse, (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = get(client, format!("/api/site/list?page={page}&per_page={per_page}"))
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
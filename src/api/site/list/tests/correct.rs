@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{list::tests::call::tests::list, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let second_random_subdomain = Uuid::new_v4().to_string();
+
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &second_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(second_correct_upload_response, Ok(()));
+
+        //* Sites are listed in order of creation
+        let first_page_response = list(&client, &first_user_token, 1, 1)
+            .await
+            .expect("Failed to list sites!");
+        assert_eq!(first_page_response.total, 2);
+        assert_eq!(first_page_response.sites.len(), 1);
+
+        let first_site = &first_page_response.sites[0];
+        assert_eq!(first_site.name, first_random_subdomain);
+        assert!(first_site.enabled);
+        assert!(first_site.last_deployed_at.is_some());
+        assert!(first_site.archive_size.is_some_and(|archive_size| archive_size > 0));
+
+        //* Precompressed variants and sero.toml are not counted
+        assert_eq!(first_site.files, 2);
+        assert_eq!(first_site.bytes, 2 * 1222400);
+
+        let second_page_response = list(&client, &first_user_token, 2, 1)
+            .await
+            .expect("Failed to list sites!");
+        assert_eq!(second_page_response.sites.len(), 1);
+        assert_eq!(second_page_response.sites[0].name, second_random_subdomain);
+
+        //* Page after the last one is empty
+        let empty_page_response = list(&client, &first_user_token, 2, 20)
+            .await
+            .expect("Failed to list sites!");
+        assert!(empty_page_response.sites.is_empty());
+        assert_eq!(empty_page_response.total, 2);
+
+        //* Page size is limited
+        let invalid_page_response = list(&client, &first_user_token, 1, 0).await;
+        assert_eq!(
+            invalid_page_response.expect_err("Empty page size was accepted!").0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
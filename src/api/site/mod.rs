@@ -1,21 +1,51 @@
 use crate::state::State;
 use axum::{
-    routing::{delete, get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 use std::sync::Arc;
 
+pub mod configure;
+pub mod deployments;
 pub mod disable;
+pub mod discard;
 pub mod download;
 pub mod enable;
+pub mod file;
+pub mod files;
+pub mod list;
 pub mod page;
+pub mod preview;
+pub mod previews;
+pub mod promote;
+pub mod protect;
+pub mod revoke;
+pub mod rollback;
+pub mod settings;
+pub mod share;
 pub mod teardown;
+pub mod unprotect;
 pub mod upload;
 
 pub fn router() -> Router<Arc<State>> {
     Router::new()
         .route("/disable", patch(disable::handler::implementation))
         .route("/enable", patch(enable::handler::implementation))
+        .route("/deployments", get(deployments::handler::implementation))
+        .route("/deployments/:id/rollback", patch(rollback::handler::implementation))
+        .route("/previews", get(previews::handler::implementation))
+        .route("/previews", post(preview::handler::implementation))
+        .route("/previews/:id", delete(discard::handler::implementation))
+        .route("/previews/:id/promote", patch(promote::handler::implementation))
+        .route("/files", get(files::handler::implementation))
+        .route("/file", get(file::handler::implementation))
+        .route("/list", get(list::handler::implementation))
+        .route("/settings", get(settings::handler::implementation))
+        .route("/settings", patch(configure::handler::implementation))
+        .route("/protection", put(protect::handler::implementation))
+        .route("/protection", delete(unprotect::handler::implementation))
+        .route("/share", post(share::handler::implementation))
+        .route("/share", delete(revoke::handler::implementation))
         .route("/", delete(teardown::handler::implementation))
         .route("/", get(download::handler::implementation))
         .route("/", post(upload::handler::implementation))
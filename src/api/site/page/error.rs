@@ -1,4 +1,10 @@
-use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use crate::{
+    services::{
+        access::error::ServiceError as AccessServiceError,
+        content_type::error::ServiceError as ContentTypeServiceError, site::error::ServiceError as SiteServiceError,
+    },
+    Details,
+};
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -11,6 +17,10 @@ pub enum PageError {
     #[error(transparent)]
     SiteServiceError(#[from] SiteServiceError),
     #[error(transparent)]
+    AccessServiceError(#[from] AccessServiceError),
+    #[error(transparent)]
+    ContentTypeServiceError(#[from] ContentTypeServiceError),
+    #[error(transparent)]
     IoError(#[from] io::Error),
 }
 
@@ -18,6 +28,8 @@ impl From<PageError> for StatusCode {
     fn from(value: PageError) -> Self {
         match value {
             PageError::SiteServiceError(error) => Self::from(error),
+            PageError::AccessServiceError(error) => Self::from(error),
+            PageError::ContentTypeServiceError(error) => Self::from(error),
             PageError::IoError(_) => Self::INTERNAL_SERVER_ERROR,
         }
     }
@@ -1,50 +1,384 @@
 use super::error::PageError;
 use crate::{
     extractors::*,
-    site::{parameters::FileSearchParameters, service::Service as SiteService},
+    http::{
+        basic, client, conditional,
+        encoding::{self, Encoding},
+        range, share,
+    },
+    services::{
+        access::service::Service as AccessService,
+        archive::{rules, service::Service as ArchiveService},
+        content_type::service::Service as ContentTypeService,
+    },
+    site::{
+        parameters::{Authorization, AuthorizeParameters, Credentials, SiteFile, SiteRedirect},
+        service::Service as SiteService,
+    },
     state::State as AppState,
 };
 use axum::{
-    body::Body,
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{OriginalUri, Path, Query, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
 };
-use std::sync::Arc;
-use tokio::fs;
-use tokio_util::io::ReaderStream;
+use chrono::Utc;
+use entity::prelude::SubdomainModel;
+use std::{collections::HashMap, sync::Arc};
 
 pub mod redirect {
     use super::*;
 
-    #[tracing::instrument(skip(state))]
+    #[tracing::instrument(skip(state, headers))]
     pub async fn implementation(
         State(state): State<Arc<AppState>>,
         Subdomain(subdomain): Subdomain,
+        client: ClientAddress,
+        uri: OriginalUri,
+        headers: HeaderMap,
     ) -> Result<impl IntoResponse, PageError> {
-        super::implementation(State(state), Subdomain(subdomain), Path(String::from("index.html"))).await
+        super::implementation(
+            State(state),
+            Subdomain(subdomain),
+            client,
+            uri,
+            Path(String::from("index.html")),
+            headers,
+        )
+        .await
     }
 }
 
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, headers))]
 pub async fn implementation(
     State(state): State<Arc<AppState>>,
     Subdomain(subdomain): Subdomain,
+    ClientAddress(address): ClientAddress,
+    OriginalUri(uri): OriginalUri,
     Path(path): Path<String>,
+    headers: HeaderMap,
 ) -> Result<Response, PageError> {
-    let parameters = FileSearchParameters {
-        path,
+    //? Blocked clients do not even get asked for credentials
+    if !AccessService::is_allowed_for(subdomain.id, address, state.connection()).await? {
+        tracing::trace!(%subdomain.name, ?address, "Client is not allowed to reach the site");
+
+        let file = SiteService::forbidden(&subdomain, state.connection()).await?;
+        return respond(&state, &subdomain, file, &headers).await;
+    }
+
+    //? Protected site does not reveal anything including
+    //? redirects and custom 404.html or 503.html pages
+    //? Behind untrusted proxy all clients share its address
+    //? and one of them could lock out everyone else
+    let is_reliable = client::is_reliable(&headers, state.configuration().trusted_proxies());
+
+    let parameters = AuthorizeParameters {
         subdomain_id: subdomain.id,
+        credentials: basic::credentials(&headers).map(|(username, password)| Credentials { username, password }),
+        pass: basic::pass_from_cookie(&headers),
+        secret: state.configuration().jwt_secret().to_owned(),
+        address: address.filter(|_| is_reliable),
+        attempts: state.configuration().login_attempts_per_address(),
+        lockout_seconds: state.configuration().login_lockout_seconds(),
+    };
+
+    //? Pass is issued after credentials were verified
+    //? so they are not verified again on every request
+    let pass = match SiteService::authorize(parameters, state.connection()).await? {
+        Authorization::Granted => None,
+        Authorization::Verified(pass) => HeaderValue::try_from(basic::pass_cookie(pass)).ok(),
+        Authorization::Locked(locked_until) => {
+            tracing::trace!(%subdomain.name, ?address, %locked_until, "Client is locked out of the site");
+            let retry_after = (locked_until - Utc::now()).num_seconds().max(1);
+
+            return Ok((
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+            )
+                .into_response());
+        }
+        Authorization::Denied => {
+            tracing::trace!(%subdomain.name, "Site is protected. Requesting credentials...");
+            let challenge = format!("Basic realm=\"{}\", charset=\"UTF-8\"", subdomain.name);
+
+            return Ok(match HeaderValue::try_from(challenge) {
+                Ok(challenge) => (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, challenge)]).into_response(),
+                Err(_) => StatusCode::UNAUTHORIZED.into_response(),
+            });
+        }
+    };
+
+    //? Private site is served only to visitors with a valid share link
+    //? Token from the link is moved to cookie and stripped from the address
+    if subdomain.private {
+        if let Some((token, rest)) = share::from_query(uri.query()) {
+            let Some(expires_at) = SiteService::shared_until(&subdomain, &token) else {
+                tracing::trace!(%subdomain.name, "Share link is invalid or expired");
+                return Ok(StatusCode::FORBIDDEN.into_response());
+            };
+
+            let location = share::location(uri.path(), rest.as_deref());
+            let cookie = share::cookie(&token, (expires_at - Utc::now()).num_seconds());
+
+            return Ok(match (HeaderValue::try_from(location), HeaderValue::try_from(cookie)) {
+                (Ok(location), Ok(cookie)) => (
+                    StatusCode::FOUND,
+                    [
+                        (header::LOCATION, location),
+                        (header::SET_COOKIE, cookie),
+                        (header::CACHE_CONTROL, HeaderValue::from_static("no-store")),
+                    ],
+                )
+                    .into_response(),
+                _ => StatusCode::FORBIDDEN.into_response(),
+            });
+        }
+
+        let is_shared = share::from_cookie(&headers)
+            .and_then(|token| SiteService::shared_until(&subdomain, token))
+            .is_some();
+
+        if !is_shared {
+            tracing::trace!(%subdomain.name, "Site is private and no valid share link was provided");
+            return Ok(StatusCode::FORBIDDEN.into_response());
+        }
+    }
+
+    //? Disabled sites are not redirected anywhere
+    let redirect = match subdomain.enabled {
+        true => {
+            let query = Query::<HashMap<String, String>>::try_from_uri(&uri)
+                .map(|Query(query)| query)
+                .unwrap_or_default();
+
+            SiteService::redirect(&subdomain, uri.path(), &query, state.connection()).await?
+        }
+        false => None,
+    };
+
+    //? Existing files are not shadowed by rules
+    //? unless the rule is forced with trailing `!`
+    let file = match &redirect {
+        Some(redirect) if redirect.is_forced() => None,
+        _ => Some(SiteService::file(&subdomain, &path, state.connection()).await?),
+    };
+
+    let redirect = match file {
+        Some(SiteFile::Found(_) | SiteFile::Moved(_)) => None,
+        _ => redirect,
+    };
+
+    let mut response = match redirect {
+        Some(SiteRedirect::Redirect {
+            location,
+            status,
+            matched,
+            ..
+        }) => {
+            //? Query is passed through unless destination sets its own
+            //? but parameters the rule matched on are dropped
+            let query = uri
+                .query()
+                .map(|query| {
+                    query
+                        .split('&')
+                        .filter(|pair| {
+                            let key = pair.split('=').next().unwrap_or_default();
+                            !pair.is_empty() && !matched.iter().any(|matched| matched == key)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("&")
+                })
+                .filter(|query| !query.is_empty());
+
+            let location = match query {
+                Some(query) if !location.contains('?') => format!("{location}?{query}"),
+                _ => location,
+            };
+
+            match HeaderValue::try_from(location) {
+                Ok(location) => (status, [(header::LOCATION, location)]).into_response(),
+                Err(cause) => {
+                    tracing::warn!(%cause, "Redirect location is not a valid header value");
+                    StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                }
+            }
+        }
+        Some(SiteRedirect::Rewrite { path, status, .. }) => {
+            let path = path.split('?').next().unwrap_or_default().trim_start_matches('/');
+            let path = match path.is_empty() {
+                true => String::from("index.html"),
+                false => path.to_owned(),
+            };
+
+            let file = SiteService::file(&subdomain, path, state.connection()).await?;
+            let mut response = serve(&state, &subdomain, file, uri.query(), &headers).await?;
+
+            //? Rewrite with 404 serves destination as not found page
+            if status == StatusCode::NOT_FOUND && response.status() == StatusCode::OK {
+                *response.status_mut() = StatusCode::NOT_FOUND;
+            }
+            response
+        }
+        None => {
+            let file = match file {
+                Some(file) => file,
+                None => SiteService::file(&subdomain, &path, state.connection()).await?,
+            };
+
+            serve(&state, &subdomain, file, uri.query(), &headers).await?
+        }
     };
 
-    let file = SiteService::file(parameters, state.connection()).await?;
+    //? Rules are matched against the requested path
+    //? and applied to any response including 304, 404 and 503
+    let rules = SiteService::headers(&subdomain, uri.path(), state.connection()).await?;
+    tracing::trace!(amount = rules.len(), "Found matching header rules");
 
-    Ok(match file.path() {
+    //? Rules saved before framing headers were rejected on upload
+    //? are skipped so they can not corrupt the response
+    let mut custom = HeaderMap::new();
+    for rule in rules {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(rule.name), HeaderValue::try_from(rule.value)) {
+            if rules::is_overridable(&name) {
+                custom.append(name, value);
+            }
+        }
+    }
+
+    //? Custom headers replace the ones set by server
+    //? but values of several matching rules are combined
+    for name in custom.keys() {
+        response.headers_mut().remove(name);
+    }
+    for (name, value) in custom.iter() {
+        response.headers_mut().append(name, value.clone());
+    }
+
+    if let Some(pass) = pass {
+        response.headers_mut().append(header::SET_COOKIE, pass);
+    }
+
+    Ok(response)
+}
+
+async fn serve(
+    state: &AppState,
+    subdomain: &SubdomainModel,
+    file: SiteFile,
+    query: Option<&str>,
+    headers: &HeaderMap,
+) -> Result<Response, PageError> {
+    if let SiteFile::Moved(location) = &file {
+        let location = match query {
+            Some(query) => format!("{location}?{query}"),
+            None => location.clone(),
+        };
+
+        tracing::trace!(%location, "Path is not canonical. Redirecting...");
+        return Ok(match HeaderValue::try_from(location) {
+            Ok(location) => (StatusCode::from(&file), [(header::LOCATION, location)]).into_response(),
+            Err(cause) => {
+                tracing::warn!(%cause, "Canonical location is not a valid header value");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        });
+    }
+
+    respond(state, subdomain, file, headers).await
+}
+
+async fn respond(
+    state: &AppState,
+    subdomain: &SubdomainModel,
+    file: SiteFile,
+    headers: &HeaderMap,
+) -> Result<Response, PageError> {
+    Ok(match file.file() {
         None => StatusCode::from(&file).into_response(),
-        Some(path) => (
-            StatusCode::from(&file),
-            Body::from_stream(ReaderStream::new(fs::File::open(path).await?)),
-        )
-            .into_response(),
+        Some(identity) => {
+            //? Precompressed variant is picked before revalidation
+            //? as every variant has its own validators
+            let variants = SiteService::variants(identity, state.connection()).await?;
+
+            let available = variants
+                .iter()
+                .filter_map(|variant| variant.encoding.as_ref().and_then(Encoding::from_token))
+                .collect::<Vec<_>>();
+
+            let negotiated = encoding::negotiate(headers, &available);
+            tracing::trace!(?available, ?negotiated, "Content encoding was negotiated");
+
+            let model = negotiated
+                .and_then(|negotiated| {
+                    variants
+                        .iter()
+                        .find(|variant| variant.encoding.as_deref() == Some(negotiated.as_str()))
+                })
+                .unwrap_or(identity);
+
+            //? Override of the site wins over the stored type.
+            //? Files uploaded before content types were stored
+            //? do not have one so it is guessed from the path
+            let overridden = match model.subdomain_id {
+                Some(subdomain_id) => {
+                    ContentTypeService::resolve_for(subdomain_id, &model.user_path, state.connection()).await?
+                }
+                None => None,
+            };
+
+            let content_type = overridden
+                .or_else(|| model.content_type.clone())
+                .unwrap_or_else(|| ArchiveService::content_type(&model.user_path));
+
+            let etag = model.hash.as_ref().map(conditional::etag);
+            //? Rolled back files are older than the deployment they replace
+            //? so they must not look unmodified to caches of the latter
+            let last_modified = model
+                .created_at
+                .max(subdomain.activated_at.unwrap_or(model.created_at))
+                .with_timezone(&Utc);
+
+            let mut validators = HeaderMap::new();
+            if let Some(Ok(etag)) = etag.as_ref().map(|etag| etag.parse()) {
+                validators.insert(header::ETAG, etag);
+            }
+            if let Ok(last_modified) = conditional::format_http_date(last_modified).parse() {
+                validators.insert(header::LAST_MODIFIED, last_modified);
+            }
+            //? Caches must not mix up representations
+            //? if response depends on Accept-Encoding
+            if !variants.is_empty() {
+                validators.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+            }
+
+            //? Only successfully found files can be revalidated
+            //? Custom 403.html, 404.html and 503.html are always sent in full
+            if matches!(file, SiteFile::Found(_) | SiteFile::Fallback(_))
+                && conditional::is_not_modified(headers, etag.as_deref(), Some(last_modified))
+            {
+                tracing::trace!(?etag, %last_modified, "Page was not modified");
+                return Ok((StatusCode::NOT_MODIFIED, validators).into_response());
+            }
+
+            let mut response = range::respond(
+                StatusCode::from(&file),
+                headers,
+                &model.real_path,
+                content_type,
+                etag.as_deref(),
+                Some(last_modified),
+            )
+            .await?;
+
+            if let Some(negotiated) = negotiated {
+                response
+                    .headers_mut()
+                    .insert(header::CONTENT_ENCODING, HeaderValue::from_static(negotiated.as_str()));
+            }
+
+            response.headers_mut().extend(validators);
+            response
+        }
     })
 }
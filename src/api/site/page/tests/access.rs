@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            access::create::tests::call::tests::create,
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+        http::client::{address, is_reliable, X_FORWARDED_FOR},
+        services::access::models::AccessAction,
+    };
+    use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use ipnet::IpNet;
+    use std::net::{IpAddr, SocketAddr};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn access() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app.into_make_service_with_connect_info::<SocketAddr>())
+            .expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-403.html.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Allow list without client network
+        let create_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "10.0.0.0/8",
+            AccessAction::Allow,
+        )
+        .await;
+        assert!(create_response.is_ok());
+
+        //* Custom 403 page is served
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+        assert_eq!(page_response.text(), "403\n");
+
+        //* Client network is allowed
+        let create_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "127.0.0.1",
+            AccessAction::Allow,
+        )
+        .await;
+        assert!(create_response.is_ok());
+
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+
+        //* Deny wins over allow
+        let create_response = create(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "127.0.0.0/8",
+            AccessAction::Deny,
+        )
+        .await;
+        assert!(create_response.is_ok());
+
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+
+        //* X-Forwarded-For is honoured only from trusted proxies
+        let trusted_proxies = vec!["10.0.0.0/8".parse::<IpNet>().expect("Failed to parse network")];
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static(X_FORWARDED_FOR),
+            HeaderValue::from_static("192.0.2.1, 203.0.113.7, 10.0.0.2"),
+        );
+
+        let untrusted = address(
+            &headers,
+            Some(IpAddr::from([198, 51, 100, 1])),
+            Some(trusted_proxies.as_slice()),
+        );
+        assert_eq!(untrusted, Some(IpAddr::from([198, 51, 100, 1])));
+
+        //* Rightmost address which is not a trusted proxy is the client
+        let trusted = address(
+            &headers,
+            Some(IpAddr::from([10, 0, 0, 1])),
+            Some(trusted_proxies.as_slice()),
+        );
+        assert_eq!(trusted, Some(IpAddr::from([203, 0, 113, 7])));
+
+        let without_proxies = address(&headers, Some(IpAddr::from([10, 0, 0, 1])), None);
+        assert_eq!(without_proxies, Some(IpAddr::from([10, 0, 0, 1])));
+
+        let unknown_peer = address(&headers, None, Some(trusted_proxies.as_slice()));
+        assert_eq!(unknown_peer, None);
+
+        //* Forwarded requests can not be told apart without trusted proxies
+        assert!(is_reliable(&headers, Some(trusted_proxies.as_slice())));
+        assert!(!is_reliable(&headers, None));
+        assert!(is_reliable(&HeaderMap::new(), None));
+    }
+}
@@ -1,19 +1,25 @@
 #[cfg(test)]
 pub mod tests {
     use crate::api::tests::get;
-    use axum::http::{HeaderName, HeaderValue};
+    use axum::http::{header, HeaderValue};
     use axum_test::{TestResponse, TestServer as TestClient};
 
+    /// Host of the site under base domain of `.env.tests`
+    pub fn host<S>(subdomain: S) -> HeaderValue
+    where
+        S: AsRef<str>,
+    {
+        HeaderValue::from_str(&format!("{}.sero.test", subdomain.as_ref()))
+            .expect("Failed to convert host to header value!")
+    }
+
     pub async fn page<U, S>(client: &TestClient, url: U, subdomain: S) -> TestResponse
     where
         U: AsRef<str>,
         S: AsRef<str>,
     {
         get(client, url.as_ref())
-            .add_header(
-                HeaderName::from_static("x-subdomain"),
-                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
-            )
+            .add_header(header::HOST, host(subdomain))
             .await
     }
 }
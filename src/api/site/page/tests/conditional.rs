@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments,
+                page::tests::call::tests::{host, page},
+                rollback::tests::call::tests::rollback,
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn conditional() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* First request returns validators
+        let first_page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert!(first_page_response.status_code().is_success());
+
+        let etag = first_page_response.headers().get(header::ETAG).cloned();
+        let last_modified = first_page_response.headers().get(header::LAST_MODIFIED).cloned();
+        assert!(etag.is_some());
+        assert!(last_modified.is_some());
+
+        //* Matching entity tag
+        let not_modified_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::IF_NONE_MATCH, etag.clone().expect("never fails"))
+            .await;
+        assert_eq!(not_modified_response.status_code(), StatusCode::NOT_MODIFIED);
+        assert!(not_modified_response.as_bytes().is_empty());
+
+        //* Not matching entity tag
+        let modified_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::IF_NONE_MATCH, HeaderValue::from_static("\"another\""))
+            .await;
+        assert_eq!(modified_response.status_code(), StatusCode::OK);
+
+        //* Same modification date
+        let not_modified_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::IF_MODIFIED_SINCE, last_modified.expect("never fails"))
+            .await;
+        assert_eq!(not_modified_response.status_code(), StatusCode::NOT_MODIFIED);
+
+        //* Redeploy changes entity tag
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-503.html.zip",
+        )
+        .await;
+        assert!(second_correct_upload_response.is_ok());
+
+        let modified_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::IF_NONE_MATCH, etag.expect("never fails"))
+            .await;
+        assert_eq!(modified_response.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn rollback_is_modified() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //? Http dates have precision of seconds
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-503.html.zip",
+        )
+        .await;
+        assert!(second_correct_upload_response.is_ok());
+
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        let last_modified = page_response
+            .headers()
+            .get(header::LAST_MODIFIED)
+            .cloned()
+            .expect("Last-Modified was not set!");
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+
+        //* Rollback to files which were created earlier
+        let previous = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!")
+            .deployments
+            .into_iter()
+            .find(|deployment| !deployment.active)
+            .expect("Previous deployment must be retained!");
+
+        let rollback_response = rollback(&client, &first_random_subdomain, &first_user_token, previous.id).await;
+        assert!(rollback_response.is_ok());
+
+        //* Cache of the replaced deployment is not considered fresh
+        let modified_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::IF_MODIFIED_SINCE, last_modified)
+            .await;
+        assert_eq!(modified_response.status_code(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::header;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn content_type() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-404.html.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Found page should be served as html
+        let index_page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert!(index_page_response.status_code().is_success());
+        assert_eq!(
+            index_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&header::HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+
+        //* Custom 404.html should also be served as html
+        let not_found_page_response = page(&client, "/not/existing.css", &first_random_subdomain).await;
+        assert_eq!(not_found_page_response.status_code(), axum::http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            not_found_page_response.headers().get(header::CONTENT_TYPE),
+            Some(&header::HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                configure::{request::ConfigureRequest, tests::call::tests::configure},
+                page::tests::call::tests::page,
+                settings::tests::call::tests::settings,
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+        site::models::TrailingSlash,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn directories() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-directories.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Policy is set from sero.toml
+        let settings_response = settings(&client, &first_random_subdomain, &first_user_token).await;
+        assert_eq!(
+            settings_response.map(|settings| settings.trailing_slash),
+            Ok(TrailingSlash::Always)
+        );
+
+        //* Directory index is redirected to canonical form
+        let docs_response = page(&client, "/docs?page=2", &first_random_subdomain).await;
+        assert_eq!(docs_response.status_code(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            docs_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/docs/?page=2"))
+        );
+
+        let docs_response = page(&client, "/docs/", &first_random_subdomain).await;
+        assert_eq!(docs_response.status_code(), StatusCode::OK);
+        assert_eq!(docs_response.text(), "docs\n");
+
+        //* Page with html extension is also redirected
+        let guide_response = page(&client, "/guide", &first_random_subdomain).await;
+        assert_eq!(guide_response.status_code(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            guide_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/guide/"))
+        );
+
+        let guide_response = page(&client, "/guide/", &first_random_subdomain).await;
+        assert_eq!(guide_response.text(), "guide\n");
+
+        //* Exact files are never redirected
+        let asset_response = page(&client, "/app.js", &first_random_subdomain).await;
+        assert_eq!(asset_response.status_code(), StatusCode::OK);
+
+        let explicit_response = page(&client, "/docs/index.html", &first_random_subdomain).await;
+        assert_eq!(explicit_response.status_code(), StatusCode::OK);
+
+        //* Trailing slash is removed with never policy
+        let never_request = ConfigureRequest {
+            trailing_slash: Some(TrailingSlash::Never),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &never_request).await;
+        assert!(configure_response.is_ok());
+
+        let docs_response = page(&client, "/docs/", &first_random_subdomain).await;
+        assert_eq!(docs_response.status_code(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            docs_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/docs"))
+        );
+
+        let docs_response = page(&client, "/docs", &first_random_subdomain).await;
+        assert_eq!(docs_response.status_code(), StatusCode::OK);
+        assert_eq!(docs_response.text(), "docs\n");
+
+        //* Both forms are served with ignore policy
+        let ignore_request = ConfigureRequest {
+            trailing_slash: Some(TrailingSlash::Ignore),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &ignore_request).await;
+        assert!(configure_response.is_ok());
+
+        for path in ["/docs", "/docs/"] {
+            let docs_response = page(&client, path, &first_random_subdomain).await;
+            assert_eq!(docs_response.status_code(), StatusCode::OK);
+            assert_eq!(docs_response.text(), "docs\n");
+        }
+
+        //* Dots in directory names do not break lookup
+        let versioned_response = page(&client, "/v1.2/guide", &first_random_subdomain).await;
+        assert_eq!(versioned_response.status_code(), StatusCode::OK);
+        assert_eq!(versioned_response.text(), "v1.2\n");
+
+        //* Root is still resolved to index.html
+        let index_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(index_response.text(), "index\n");
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                page::tests::call::tests::{host, page},
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn encoding() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Identity is served without Accept-Encoding
+        let identity_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(identity_response.status_code(), StatusCode::OK);
+        assert!(identity_response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(
+            identity_response.headers().get(header::VARY),
+            Some(&HeaderValue::from_static("accept-encoding"))
+        );
+        let identity_length = identity_response.as_bytes().len();
+
+        //* Gzip variant is smaller
+        let gzip_response = get(&client, "/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::ACCEPT_ENCODING, HeaderValue::from_static("gzip"))
+            .await;
+        assert_eq!(gzip_response.status_code(), StatusCode::OK);
+        assert_eq!(
+            gzip_response.headers().get(header::CONTENT_ENCODING),
+            Some(&HeaderValue::from_static("gzip"))
+        );
+        assert_eq!(
+            gzip_response.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+        assert!(gzip_response.as_bytes().len() < identity_length);
+
+        //* Brotli is preferred when qualities are equal
+        let brotli_response = get(&client, "/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::ACCEPT_ENCODING, HeaderValue::from_static("gzip, deflate, br"))
+            .await;
+        assert_eq!(
+            brotli_response.headers().get(header::CONTENT_ENCODING),
+            Some(&HeaderValue::from_static("br"))
+        );
+
+        //* Variants have their own entity tags
+        assert_ne!(
+            gzip_response.headers().get(header::ETAG),
+            brotli_response.headers().get(header::ETAG)
+        );
+
+        //* Refused encodings are not served
+        let refused_response = get(&client, "/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(
+                header::ACCEPT_ENCODING,
+                HeaderValue::from_static("br;q=0, zstd;q=0, gzip;q=0"),
+            )
+            .await;
+        assert!(refused_response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(refused_response.as_bytes().len(), identity_length);
+
+        //* Tiny files are not compressed at all
+        let tiny_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert!(tiny_upload_response.is_ok());
+
+        let tiny_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(header::ACCEPT_ENCODING, HeaderValue::from_static("gzip"))
+            .await;
+        assert_eq!(tiny_response.status_code(), StatusCode::OK);
+        assert!(tiny_response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert!(tiny_response.headers().get(header::VARY).is_none());
+    }
+}
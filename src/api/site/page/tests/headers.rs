@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::{header, HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn headers() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-headers.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        let x_frame_options = HeaderName::from_static("x-frame-options");
+        let x_custom = HeaderName::from_static("x-custom");
+
+        //* Root path has its own rule and the wildcard one
+        let index_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(index_page_response.status_code(), StatusCode::OK);
+        assert_eq!(
+            index_page_response.headers().get(header::CONTENT_SECURITY_POLICY),
+            Some(&HeaderValue::from_static("default-src 'self'"))
+        );
+        assert_eq!(
+            index_page_response.headers().get(&x_frame_options),
+            Some(&HeaderValue::from_static("DENY"))
+        );
+
+        //* Values of several matching rules are combined
+        let asset_response = page(&client, "/assets/style.css", &first_random_subdomain).await;
+        assert_eq!(asset_response.status_code(), StatusCode::OK);
+        assert_eq!(
+            asset_response.headers().get(header::CACHE_CONTROL),
+            Some(&HeaderValue::from_static("public, max-age=31536000, immutable"))
+        );
+        assert_eq!(
+            asset_response.headers().get_all(&x_custom).iter().collect::<Vec<_>>(),
+            vec!["all", "assets"]
+        );
+        assert!(asset_response.headers().get(header::CONTENT_SECURITY_POLICY).is_none());
+
+        //* Rules are applied to not found pages too
+        let not_found_page_response = page(&client, "/not/existing.css", &first_random_subdomain).await;
+        assert_eq!(not_found_page_response.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            not_found_page_response.headers().get(&x_frame_options),
+            Some(&HeaderValue::from_static("DENY"))
+        );
+
+        //* Headers file itself is not served
+        let headers_file_response = page(&client, "/_headers", &first_random_subdomain).await;
+        assert_eq!(headers_file_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Rules are removed with redeploy
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-404.html.zip",
+        )
+        .await;
+        assert!(second_correct_upload_response.is_ok());
+
+        let not_found_page_response = page(&client, "/not/existing.css", &first_random_subdomain).await;
+        assert!(not_found_page_response.headers().get(&x_frame_options).is_none());
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::upload::tests::call::tests::upload,
+            tests::get,
+        },
+        app,
+        http::host,
+    };
+    use axum::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode, Uri};
+    use axum_test::TestServer as TestClient;
+    use ipnet::IpNet;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn host() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-404.html.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Site is resolved from Host header with base domain
+        let host_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&format!("{first_random_subdomain}.sero.test"))
+                    .expect("Failed to convert host to header value!"),
+            )
+            .await;
+        assert_eq!(host_response.status_code(), StatusCode::OK);
+
+        //* Port and case are ignored
+        let host_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&format!("{}.SERO.test:8080", first_random_subdomain.to_uppercase()))
+                    .expect("Failed to convert host to header value!"),
+            )
+            .await;
+        assert_eq!(host_response.status_code(), StatusCode::OK);
+
+        //* Hosts outside of base domains are not resolved
+        let foreign_host_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&format!("{first_random_subdomain}.example.com"))
+                    .expect("Failed to convert host to header value!"),
+            )
+            .await;
+        assert_eq!(foreign_host_response.status_code(), StatusCode::BAD_REQUEST);
+
+        //* Only one level of subdomains is served
+        let nested_host_response = get(&client, "/some/index.html")
+            .add_header(
+                header::HOST,
+                HeaderValue::from_str(&format!("www.{first_random_subdomain}.sero.test"))
+                    .expect("Failed to convert host to header value!"),
+            )
+            .await;
+        assert_eq!(nested_host_response.status_code(), StatusCode::BAD_REQUEST);
+
+        //* x-subdomain of untrusted peer is ignored if base domains are set
+        let spoofed_response = get(&client, "/some/index.html")
+            .add_header(
+                HeaderName::from_static(host::X_SUBDOMAIN),
+                HeaderValue::from_str(&first_random_subdomain).expect("Failed to convert subdomain to header value!"),
+            )
+            .await;
+        assert_eq!(spoofed_response.status_code(), StatusCode::BAD_REQUEST);
+
+        //* x-subdomain is honoured only from trusted proxies
+        let base_domains = vec![String::from("sero.test")];
+        let trusted_proxies = vec!["10.0.0.0/8".parse::<IpNet>().expect("Failed to parse network")];
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::HOST, HeaderValue::from_static("real.sero.test"));
+        headers.insert(
+            HeaderName::from_static(host::X_SUBDOMAIN),
+            HeaderValue::from_static("spoofed"),
+        );
+        let uri = Uri::from_static("/");
+
+        let untrusted = host::subdomain(
+            &headers,
+            &uri,
+            Some([203, 0, 113, 7].into()),
+            &base_domains,
+            Some(trusted_proxies.as_slice()),
+        );
+        assert_eq!(untrusted.as_deref(), Some("real"));
+
+        let unknown_peer = host::subdomain(&headers, &uri, None, &base_domains, Some(trusted_proxies.as_slice()));
+        assert_eq!(unknown_peer.as_deref(), Some("real"));
+
+        let trusted = host::subdomain(
+            &headers,
+            &uri,
+            Some([10, 1, 2, 3].into()),
+            &base_domains,
+            Some(trusted_proxies.as_slice()),
+        );
+        assert_eq!(trusted.as_deref(), Some("spoofed"));
+
+        let without_proxies = host::subdomain(&headers, &uri, Some([203, 0, 113, 7].into()), &base_domains, None);
+        assert_eq!(without_proxies.as_deref(), Some("real"));
+
+        //* Header is the only way to resolve sites without base domains
+        let legacy = host::subdomain(&headers, &uri, Some([203, 0, 113, 7].into()), &[], None);
+        assert_eq!(legacy.as_deref(), Some("spoofed"));
+    }
+}
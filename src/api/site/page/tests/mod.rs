@@ -1 +1,13 @@
+pub mod access;
 pub mod call;
+pub mod conditional;
+pub mod content_type;
+pub mod directories;
+pub mod encoding;
+pub mod headers;
+pub mod host;
+pub mod protection;
+pub mod range;
+pub mod rate_limit;
+pub mod redirects;
+pub mod spa;
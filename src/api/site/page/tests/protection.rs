@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                disable::tests::call::tests::disable,
+                page::tests::call::tests::{host, page},
+                protect::{
+                    request::{CredentialsRequest, ProtectRequest},
+                    tests::call::tests::protect,
+                },
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+        },
+        app,
+        services::site::service::Service as SiteService,
+        site::parameters::{Authorization, AuthorizeParameters, Credentials},
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::{TestResponse, TestServer as TestClient};
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use entity::prelude::*;
+    use sea_orm::prelude::*;
+    use std::net::IpAddr;
+    use uuid::Uuid;
+
+    async fn authorized(
+        client: &TestClient,
+        url: &str,
+        subdomain: &str,
+        username: &str,
+        password: &str,
+    ) -> TestResponse {
+        let authorization = format!("Basic {}", STANDARD.encode(format!("{username}:{password}")));
+
+        get(client, url)
+            .add_header(header::HOST, host(subdomain))
+            .add_header(
+                header::AUTHORIZATION,
+                HeaderValue::from_str(&authorization).expect("Failed to convert credentials to header value!"),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn protection() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-404.html.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let request = ProtectRequest {
+            credentials: vec![CredentialsRequest {
+                username: String::from("client"),
+                password: String::from("pre:view"),
+            }],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert!(protect_response.is_ok());
+
+        //* Without credentials
+        let page_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            page_response.headers().get(header::WWW_AUTHENTICATE),
+            Some(
+                &HeaderValue::from_str(&format!("Basic realm=\"{first_random_subdomain}\", charset=\"UTF-8\""))
+                    .expect("never fails")
+            )
+        );
+
+        //* Wrong password
+        let page_response = authorized(&client, "/index.html", &first_random_subdomain, "client", "wrong").await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+
+        //* Unknown username
+        let page_response = authorized(&client, "/index.html", &first_random_subdomain, "unknown", "pre:view").await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+
+        //* Correct credentials
+        let page_response = authorized(&client, "/index.html", &first_random_subdomain, "client", "pre:view").await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+
+        //* Pass is issued so credentials are not verified on every request
+        let pass = page_response
+            .headers()
+            .get(header::SET_COOKIE)
+            .and_then(|cookie| cookie.to_str().ok())
+            .and_then(|cookie| cookie.split(';').next())
+            .map(str::to_owned)
+            .expect("Pass cookie must be set");
+        assert!(pass.starts_with("sero-pass="));
+
+        let page_response = get(&client, "/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(
+                header::COOKIE,
+                HeaderValue::from_str(&pass).expect("Failed to convert pass to header value!"),
+            )
+            .await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+        assert!(page_response.headers().get(header::SET_COOKIE).is_none());
+
+        //* Tampered pass
+        let page_response = get(&client, "/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(
+                header::COOKIE,
+                HeaderValue::from_str(&format!("{pass}A")).expect("Failed to convert pass to header value!"),
+            )
+            .await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+
+        //* Custom 404 page is protected as well
+        let page_response = page(&client, "/missing.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+        assert!(page_response.as_bytes().is_empty());
+
+        let page_response = authorized(&client, "/missing.html", &first_random_subdomain, "client", "pre:view").await;
+        assert_eq!(page_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Disabled site does not reveal 503 without credentials
+        let disable_response = disable(&client, &first_random_subdomain, &first_user_token).await;
+        assert!(disable_response.is_ok());
+
+        let page_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::UNAUTHORIZED);
+
+        let page_response = authorized(&client, "/index.html", &first_random_subdomain, "client", "pre:view").await;
+        assert_eq!(page_response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn lockout() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let request = ProtectRequest {
+            credentials: vec![CredentialsRequest {
+                username: String::from("client"),
+                password: String::from("pre:view"),
+            }],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert!(protect_response.is_ok());
+
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&first_random_subdomain))
+            .one(state.connection())
+            .await
+            .expect("Failed to find subdomain!")
+            .expect("Subdomain must exist!");
+
+        let connection = state.connection();
+        let secret = state.configuration().jwt_secret().to_owned();
+
+        let authorize = move |address: [u8; 4], username: &str, password: &str| {
+            let parameters = AuthorizeParameters {
+                subdomain_id: subdomain.id,
+                credentials: Some(Credentials {
+                    username: username.to_owned(),
+                    password: password.to_owned(),
+                }),
+                pass: None,
+                secret: secret.clone(),
+                address: Some(IpAddr::from(address)),
+                attempts: 3,
+                lockout_seconds: 60,
+            };
+
+            SiteService::authorize(parameters, connection)
+        };
+
+        //* Unknown usernames and wrong passwords are counted alike
+        for (username, password) in [("unknown", "pre:view"), ("client", "wrong"), ("client", "wrong")] {
+            let authorization = authorize([192, 0, 2, 1], username, password).await;
+            assert!(matches!(authorization, Ok(Authorization::Denied)));
+        }
+
+        //* Correct credentials are not checked during lockout
+        let authorization = authorize([192, 0, 2, 1], "client", "pre:view").await;
+        assert!(matches!(authorization, Ok(Authorization::Locked(_))));
+
+        //* Other clients are not affected
+        let authorization = authorize([192, 0, 2, 2], "client", "pre:view").await;
+        assert!(matches!(authorization, Ok(Authorization::Verified(_))));
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::host, upload::tests::call::tests::upload},
+            tests::get,
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::{TestRequest, TestServer as TestClient};
+    use uuid::Uuid;
+
+    fn page_with_range(client: &TestClient, subdomain: &str, range: &'static str) -> TestRequest {
+        get(client, "/some/index.html")
+            .add_header(header::HOST, host(subdomain))
+            .add_header(header::RANGE, HeaderValue::from_static(range))
+    }
+
+    #[tokio::test]
+    async fn range() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //? some/index.html contains "index\n"
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-503.html.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Single range
+        let single_range_response = page_with_range(&client, &first_random_subdomain, "bytes=0-2").await;
+        assert_eq!(single_range_response.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(single_range_response.text(), "ind");
+        assert_eq!(
+            single_range_response.headers().get(header::CONTENT_RANGE),
+            Some(&HeaderValue::from_static("bytes 0-2/6"))
+        );
+
+        //* Suffix range
+        let suffix_range_response = page_with_range(&client, &first_random_subdomain, "bytes=-3").await;
+        assert_eq!(suffix_range_response.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(suffix_range_response.text(), "ex\n");
+
+        //* Multiple ranges
+        let multiple_ranges_response = page_with_range(&client, &first_random_subdomain, "bytes=0-0,2-3").await;
+        assert_eq!(multiple_ranges_response.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert!(multiple_ranges_response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("multipart/byteranges; boundary=")));
+        let multiple_ranges_body = multiple_ranges_response.text();
+        assert!(multiple_ranges_body.contains("Content-Range: bytes 0-0/6\r\n\r\ni"));
+        assert!(multiple_ranges_body.contains("Content-Range: bytes 2-3/6\r\n\r\nde"));
+
+        //* Unsatisfiable range
+        let unsatisfiable_range_response = page_with_range(&client, &first_random_subdomain, "bytes=10-").await;
+        assert_eq!(
+            unsatisfiable_range_response.status_code(),
+            StatusCode::RANGE_NOT_SATISFIABLE
+        );
+        assert_eq!(
+            unsatisfiable_range_response.headers().get(header::CONTENT_RANGE),
+            Some(&HeaderValue::from_static("bytes */6"))
+        );
+
+        //* Outdated If-Range means full representation
+        let outdated_range_response = page_with_range(&client, &first_random_subdomain, "bytes=0-2")
+            .add_header(header::IF_RANGE, HeaderValue::from_static("\"outdated\""))
+            .await;
+        assert_eq!(outdated_range_response.status_code(), StatusCode::OK);
+        assert_eq!(outdated_range_response.text(), "index\n");
+        assert_eq!(
+            outdated_range_response.headers().get(header::CONTENT_LENGTH),
+            Some(&HeaderValue::from_static("6"))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                configure::{request::ConfigureRequest, tests::call::tests::configure},
+                page::tests::call::tests::page,
+                preview::tests::call::tests::preview,
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+        limits::{
+            bucket::Limit,
+            limiter::{Key, RateLimiter, Scope},
+        },
+    };
+    use axum::http::{header, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::net::{IpAddr, SocketAddr};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn rate_limit() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app.into_make_service_with_connect_info::<SocketAddr>())
+            .expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Site overrides server wide limit
+        let limit_request = ConfigureRequest {
+            rate_limit: Some(60),
+            rate_burst: Some(2),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &limit_request).await;
+        assert!(configure_response.is_ok());
+        assert_eq!(configure_response.expect("to never fail").rate_limit, Some(60));
+
+        let preview_response = preview(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await
+        .expect("to never fail");
+
+        //* Burst is allowed
+        for _ in 0..2 {
+            let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+            assert_eq!(page_response.status_code(), StatusCode::OK);
+        }
+
+        //* Too many requests
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+
+        //* Preview shares the limit of its site
+        let preview_page_response = page(&client, "/some/index.html", &preview_response.name).await;
+        assert_eq!(preview_page_response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            page_response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok()),
+            Some("1")
+        );
+
+        //* Zero resets override
+        let reset_request = ConfigureRequest {
+            rate_limit: Some(0),
+            rate_burst: Some(0),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &reset_request).await;
+        assert!(configure_response.is_ok());
+        assert_eq!(configure_response.expect("to never fail").rate_limit, None);
+
+        //* Server wide limit is applied again
+        let page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+    }
+
+    #[test]
+    fn limiter() {
+        let limiter = RateLimiter::default();
+        let limit = Limit::per_minute(60, Some(2)).expect("to never fail");
+
+        let key = |address: [u8; 4], site: i64| Key {
+            scope: Scope::Pages,
+            address: IpAddr::from(address),
+            site: Some(site),
+        };
+
+        assert!(limiter.check(key([127, 0, 0, 1], 1), limit).is_ok());
+        assert!(limiter.check(key([127, 0, 0, 1], 1), limit).is_ok());
+        assert!(limiter.check(key([127, 0, 0, 1], 1), limit).is_err());
+
+        //* Buckets are separate for every client and site
+        assert!(limiter.check(key([127, 0, 0, 2], 1), limit).is_ok());
+        assert!(limiter.check(key([127, 0, 0, 1], 2), limit).is_ok());
+
+        //* Sweep drops only full buckets
+        assert_eq!(limiter.sweep(), 0);
+        assert!(limiter.check(key([127, 0, 0, 1], 1), limit).is_err());
+
+        //* Zero means there is no limit
+        assert_eq!(Limit::per_minute(0, Some(10)), None);
+
+        assert_eq!(Scope::of("/api/auth/login"), Some(Scope::Auth));
+        assert_eq!(Scope::of("/api/site/settings"), Some(Scope::Api));
+        assert_eq!(Scope::of("/api/health"), None);
+        assert_eq!(Scope::of("/api-docs/openapi.json"), None);
+        assert_eq!(Scope::of("/.well-known/acme-challenge/token"), None);
+        assert_eq!(Scope::of("/some/index.html"), Some(Scope::Pages));
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn redirects() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-redirects.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Splat is substituted into destination
+        let splat_response = page(&client, "/blog/first", &first_random_subdomain).await;
+        assert_eq!(splat_response.status_code(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            splat_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/posts/first"))
+        );
+
+        //* Query string is passed through
+        let query_response = page(&client, "/blog/first?ref=home", &first_random_subdomain).await;
+        assert_eq!(
+            query_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/posts/first?ref=home"))
+        );
+
+        //* Forced rule with explicit status
+        let found_response = page(&client, "/old", &first_random_subdomain).await;
+        assert_eq!(found_response.status_code(), StatusCode::FOUND);
+        assert_eq!(
+            found_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/"))
+        );
+
+        //* Query parameter is captured into placeholder
+        //* and dropped from the passed through query
+        let store_response = page(&client, "/store?id=42", &first_random_subdomain).await;
+        assert_eq!(store_response.status_code(), StatusCode::FOUND);
+        assert_eq!(
+            store_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/products/42"))
+        );
+
+        let store_response = page(&client, "/store?id=42&ref=home", &first_random_subdomain).await;
+        assert_eq!(
+            store_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/products/42?ref=home"))
+        );
+
+        //* Rule with unmet query condition is skipped
+        //* so the catch-all rewrite is applied
+        let store_response = page(&client, "/store", &first_random_subdomain).await;
+        assert_eq!(store_response.status_code(), StatusCode::OK);
+        assert_eq!(store_response.text(), "index\n");
+
+        //* Redirect to another host
+        let external_response = page(&client, "/external", &first_random_subdomain).await;
+        assert_eq!(external_response.status_code(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            external_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("https://example.com/"))
+        );
+
+        //* Rewrite serves destination without redirect
+        let rewrite_response = page(&client, "/app/some/route", &first_random_subdomain).await;
+        assert_eq!(rewrite_response.status_code(), StatusCode::OK);
+        assert_eq!(rewrite_response.text(), "index\n");
+
+        //* Rewrite with 404 status
+        let gone_response = page(&client, "/gone", &first_random_subdomain).await;
+        assert_eq!(gone_response.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(gone_response.text(), "404\n");
+
+        //* Existing files are not shadowed by catch-all rule
+        let post_response = page(&client, "/posts/first", &first_random_subdomain).await;
+        assert_eq!(post_response.status_code(), StatusCode::OK);
+        assert_eq!(post_response.text(), "first\n");
+
+        let not_found_response = page(&client, "/404.html", &first_random_subdomain).await;
+        assert_eq!(not_found_response.status_code(), StatusCode::OK);
+        assert_eq!(not_found_response.text(), "404\n");
+
+        //* Forced rule shadows existing file
+        let hidden_response = page(&client, "/hidden", &first_random_subdomain).await;
+        assert_eq!(hidden_response.status_code(), StatusCode::FOUND);
+        assert_eq!(
+            hidden_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/"))
+        );
+
+        //* Redirects file itself is not served
+        //* so the catch-all rewrite is applied
+        let redirects_file_response = page(&client, "/_redirects", &first_random_subdomain).await;
+        assert_eq!(redirects_file_response.status_code(), StatusCode::OK);
+        assert_eq!(redirects_file_response.text(), "index\n");
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{page::tests::call::tests::page, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn spa() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-with-spa.zip",
+        )
+        .await;
+        assert!(first_correct_upload_response.is_ok());
+
+        //* Mode is enabled from sero.toml
+        let route_response = page(&client, "/dashboard/settings", &first_random_subdomain).await;
+        assert_eq!(route_response.status_code(), StatusCode::OK);
+        assert_eq!(route_response.text(), "index\n");
+
+        let asset_response = page(&client, "/assets/app.js", &first_random_subdomain).await;
+        assert_eq!(asset_response.status_code(), StatusCode::OK);
+        assert_eq!(asset_response.text(), "app\n");
+
+        //* Missing assets are still not found
+        let missing_asset_response = page(&client, "/assets/missing.js", &first_random_subdomain).await;
+        assert_eq!(missing_asset_response.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(missing_asset_response.text(), "404\n");
+
+        //* Configuration file itself is not served
+        let configuration_file_response = page(&client, "/sero.toml", &first_random_subdomain).await;
+        assert_eq!(configuration_file_response.status_code(), StatusCode::NOT_FOUND);
+    }
+}
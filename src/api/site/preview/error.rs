@@ -0,0 +1,34 @@
+use crate::{services::archive::error::ServiceError as ArchiveServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum UploadPreviewError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error(transparent)]
+    ArchiveServiceError(#[from] ArchiveServiceError),
+}
+
+impl From<UploadPreviewError> for StatusCode {
+    fn from(value: UploadPreviewError) -> Self {
+        match value {
+            UploadPreviewError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
+            UploadPreviewError::ArchiveServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for UploadPreviewError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
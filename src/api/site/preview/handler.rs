@@ -0,0 +1,78 @@
+use super::{error::UploadPreviewError, response::UploadPreviewResponse};
+use crate::{
+    api::site::upload::handler::UploadData, archive::parameters::UploadParameters, extractors::*, http::host,
+    services::archive::service::Service as ArchiveService, state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use axum_typed_multipart::TypedMultipart;
+use chrono::{Duration, Utc};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Uploads preview of a specified subdomain.
+/// Preview is served on `<id>--<subdomain>` while the site keeps
+/// serving its active deployment until the preview is promoted.
+/// Previews are removed by the cleanup task after `PREVIEW_TTL_SECONDS`.
+///
+/// Previews can be uploaded only for existing sites
+/// so they do not count against `MAX_SITES_PER_USER`.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Upload preview",
+    post,
+    path = "/api/site/previews",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+      request_body(content = UploadData, content_type = "multipart/form-data"),
+      responses(
+        (status = 200, description = "Preview was successfully uploaded",                                                   body = UploadPreviewResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state, archive, user))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    TypedMultipart(UploadData { archive }): TypedMultipart<UploadData>,
+) -> Result<impl IntoResponse, UploadPreviewError> {
+    let transaction = state.connection().begin().await?;
+
+    let expires_at =
+        Utc::now() + Duration::try_seconds(state.configuration().preview_ttl_seconds()).unwrap_or_default();
+
+    let upload_parameters = UploadParameters {
+        subdomain_id: subdomain.id,
+        contents: archive.contents,
+        upload_folder: state.configuration().upload_folder(),
+        expires_at: Some(expires_at),
+        precompress_limit: state.configuration().max_precompress_size(),
+    };
+
+    tracing::trace!(%subdomain.id,
+        %subdomain.name,
+        %user.id,
+        "Starting preview upload process!");
+
+    let preview = ArchiveService::upload(upload_parameters, &transaction).await?;
+
+    tracing::trace!(%subdomain.id,
+        %subdomain.name,
+        %user.id,
+        %preview.id,
+        "Preview was successfully uploaded. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(Json(UploadPreviewResponse {
+        name: host::preview_name(preview.id, &subdomain.name),
+        id: preview.id,
+        expires_at,
+    }))
+}
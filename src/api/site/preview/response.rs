@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"id": 3, "name": "3--example", "expires_at": "2024-11-10T12:00:00Z"}))]
+pub struct UploadPreviewResponse {
+    pub id: i64,
+    /// Name the preview is served on until it is promoted
+    pub name: String,
+    pub expires_at: DateTime<Utc>,
+}
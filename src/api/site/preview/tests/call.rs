@@ -0,0 +1,40 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::api::{site::preview::response::UploadPreviewResponse, tests::post};
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::{
+        multipart::{MultipartForm, Part},
+        TestServer as TestClient,
+    };
+    use std::{fmt::Display, fs, path::Path};
+
+    pub async fn preview<T, S, F>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        filename: F,
+    ) -> Result<UploadPreviewResponse, StatusCode>
+    where
+        T: Display,
+        S: AsRef<str>,
+        F: AsRef<Path>,
+    {
+        let buffer = fs::read(filename.as_ref()).expect("Failed to read zip bytes into buffer");
+
+        let form = MultipartForm::new().add_part("archive", Part::bytes(buffer));
+
+        let response = post(client, "/api/site/previews", Option::<()>::None)
+            .multipart(form)
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err(response.status_code()),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments, page::tests::call::tests::page,
+                preview::tests::call::tests::preview, upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Preview does not replace production
+        let preview_response = preview(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await
+        .expect("Failed to upload preview!");
+        assert_eq!(
+            preview_response.name,
+            format!("{}--{first_random_subdomain}", preview_response.id)
+        );
+
+        let production_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(production_page_response.status_code(), StatusCode::OK);
+        assert_eq!(production_page_response.as_bytes().len(), 1222400);
+
+        //* Preview is served on its own name
+        let preview_index_response = page(&client, "/", &preview_response.name).await;
+        assert_eq!(preview_index_response.status_code(), StatusCode::NOT_FOUND);
+
+        let preview_page_response = page(&client, "/some/index.html", &preview_response.name).await;
+        assert_eq!(preview_page_response.status_code(), StatusCode::OK);
+        assert_eq!(preview_page_response.as_bytes().len(), 2);
+
+        //* Preview is not a part of deployment history
+        let deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(deployments_response.deployments.len(), 1);
+        assert!(deployments_response.deployments[0].active);
+
+        //* Previews of unknown sites are not served
+        let unknown_preview_response = page(
+            &client,
+            "/some/index.html",
+            format!("{}--{}", preview_response.id, Uuid::new_v4()),
+        )
+        .await;
+        assert_eq!(unknown_preview_response.status_code(), StatusCode::NOT_FOUND);
+
+        //* Names of previews can not be taken by sites
+        let reserved_upload_response = upload(
+            &client,
+            &first_user_token,
+            format!("1--{first_random_subdomain}"),
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(reserved_upload_response, Err(StatusCode::BAD_REQUEST));
+
+        //* Previews can be uploaded only for existing sites
+        let missing_preview_response = preview(
+            &client,
+            &first_user_token,
+            Uuid::new_v4().to_string(),
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert_eq!(
+            missing_preview_response.expect_err("Preview created a site!"),
+            StatusCode::NOT_FOUND
+        );
+    }
+}
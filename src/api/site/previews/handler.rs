@@ -0,0 +1,59 @@
+use super::{
+    error::ListPreviewsError,
+    response::{ListPreviewsResponse, PreviewResponse},
+};
+use crate::{
+    extractors::*, http::host, services::deployment::service::Service as DeploymentService, state::State as AppState,
+};
+use axum::{extract::State, Json};
+use std::sync::Arc;
+
+/// List previews of the specified subdomain.
+///
+/// Previews are served on `<id>--<subdomain>` until they are promoted
+/// or expire. Expiration is configured with `PREVIEW_TTL_SECONDS` env.
+#[utoipa::path(
+    get,
+    tag = "Actions",
+    operation_id = "List previews",
+    path = "/api/site/previews",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Previews were successfully retrieved.",                                      body = ListPreviewsResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",        body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                 body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                         body = Details),
+        (status = 404, description = "Not Found: The subdomain was not found.",                                    body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                    body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<Json<ListPreviewsResponse>, ListPreviewsError> {
+    tracing::trace!(%user.id, %subdomain.name, "Retrieving previews...");
+
+    let previews = DeploymentService::previews(subdomain.id, state.connection()).await?;
+
+    tracing::trace!(%user.id, %subdomain.name, amount = previews.len(), "Previews were successfully retrieved!");
+
+    Ok(Json(ListPreviewsResponse {
+        previews: previews
+            .into_iter()
+            .map(|summary| PreviewResponse {
+                id: summary.deployment.id,
+                name: host::preview_name(summary.deployment.id, &subdomain.name),
+                files: summary.files,
+                bytes: summary.bytes,
+                created_at: summary.deployment.created_at,
+                expires_at: summary.deployment.expires_at,
+            })
+            .collect(),
+    }))
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PreviewResponse {
+    pub id: i64,
+    /// Name the preview is served on until it is promoted
+    pub name: String,
+    /// Deployed files without precompressed variants
+    pub files: u64,
+    /// Total size of deployed files in bytes
+    pub bytes: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"previews": [{"id": 3, "name": "3--example", "files": 12, "bytes": 48213, "created_at": "2024-11-03T12:00:00+00:00", "expires_at": "2024-11-10T12:00:00+00:00"}]}))]
+pub struct ListPreviewsResponse {
+    /// Previews which have not expired yet from the newest to the oldest
+    pub previews: Vec<PreviewResponse>,
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                preview::tests::call::tests::preview, previews::tests::call::tests::previews,
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let empty_previews_response = previews(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list previews!");
+        assert!(empty_previews_response.previews.is_empty());
+
+        let preview_response = preview(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await
+        .expect("Failed to upload preview!");
+
+        let previews_response = previews(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list previews!");
+        assert_eq!(previews_response.previews.len(), 1);
+
+        let listed_preview = &previews_response.previews[0];
+        assert_eq!(listed_preview.id, preview_response.id);
+        assert_eq!(listed_preview.name, preview_response.name);
+        assert_eq!(listed_preview.files, 2);
+        assert_eq!(listed_preview.bytes, 2 * 1222400);
+        assert!(listed_preview.expires_at.is_some());
+    }
+}
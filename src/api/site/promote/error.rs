@@ -0,0 +1,34 @@
+use crate::{services::deployment::error::ServiceError as DeploymentServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum PromoteError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
+}
+
+impl From<PromoteError> for StatusCode {
+    fn from(value: PromoteError) -> Self {
+        match value {
+            PromoteError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            PromoteError::DeploymentServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for PromoteError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
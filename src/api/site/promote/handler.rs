@@ -0,0 +1,72 @@
+use super::error::PromoteError;
+use crate::{extractors::*, services::deployment::service::Service as DeploymentService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Promote preview of the specified subdomain.
+///
+/// Preview becomes a regular deployment which is served on the site at once.
+/// Deployments which do not fit into `MAX_DEPLOYMENTS` are pruned
+/// the same way as after an upload.
+#[utoipa::path(
+    patch,
+    tag = "Actions",
+    operation_id = "Promote preview",
+    path = "/api/site/previews/{id}/promote",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+        ("id" = i64, Path, description = "Id of the preview to promote"),
+      ),
+    responses(
+        (status = 204, description = "Preview was successfully promoted."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",  body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",           body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                   body = Details),
+        (status = 404, description = "Not Found: The subdomain or preview was not found.",                   body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(deployment_id): Path<i64>,
+) -> Result<impl IntoResponse, PromoteError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%user.id, %subdomain.name, %deployment_id, "Promoting preview...");
+
+    let (deployment, pruned) = DeploymentService::promote(
+        subdomain.id,
+        deployment_id,
+        state.configuration().max_deployments(),
+        &transaction,
+    )
+    .await?;
+
+    tracing::trace!(%user.id,
+        %subdomain.name,
+        %deployment.id,
+        amount = pruned.len(),
+        "Preview was successfully promoted and deployments which do not fit into retention limit were pruned. Committing changes...");
+
+    transaction.commit().await?;
+
+    //? Archives are removed only after the transaction
+    //? is committed so they are never lost on failure
+    for archive_path in pruned.iter().filter_map(|deployment| deployment.archive_path.as_ref()) {
+        tokio::fs::remove_file(archive_path)
+            .await
+            .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"))
+            .ok();
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments, page::tests::call::tests::page,
+                preview::tests::call::tests::preview, previews::tests::call::tests::previews,
+                promote::tests::call::tests::promote, upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let preview_response = preview(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await
+        .expect("Failed to upload preview!");
+
+        //* Promoted preview is served on the site
+        let promote_response = promote(&client, &first_random_subdomain, &first_user_token, preview_response.id).await;
+        assert_eq!(promote_response, Ok(()));
+
+        let promoted_index_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(promoted_index_response.status_code(), StatusCode::NOT_FOUND);
+
+        let promoted_page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(promoted_page_response.status_code(), StatusCode::OK);
+        assert_eq!(promoted_page_response.as_bytes().len(), 2);
+
+        //* Promoted preview becomes a regular deployment
+        let previews_response = previews(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list previews!");
+        assert!(previews_response.previews.is_empty());
+
+        let deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(deployments_response.deployments.len(), 2);
+        assert!(deployments_response.deployments[0].active);
+        assert_eq!(deployments_response.deployments[0].id, preview_response.id);
+
+        //* Preview is promoted only once
+        let second_promote_response =
+            promote(&client, &first_random_subdomain, &first_user_token, preview_response.id).await;
+        assert_eq!(
+            second_promote_response.expect_err("Deployment was promoted twice!").0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
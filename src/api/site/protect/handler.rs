@@ -0,0 +1,78 @@
+use super::{error::ProtectError, request::ProtectRequest, response::ProtectResponse};
+use crate::{
+    extractors::*,
+    services::site::service::Service as SiteService,
+    site::parameters::{Credentials, ProtectParameters},
+    state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+use validator::Validate;
+
+/// Protects a specific site identified by the `x-subdomain` header with HTTP Basic auth.
+///
+/// Provided credentials replace current ones. Visitors must provide one of them
+/// to see any page of the site including custom 404 and 503 pages.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Protect site",
+    put,
+    path = "/api/site/protection",
+    request_body = ProtectRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Site was successfully protected",                                                     body = ProtectResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or credentials are invalid or duplicated.",       body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<ProtectRequest>,
+) -> Result<impl IntoResponse, ProtectError> {
+    //? Safe because of custom Debug impl
+    tracing::trace!(?payload, "Validating provided credentials...");
+    payload.validate()?;
+
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Protecting site...");
+    let transaction = state.connection().begin().await?;
+
+    let parameters = ProtectParameters {
+        subdomain_id: subdomain.id,
+        credentials: payload
+            .credentials
+            .into_iter()
+            .map(|credentials| Credentials {
+                username: credentials.username,
+                password: credentials.password,
+            })
+            .collect(),
+    };
+    let credentials = SiteService::protect(parameters, &transaction).await?;
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Site was successfully protected. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(Json(ProtectResponse {
+        usernames: credentials
+            .into_iter()
+            .map(|credentials| credentials.username)
+            .collect(),
+    }))
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, Validate, ToSchema)]
+pub struct CredentialsRequest {
+    /// Username which is asked by browser.
+    /// It must not contain colon as basic auth splits on it.
+    #[validate(length(min = 1, max = 64), does_not_contain(pattern = ":"))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub username: String,
+
+    #[validate(length(min = 1, max = 128))]
+    #[schema(min_length = 1, max_length = 128)]
+    pub password: String,
+}
+
+impl Debug for CredentialsRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CredentialsRequest")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+#[schema(example = json!({"credentials": [{"username": "client", "password": "preview"}]}))]
+pub struct ProtectRequest {
+    /// Replaces all current credentials of the site
+    #[validate(length(min = 1), nested)]
+    pub credentials: Vec<CredentialsRequest>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"usernames": ["client"]}))]
+pub struct ProtectResponse {
+    /// Usernames which can view the site
+    pub usernames: Vec<String>,
+}
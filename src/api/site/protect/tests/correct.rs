@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                protect::{
+                    request::{CredentialsRequest, ProtectRequest},
+                    response::ProtectResponse,
+                    tests::call::tests::protect,
+                },
+                upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Credentials are set
+        let request = ProtectRequest {
+            credentials: vec![
+                CredentialsRequest {
+                    username: String::from("client"),
+                    password: String::from("preview"),
+                },
+                CredentialsRequest {
+                    username: String::from("manager"),
+                    password: String::from("with:colon"),
+                },
+            ],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response,
+            Ok(ProtectResponse {
+                usernames: vec![String::from("client"), String::from("manager")]
+            })
+        );
+
+        //* Credentials are replaced
+        let request = ProtectRequest {
+            credentials: vec![CredentialsRequest {
+                username: String::from("another"),
+                password: String::from("preview"),
+            }],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response,
+            Ok(ProtectResponse {
+                usernames: vec![String::from("another")]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* No credentials
+        let request = ProtectRequest { credentials: vec![] };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        //* Username with colon
+        let request = ProtectRequest {
+            credentials: vec![CredentialsRequest {
+                username: String::from("cli:ent"),
+                password: String::from("preview"),
+            }],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        //* Empty password
+        let request = ProtectRequest {
+            credentials: vec![CredentialsRequest {
+                username: String::from("client"),
+                password: String::new(),
+            }],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        //* Duplicated username
+        let request = ProtectRequest {
+            credentials: vec![
+                CredentialsRequest {
+                    username: String::from("client"),
+                    password: String::from("first"),
+                },
+                CredentialsRequest {
+                    username: String::from("client"),
+                    password: String::from("second"),
+                },
+            ],
+        };
+        let protect_response = protect(&client, &first_user_token, &first_random_subdomain, &request).await;
+        assert_eq!(
+            protect_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
+}
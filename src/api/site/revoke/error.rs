@@ -0,0 +1,34 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RevokeError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+}
+
+impl From<RevokeError> for StatusCode {
+    fn from(value: RevokeError) -> Self {
+        match value {
+            RevokeError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
+            RevokeError::SiteServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for RevokeError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
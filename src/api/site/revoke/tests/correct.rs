@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                configure::{request::ConfigureRequest, tests::call::tests::configure},
+                page::tests::call::tests::host,
+                revoke::tests::call::tests::revoke,
+                share::{request::ShareRequest, tests::call::tests::share},
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+        },
+        app,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::{TestResponse, TestServer as TestClient};
+    use uuid::Uuid;
+
+    async fn shared(client: &TestClient, subdomain: &str, token: &str) -> TestResponse {
+        get(client, "/index.html")
+            .add_header(header::HOST, host(subdomain))
+            .add_header(
+                header::COOKIE,
+                HeaderValue::from_str(&format!("sero-share={token}"))
+                    .expect("Failed to convert cookie to header value!"),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let private_request = ConfigureRequest {
+            private: Some(true),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &private_request).await;
+        assert!(configure_response.is_ok());
+
+        let request = ShareRequest { expires_in: 3600 };
+        let first_share_response = share(&client, &first_user_token, &first_random_subdomain, &request)
+            .await
+            .expect("Failed to mint share link!");
+
+        let page_response = shared(&client, &first_random_subdomain, &first_share_response.token).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+
+        //* Outstanding links stop working
+        let revoke_response = revoke(&client, &first_user_token, &first_random_subdomain).await;
+        assert!(revoke_response.is_ok());
+
+        let page_response = shared(&client, &first_random_subdomain, &first_share_response.token).await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+
+        //* New links are signed with the new secret
+        let second_share_response = share(&client, &first_user_token, &first_random_subdomain, &request)
+            .await
+            .expect("Failed to mint share link!");
+
+        let page_response = shared(&client, &first_random_subdomain, &second_share_response.token).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+    }
+}
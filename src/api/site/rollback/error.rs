@@ -0,0 +1,34 @@
+use crate::{services::deployment::error::ServiceError as DeploymentServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RollbackError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
+}
+
+impl From<RollbackError> for StatusCode {
+    fn from(value: RollbackError) -> Self {
+        match value {
+            RollbackError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            RollbackError::DeploymentServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for RollbackError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
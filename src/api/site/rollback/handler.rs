@@ -0,0 +1,52 @@
+use super::error::RollbackError;
+use crate::{extractors::*, services::deployment::service::Service as DeploymentService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Roll back the specified subdomain to a retained deployment.
+///
+/// Files, header and redirect rules and settings from `sero.toml`
+/// of the deployment are served at once. Newer deployments are kept
+/// so the site can be rolled forward again.
+#[utoipa::path(
+    patch,
+    tag = "Actions",
+    operation_id = "Roll back site",
+    path = "/api/site/deployments/{id}/rollback",
+    params(
+        ("x-subdomain" = String, 
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+        ("id" = i64, Path, description = "Id of the deployment to make active"),
+      ),
+    responses(
+        (status = 204, description = "Site was successfully rolled back."),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",  body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",           body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                   body = Details),
+        (status = 404, description = "Not Found: The subdomain or deployment was not found.",                body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",              body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Path(deployment_id): Path<i64>,
+) -> Result<impl IntoResponse, RollbackError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%user.id, %subdomain.name, %deployment_id, "Rolling back site...");
+
+    let deployment = DeploymentService::rollback(subdomain.id, deployment_id, &transaction).await?;
+
+    tracing::trace!(%user.id, %subdomain.name, %deployment.id, "Site was successfully rolled back. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments, page::tests::call::tests::page,
+                rollback::tests::call::tests::rollback, upload::tests::call::tests::upload,
+            },
+        },
+        app,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Bad deploy without index.html
+        let second_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await;
+        assert_eq!(second_correct_upload_response, Ok(()));
+
+        let broken_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(broken_page_response.status_code(), StatusCode::NOT_FOUND);
+
+        let deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        let (newest, previous) = (
+            &deployments_response.deployments[0],
+            &deployments_response.deployments[1],
+        );
+
+        //* Previous deployment is served again without re-uploading
+        let rollback_response = rollback(&client, &first_random_subdomain, &first_user_token, previous.id).await;
+        assert_eq!(rollback_response, Ok(()));
+
+        let restored_page_response = page(&client, "/", &first_random_subdomain).await;
+        assert_eq!(restored_page_response.status_code(), StatusCode::OK);
+        assert_eq!(restored_page_response.as_bytes().len(), 1222400);
+
+        let rolled_back_deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        assert_eq!(rolled_back_deployments_response.deployments.len(), 2);
+        assert!(!rolled_back_deployments_response.deployments[0].active);
+        assert!(rolled_back_deployments_response.deployments[1].active);
+
+        //* Newer deployment is kept so site can be rolled forward
+        let roll_forward_response = rollback(&client, &first_random_subdomain, &first_user_token, newest.id).await;
+        assert_eq!(roll_forward_response, Ok(()));
+
+        let forward_page_response = page(&client, "/some/index.html", &first_random_subdomain).await;
+        assert_eq!(forward_page_response.status_code(), StatusCode::OK);
+        assert_eq!(forward_page_response.as_bytes().len(), 2);
+
+        //* Deployments of other sites are not found
+        let second_random_subdomain = Uuid::new_v4().to_string();
+
+        let other_upload_response = upload(
+            &client,
+            &first_user_token,
+            &second_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(other_upload_response, Ok(()));
+
+        let foreign_rollback_response =
+            rollback(&client, &second_random_subdomain, &first_user_token, previous.id).await;
+        assert_eq!(
+            foreign_rollback_response
+                .expect_err("Deployment of another site was activated!")
+                .0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
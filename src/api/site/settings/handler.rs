@@ -0,0 +1,37 @@
+use super::response::SettingsResponse;
+use crate::extractors::*;
+use axum::Json;
+
+/// Retrieves settings of a specific site identified by the `x-subdomain` header.
+///
+/// Settings can be changed with the configure endpoint or
+/// with `sero.toml` file in the root of uploaded archive.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Get site settings",
+    get,
+    path = "/api/site/settings",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Settings were successfully retrieved",                                                 body = SettingsResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument]
+pub async fn implementation(SubdomainOwned { user, subdomain }: SubdomainOwned) -> Json<SettingsResponse> {
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Retrieving site settings...");
+
+    Json(SettingsResponse::from(subdomain))
+}
@@ -0,0 +1,4 @@
+pub mod handler;
+pub mod response;
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,32 @@
+use crate::site::models::TrailingSlash;
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({"spa": true, "trailing_slash": "always", "private": false}))]
+pub struct SettingsResponse {
+    /// Unknown extension-less paths are served with index.html
+    /// This is useful for applications with client-side routing
+    pub spa: bool,
+    /// Canonical form of pages resolved as `path.html` or `path/index.html`
+    pub trailing_slash: TrailingSlash,
+    /// Pages are served only to visitors with a share link
+    pub private: bool,
+    /// Requests per minute to pages from a single visitor
+    /// None means that the server wide limit is applied
+    pub rate_limit: Option<u32>,
+    pub rate_burst: Option<u32>,
+}
+
+impl From<SubdomainModel> for SettingsResponse {
+    fn from(value: SubdomainModel) -> Self {
+        Self {
+            spa: value.spa,
+            trailing_slash: value.trailing_slash.parse().unwrap_or_default(),
+            private: value.private,
+            rate_limit: value.rate_limit.and_then(|rate_limit| u32::try_from(rate_limit).ok()),
+            rate_burst: value.rate_burst.and_then(|rate_burst| u32::try_from(rate_burst).ok()),
+        }
+    }
+}
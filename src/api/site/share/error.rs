@@ -0,0 +1,38 @@
+use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+use validator::ValidationErrors;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ShareError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+    #[error("Share link expiration does not match validation rules!")]
+    ValidationError(#[from] ValidationErrors),
+}
+
+impl From<ShareError> for StatusCode {
+    fn from(value: ShareError) -> Self {
+        match value {
+            ShareError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
+            ShareError::SiteServiceError(error) => Self::from(error),
+            ShareError::ValidationError(_) => Self::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for ShareError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
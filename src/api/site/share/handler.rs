@@ -0,0 +1,71 @@
+use super::{error::ShareError, request::ShareRequest, response::ShareResponse};
+use crate::{
+    extractors::*, http::share::SHARE_PARAMETER, services::site::service::Service as SiteService,
+    site::parameters::ShareParameters, state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use chrono::{Duration, SubsecRound, Utc};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+use validator::Validate;
+
+/// Mints a share link of a specific site identified by the `x-subdomain` header.
+///
+/// Private site is served only to visitors who opened such a link.
+/// The link keeps working until it expires or all links are revoked.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Share site",
+    post,
+    path = "/api/site/share",
+    request_body = ShareRequest,
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 200, description = "Share link was successfully minted",                                                  body = ShareResponse),
+        (status = 400, description = "The 'x-subdomain' header is missing or expiration is out of range.",                  body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+    Json(payload): Json<ShareRequest>,
+) -> Result<impl IntoResponse, ShareError> {
+    payload.validate()?;
+
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Minting share link...");
+    let transaction = state.connection().begin().await?;
+
+    //? Token carries expiration with seconds precision
+    let expires_at = Utc::now().trunc_subsecs(0) + Duration::try_seconds(payload.expires_in.into()).unwrap_or_default();
+
+    let parameters = ShareParameters {
+        subdomain_id: subdomain.id,
+        expires_at,
+    };
+    let token = SiteService::share(parameters, &transaction).await?;
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    %expires_at,
+                    "Share link was successfully minted. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(Json(ShareResponse {
+        path: format!("/?{SHARE_PARAMETER}={token}"),
+        token,
+        expires_at,
+    }))
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+#[schema(example = json!({"expires_in": 86400}))]
+pub struct ShareRequest {
+    /// Lifetime of the link in seconds.
+    /// It must be between one minute and one year.
+    #[validate(range(min = 60, max = 31_536_000))]
+    #[schema(minimum = 60, maximum = 31_536_000)]
+    pub expires_in: u32,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[schema(example = json!({
+    "token": "1729425600.4Fa0m3Qk9gOqsZ0RzGm6a2kQxX3vZyq4E6b0n8cYb1w",
+    "path": "/?sero-share=1729425600.4Fa0m3Qk9gOqsZ0RzGm6a2kQxX3vZyq4E6b0n8cYb1w",
+    "expires_at": "2024-10-20T12:00:00Z"
+}))]
+pub struct ShareResponse {
+    /// Signed token of the link
+    pub token: String,
+    /// Path with the token which can be opened on any host of the site
+    pub path: String,
+    pub expires_at: DateTime<Utc>,
+}
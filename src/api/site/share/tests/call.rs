@@ -0,0 +1,37 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            site::share::{request::ShareRequest, response::ShareResponse},
+            tests::post,
+        },
+        Details,
+    };
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn share<T, S>(
+        client: &TestClient,
+        token: T,
+        subdomain: S,
+        request: &ShareRequest,
+    ) -> Result<ShareResponse, (StatusCode, Details)>
+    where
+        T: Display,
+        S: AsRef<str>,
+    {
+        let response = post(client, "/api/site/share", Some(request))
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(subdomain.as_ref()).expect("Failed to convert subdomain name to header value!"),
+            )
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
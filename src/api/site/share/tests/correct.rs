@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                configure::{request::ConfigureRequest, tests::call::tests::configure},
+                page::tests::call::tests::{host, page},
+                share::{request::ShareRequest, tests::call::tests::share},
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+        },
+        app,
+        http::share,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Link is minted
+        let share_response = share(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &ShareRequest { expires_in: 3600 },
+        )
+        .await
+        .expect("Failed to mint share link!");
+        assert_eq!(share_response.path, format!("/?sero-share={}", share_response.token));
+        assert!(share_response.expires_at > Utc::now() + Duration::try_minutes(59).expect("never fails"));
+        assert!(share_response.expires_at <= Utc::now() + Duration::try_hours(1).expect("never fails"));
+
+        //* Public site ignores links
+        let page_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+
+        let private_request = ConfigureRequest {
+            private: Some(true),
+            ..Default::default()
+        };
+        let configure_response = configure(&client, &first_random_subdomain, &first_user_token, &private_request).await;
+        assert!(configure_response.is_ok_and(|settings| settings.private));
+
+        //* Private site is not served without link
+        let page_response = page(&client, "/index.html", &first_random_subdomain).await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+
+        //* Tampered link
+        let page_response = page(
+            &client,
+            format!("/index.html?sero-share={}a", share_response.token),
+            &first_random_subdomain,
+        )
+        .await;
+        assert_eq!(page_response.status_code(), StatusCode::FORBIDDEN);
+
+        //* Link sets cookie and strips token from address
+        let page_response = page(
+            &client,
+            format!("/index.html?page=2&sero-share={}", share_response.token),
+            &first_random_subdomain,
+        )
+        .await;
+        assert_eq!(page_response.status_code(), StatusCode::FOUND);
+        assert_eq!(
+            page_response.headers().get(header::LOCATION),
+            Some(&HeaderValue::from_static("/index.html?page=2"))
+        );
+
+        let cookie = page_response
+            .headers()
+            .get(header::SET_COOKIE)
+            .and_then(|cookie| cookie.to_str().ok())
+            .expect("Cookie was not set!")
+            .to_owned();
+        assert!(cookie.starts_with(&format!("sero-share={};", share_response.token)));
+        assert!(!cookie.contains("Domain"));
+
+        //* Cookie keeps visitor browsing
+        let page_response = get(&client, "/some/index.html")
+            .add_header(header::HOST, host(&first_random_subdomain))
+            .add_header(
+                header::COOKIE,
+                HeaderValue::from_str(&format!("theme=dark; sero-share={}", share_response.token))
+                    .expect("Failed to convert cookie to header value!"),
+            )
+            .await;
+        assert_eq!(page_response.status_code(), StatusCode::OK);
+    }
+
+    #[test]
+    fn location() {
+        assert_eq!(share::location("/index.html", Some("page=2")), "/index.html?page=2");
+        assert_eq!(share::location("/", None), "/");
+
+        //* Visitor is never redirected to another host
+        assert_eq!(share::location("//evil.com", None), "/evil.com");
+        assert_eq!(share::location("///evil.com/path", Some("a=b")), "/evil.com/path?a=b");
+        assert_eq!(share::location("/\\evil.com", None), "/evil.com");
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let share_response = share(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &ShareRequest { expires_in: 0 },
+        )
+        .await;
+        assert_eq!(
+            share_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        let share_response = share(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            &ShareRequest { expires_in: u32::MAX },
+        )
+        .await;
+        assert_eq!(
+            share_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{services::site::error::ServiceError as SiteServiceError, Details};
+use crate::{
+    services::{domain::error::ServiceError as DomainServiceError, site::error::ServiceError as SiteServiceError},
+    Details,
+};
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -12,6 +15,8 @@ pub enum TeardownError {
     DbError(#[from] DbErr),
     #[error(transparent)]
     SiteServiceError(#[from] SiteServiceError),
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
 }
 
 impl From<TeardownError> for StatusCode {
@@ -19,6 +24,7 @@ impl From<TeardownError> for StatusCode {
         match value {
             TeardownError::DbError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             TeardownError::SiteServiceError(error) => Self::from(error),
+            TeardownError::DomainServiceError(error) => Self::from(error),
         }
     }
 }
@@ -1,6 +1,8 @@
 use super::error::TeardownError;
 use crate::{
-    extractors::*, services::site::service::Service as SiteService, site::parameters::ActionParameters,
+    extractors::*,
+    services::{domain::service::Service as DomainService, site::service::Service as SiteService},
+    site::parameters::ActionParameters,
     state::State as AppState,
 };
 use axum::{extract::State, http::StatusCode, response::IntoResponse};
@@ -50,7 +52,9 @@ pub async fn implementation(
         subdomain_id: subdomain.id,
     };
 
-    let amount_of_files_to_be_removed = SiteService::teardown(parameters, &transaction).await?;
+    //? Domains are removed together with the site
+    let domains = DomainService::retrieve_domains_for(subdomain.id, &transaction).await?;
+    let (amount_of_files_to_be_removed, archives) = SiteService::teardown(parameters, &transaction).await?;
     tracing::trace!(
         %subdomain.name, 
         %subdomain.id,
@@ -59,6 +63,21 @@ pub async fn implementation(
         "Site was successfully removed and inaccessible now. Old files wer marked as obsolete. Committing changes...");
 
     transaction.commit().await?;
+    state.limiter().forget_overrides();
+
+    //? Certificates of removed domains must not be presented anymore
+    for domain in domains.iter() {
+        state.certificates().remove(&domain.name);
+    }
+
+    //? Archives are removed only after the transaction
+    //? is committed so they are never lost on failure
+    for archive_path in archives.iter() {
+        tokio::fs::remove_file(archive_path)
+            .await
+            .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"))
+            .ok();
+    }
     tracing::trace!(
         %subdomain.name, 
         %subdomain.id,
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{teardown::tests::call::tests::teardown, upload::tests::call::tests::upload},
+        },
+        app,
+    };
+    use axum_test::TestServer as TestClient;
+    use entity::prelude::*;
+    use sea_orm::prelude::*;
+    use std::path::Path;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn archives() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+
+        let first_user_token = first_user_login_response.expect("never fails").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Every upload is retained as a separate deployment
+        for zip_path in ["./assets/zips/correct-1.zip", "./assets/zips/correct-2.zip"] {
+            let upload_response = upload(&client, &first_user_token, &first_random_subdomain, zip_path).await;
+            assert!(upload_response.is_ok());
+        }
+
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&first_random_subdomain))
+            .one(state.connection())
+            .await
+            .expect("Failed to find subdomain!")
+            .expect("Subdomain must exist!");
+
+        let archives = DeploymentEntity::find()
+            .filter(DeploymentColumn::SubdomainId.eq(subdomain.id))
+            .all(state.connection())
+            .await
+            .expect("Failed to find deployments!")
+            .into_iter()
+            .filter_map(|deployment| deployment.archive_path)
+            .collect::<Vec<_>>();
+        assert_eq!(archives.len(), 2);
+        assert!(archives.iter().all(|archive| Path::new(archive).exists()));
+
+        //* Archives of all deployments are removed with the site
+        let teardown_response = teardown(&client, &first_random_subdomain, &first_user_token).await;
+        assert_eq!(teardown_response, Ok(()));
+
+        assert!(archives.iter().all(|archive| !Path::new(archive).exists()));
+    }
+}
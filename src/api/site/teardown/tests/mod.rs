@@ -1,2 +1,3 @@
+pub mod archives;
 pub mod call;
 pub mod correct;
@@ -0,0 +1,56 @@
+use super::error::UnprotectError;
+use crate::{
+    extractors::*, services::site::service::Service as SiteService, site::parameters::ActionParameters,
+    state::State as AppState,
+};
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Removes HTTP Basic auth from a specific site identified by the `x-subdomain` header.
+///
+/// The site becomes public. Nothing happens if the site was not protected.
+#[utoipa::path(
+    tag = "Actions",
+    operation_id = "Unprotect site",
+    delete,
+    path = "/api/site/protection",
+    params(
+        ("x-subdomain" = String,
+        Header,
+        description = "x-subdomain header represents name of subdomain to call action on"),
+      ),
+    responses(
+        (status = 204, description = "Site is not protected anymore"),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 400, description = "The 'x-subdomain' header is missing or contains invalid characters.",                 body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    SubdomainOwned { user, subdomain }: SubdomainOwned,
+) -> Result<impl IntoResponse, UnprotectError> {
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    "Removing protection of site...");
+    let transaction = state.connection().begin().await?;
+
+    let parameters = ActionParameters {
+        subdomain_id: subdomain.id,
+    };
+    let rows_affected = SiteService::unprotect(parameters, &transaction).await?;
+    tracing::trace!(%subdomain.name,
+                    %subdomain.id,
+                    %user.id,
+                    %rows_affected,
+                    "Protection of site was successfully removed. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,5 +1,8 @@
 use crate::{
-    services::{archive::error::ServiceError as ArchiveServiceError, site::error::ServiceError as SiteServiceError},
+    services::{
+        archive::error::ServiceError as ArchiveServiceError, deployment::error::ServiceError as DeploymentServiceError,
+        site::error::ServiceError as SiteServiceError,
+    },
     Details,
 };
 use axum::{
@@ -17,6 +20,8 @@ pub enum UploadError {
     SiteServiceError(#[from] SiteServiceError),
     #[error(transparent)]
     ArchiveServiceError(#[from] ArchiveServiceError),
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
 }
 
 impl From<UploadError> for StatusCode {
@@ -25,6 +30,7 @@ impl From<UploadError> for StatusCode {
             UploadError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
             UploadError::SiteServiceError(error) => Self::from(error),
             UploadError::ArchiveServiceError(error) => Self::from(error),
+            UploadError::DeploymentServiceError(error) => Self::from(error),
         }
     }
 }
@@ -2,7 +2,10 @@ use super::error::UploadError;
 use crate::{
     archive::parameters::UploadParameters,
     extractors::*,
-    services::{archive::service::Service as ArchiveService, site::service::Service as SiteService},
+    services::{
+        archive::service::Service as ArchiveService, deployment::service::Service as DeploymentService,
+        site::service::Service as SiteService,
+    },
     site::parameters::AssociateParameters,
     state::State as AppState,
 };
@@ -23,7 +26,9 @@ pub struct UploadData {
 }
 
 /// Uploads site for a specified subdomain.
-/// Warning: Old files will be removed after successful upload.
+/// Every upload creates a new deployment which becomes active at once.
+/// Previous deployments are kept for rollback up to `MAX_DEPLOYMENTS`,
+/// files of older ones will be removed after successful upload.
 /// The cleanup task is configured with `CLEAN_OBSOLETE_INTERVAL` env
 /// If upload fails then old files will be preserved.
 /// If upload fails on th stage of extracting zips then
@@ -82,6 +87,8 @@ pub async fn implementation(
         subdomain_id: subdomain.id,
         contents: archive.contents,
         upload_folder: state.configuration().upload_folder(),
+        expires_at: None,
+        precompress_limit: state.configuration().max_precompress_size(),
     };
 
     tracing::trace!(%subdomain.id, 
@@ -89,14 +96,31 @@ pub async fn implementation(
         %user.id,
         "Starting upload process!");
 
-    ArchiveService::upload(upload_parameters, &transaction).await?;
+    let deployment = ArchiveService::upload(upload_parameters, &transaction).await?;
 
     tracing::trace!(%subdomain.id, 
         %subdomain.name,        
         %user.id,
+        %deployment.id,
         "Site was successfully uploaded!");
 
+    let pruned = DeploymentService::prune(subdomain.id, state.configuration().max_deployments(), &transaction).await?;
+
+    tracing::trace!(%subdomain.id,
+        %subdomain.name,
+        amount = pruned.len(),
+        "Deployments which do not fit into retention limit were pruned!");
+
     transaction.commit().await?;
 
+    //? Archives are removed only after the transaction
+    //? is committed so they are never lost on failure
+    for archive_path in pruned.iter().filter_map(|deployment| deployment.archive_path.as_ref()) {
+        tokio::fs::remove_file(archive_path)
+            .await
+            .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"))
+            .ok();
+    }
+
     Ok(())
 }
@@ -1,4 +1,8 @@
 pub mod big;
 pub mod call;
 pub mod empty;
+pub mod framing_headers;
 pub mod guard;
+pub mod invalid_configuration;
+pub mod invalid_headers;
+pub mod invalid_redirects;
@@ -0,0 +1,38 @@
+use crate::{services::token::error::ServiceError as TokenServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+use validator::ValidationErrors;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CreateTokenError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error(transparent)]
+    TokenServiceError(#[from] TokenServiceError),
+    #[error("Token name, scopes or expiration do not match validation rules!")]
+    ValidationError(#[from] ValidationErrors),
+}
+
+impl From<CreateTokenError> for StatusCode {
+    fn from(value: CreateTokenError) -> Self {
+        match value {
+            CreateTokenError::DbError(_) => Self::INTERNAL_SERVER_ERROR,
+            CreateTokenError::TokenServiceError(error) => Self::from(error),
+            CreateTokenError::ValidationError(_) => Self::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for CreateTokenError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,78 @@
+use super::{error::CreateTokenError, request::CreateTokenRequest, response::CreateTokenResponse};
+use crate::{
+    api::token::TokenResponse, extractors::*, services::token::service::Service as TokenService,
+    state::State as AppState,
+};
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{Duration, Utc};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+use validator::Validate;
+
+/// Issues a named personal API token.
+///
+/// Token is used as a bearer token instead of JWT but only for endpoints
+/// covered by its scopes. It can be restricted to a single site of the user.
+/// Secret of the token is returned only once and only its hash is stored.
+/// Tokens can not be used to issue other tokens.
+#[utoipa::path(
+    post,
+    tag = "Token Management",
+    operation_id = "Create token",
+    path = "/api/token",
+    request_body = CreateTokenRequest,
+    responses(
+        (status = 201, description = "The token was successfully issued.",                                                  body = CreateTokenResponse),
+        (status = 400, description = "Name, scopes or expiration are invalid.",                                             body = Details),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",                          body = Details),
+        (status = 403, description = "Forbidden: The subdomain is owned by another user.",                                  body = Details),
+        (status = 404, description = "Not Found: The login or subdomain was not found. See details for more information.",  body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",                             body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AuthJWT(user): AuthJWT,
+    Json(payload): Json<CreateTokenRequest>,
+) -> Result<impl IntoResponse, CreateTokenError> {
+    payload.validate()?;
+
+    tracing::trace!(%payload.name, ?payload.scopes, %user.id, "Issuing personal API token...");
+    let transaction = state.connection().begin().await?;
+
+    let expires_at = payload
+        .expires_in
+        .map(|expires_in| Utc::now() + Duration::try_seconds(expires_in.into()).unwrap_or_default());
+
+    let subdomain = payload.subdomain.map(|subdomain| subdomain.to_ascii_lowercase());
+
+    let (issued, token) = TokenService::issue_for(
+        user.id,
+        &payload.name,
+        &payload.scopes,
+        subdomain.as_deref(),
+        expires_at,
+        &transaction,
+    )
+    .await?;
+    tracing::trace!(%issued.id, %user.id, "Token was successfully issued. Committing changes...");
+
+    transaction.commit().await?;
+
+    let id = issued.id;
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/token/{id}"))],
+        Json(CreateTokenResponse {
+            token,
+            details: TokenResponse::from((issued, subdomain)),
+        }),
+    ))
+}
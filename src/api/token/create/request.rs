@@ -0,0 +1,21 @@
+use crate::services::token::models::TokenScope;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+#[schema(example = json!({"name": "ci", "scopes": ["deploy"], "subdomain": "docs", "expires_in": 2_592_000}))]
+pub struct CreateTokenRequest {
+    /// Name helps to tell tokens apart
+    #[validate(length(min = 1, max = 64))]
+    pub name: String,
+    #[validate(length(min = 1))]
+    pub scopes: Vec<TokenScope>,
+    /// Restricts token to a single site of the user
+    pub subdomain: Option<String>,
+    /// Lifetime of the token in seconds.
+    /// Token never expires if omitted
+    #[validate(range(min = 60))]
+    #[schema(minimum = 60)]
+    pub expires_in: Option<u32>,
+}
@@ -0,0 +1,14 @@
+use crate::api::token::TokenResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({
+    "token": "sero_0f4c3e0d4f7a4a0b9f1e2d3c4b5a69780f4c3e0d4f7a4a0b9f1e2d3c4b5a6978",
+    "details": {"id": 42, "name": "ci", "scopes": ["deploy"], "subdomain": "docs", "expires_at": null, "last_used_at": null, "created_at": "2024-10-25T12:00:00+00:00"}
+}))]
+pub struct CreateTokenResponse {
+    /// Secret of the token. It is shown only once
+    pub token: String,
+    pub details: TokenResponse,
+}
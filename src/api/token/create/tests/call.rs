@@ -0,0 +1,31 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            tests::post,
+            token::create::{request::CreateTokenRequest, response::CreateTokenResponse},
+        },
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn create<T>(
+        client: &TestClient,
+        token: T,
+        request: &CreateTokenRequest,
+    ) -> Result<CreateTokenResponse, (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = post(client, "/api/token", Some(request))
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
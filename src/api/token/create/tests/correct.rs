@@ -0,0 +1,152 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{settings::tests::call::tests::settings, upload::tests::call::tests::upload},
+            token::create::{request::CreateTokenRequest, tests::call::tests::create},
+        },
+        app,
+        services::token::models::TokenScope,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* Deploy token restricted to the site
+        let deploy_request = CreateTokenRequest {
+            name: "ci".to_owned(),
+            scopes: vec![TokenScope::Deploy],
+            subdomain: Some(first_random_subdomain.clone()),
+            expires_in: Some(3600),
+        };
+        let create_response = create(&client, &first_user_token, &deploy_request)
+            .await
+            .expect("Failed to create token!");
+        assert!(create_response.token.starts_with("sero_"));
+        assert_eq!(create_response.details.scopes, vec![TokenScope::Deploy]);
+        assert_eq!(
+            create_response.details.subdomain.as_ref(),
+            Some(&first_random_subdomain)
+        );
+        assert!(create_response.details.expires_at.is_some());
+        let deploy_token = create_response.token;
+
+        //* Token is accepted instead of JWT
+        let upload_response = upload(
+            &client,
+            &deploy_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(upload_response, Ok(()));
+
+        //* But only for the granted site
+        let second_random_subdomain = Uuid::new_v4().to_string();
+        let upload_response = upload(
+            &client,
+            &deploy_token,
+            &second_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(upload_response, Err(StatusCode::FORBIDDEN));
+
+        //* And only for granted scopes
+        let settings_response = settings(&client, &first_random_subdomain, &deploy_token).await;
+        assert_eq!(
+            settings_response.map_err(|(status, _)| status),
+            Err(StatusCode::FORBIDDEN)
+        );
+
+        //* Tokens can not issue other tokens
+        let create_response = create(&client, &deploy_token, &deploy_request).await;
+        assert_eq!(
+            create_response.map_err(|(status, _)| status),
+            Err(StatusCode::FORBIDDEN)
+        );
+
+        //* Read token works for any site of the user
+        let read_request = CreateTokenRequest {
+            name: "dashboard".to_owned(),
+            scopes: vec![TokenScope::Read],
+            subdomain: None,
+            expires_in: None,
+        };
+        let read_token = create(&client, &first_user_token, &read_request)
+            .await
+            .expect("Failed to create token!")
+            .token;
+        let settings_response = settings(&client, &first_random_subdomain, &read_token).await;
+        assert!(settings_response.is_ok());
+
+        //* Empty scopes
+        let invalid_request = CreateTokenRequest {
+            name: "empty".to_owned(),
+            scopes: vec![],
+            subdomain: None,
+            expires_in: None,
+        };
+        let create_response = create(&client, &first_user_token, &invalid_request).await;
+        assert_eq!(
+            create_response.map_err(|(status, _)| status),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        //* Unknown site
+        let unknown_request = CreateTokenRequest {
+            name: "unknown".to_owned(),
+            scopes: vec![TokenScope::Deploy],
+            subdomain: Some(second_random_subdomain),
+            expires_in: None,
+        };
+        let create_response = create(&client, &first_user_token, &unknown_request).await;
+        assert_eq!(
+            create_response.map_err(|(status, _)| status),
+            Err(StatusCode::NOT_FOUND)
+        );
+    }
+}
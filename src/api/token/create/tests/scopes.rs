@@ -0,0 +1,221 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{
+                deployments::tests::call::tests::deployments, list::tests::call::tests::list,
+                preview::tests::call::tests::preview, promote::tests::call::tests::promote,
+                rollback::tests::call::tests::rollback, teardown::tests::call::tests::teardown,
+                upload::tests::call::tests::upload,
+            },
+            tests::get,
+            token::create::{request::CreateTokenRequest, tests::call::tests::create},
+        },
+        app,
+        services::token::models::TokenScope,
+    };
+    use axum::http::{HeaderName, HeaderValue, Method, StatusCode};
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[test]
+    fn permits() {
+        //* Deploy scope covers the whole deployment workflow
+        for (method, path) in [
+            (Method::POST, "/api/site"),
+            (Method::POST, "/api/site/previews"),
+            (Method::PATCH, "/api/site/previews/3/promote"),
+            (Method::DELETE, "/api/site/previews/3"),
+            (Method::PATCH, "/api/site/deployments/3/rollback"),
+            (Method::PATCH, "/api/site/enable"),
+            (Method::PATCH, "/api/site/disable/"),
+        ] {
+            assert!(TokenScope::Deploy.permits(&method, path), "{method} {path}");
+        }
+
+        //* But nothing else
+        for (method, path) in [
+            (Method::DELETE, "/api/site"),
+            (Method::GET, "/api/site/previews"),
+            (Method::PATCH, "/api/site/previews//promote"),
+            (Method::PATCH, "/api/site/previews/3/promote/again"),
+            (Method::PATCH, "/api/site/deployments/3"),
+            (Method::POST, "/api/site/deployments/3/rollback"),
+            (Method::PATCH, "/api/site/settings"),
+            (Method::POST, "/api/token"),
+        ] {
+            assert!(!TokenScope::Deploy.permits(&method, path), "{method} {path}");
+        }
+
+        //* Endpoints which are not bound to a single site
+        assert!(TokenScope::is_owner_wide("/api/site/list"));
+        assert!(TokenScope::is_owner_wide("/api/token/3"));
+        assert!(TokenScope::is_owner_wide("/api/admin/users"));
+        assert!(!TokenScope::is_owner_wide("/api/site/listing"));
+        assert!(!TokenScope::is_owner_wide("/api/site/deployments"));
+    }
+
+    #[tokio::test]
+    async fn deploy() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(upload_response, Ok(()));
+
+        let deploy_request = CreateTokenRequest {
+            name: "ci".to_owned(),
+            scopes: vec![TokenScope::Deploy],
+            subdomain: Some(first_random_subdomain.clone()),
+            expires_in: None,
+        };
+        let deploy_token = create(&client, &first_user_token, &deploy_request)
+            .await
+            .expect("Failed to create token!")
+            .token;
+
+        //* Preview is uploaded and promoted with deploy token
+        let preview_response = preview(
+            &client,
+            &deploy_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-2.zip",
+        )
+        .await
+        .expect("Failed to upload preview!");
+
+        let promote_response = promote(&client, &first_random_subdomain, &deploy_token, preview_response.id).await;
+        assert_eq!(promote_response.map_err(|(status, _)| status), Ok(()));
+
+        //* And rolled back with it as well
+        let deployments_response = deployments(&client, &first_random_subdomain, &first_user_token)
+            .await
+            .expect("Failed to list deployments!");
+        let previous = deployments_response
+            .deployments
+            .iter()
+            .find(|deployment| !deployment.active)
+            .expect("Previous deployment must be retained!");
+
+        let rollback_response = rollback(&client, &first_random_subdomain, &deploy_token, previous.id).await;
+        assert_eq!(rollback_response.map_err(|(status, _)| status), Ok(()));
+
+        //* Site still can not be removed with deploy token
+        let teardown_response = teardown(&client, &first_random_subdomain, &deploy_token).await;
+        assert_eq!(
+            teardown_response.map_err(|(status, _)| status),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+
+    #[tokio::test]
+    async fn owner_wide() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        let upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(upload_response, Ok(()));
+
+        let read_request = CreateTokenRequest {
+            name: "dashboard".to_owned(),
+            scopes: vec![TokenScope::Read],
+            subdomain: Some(first_random_subdomain.clone()),
+            expires_in: None,
+        };
+        let read_token = create(&client, &first_user_token, &read_request)
+            .await
+            .expect("Failed to create token!")
+            .token;
+
+        //* Granted site is readable
+        let deployments_response = deployments(&client, &first_random_subdomain, &read_token).await;
+        assert!(deployments_response.is_ok());
+
+        //* But header of the granted site does not reveal other sites
+        let list_response = get(&client, "/api/site/list")
+            .add_header(
+                HeaderName::from_static("x-subdomain"),
+                HeaderValue::from_str(&first_random_subdomain).expect("Failed to convert subdomain to header value!"),
+            )
+            .authorization_bearer(&read_token)
+            .await;
+        assert_eq!(list_response.status_code(), StatusCode::FORBIDDEN);
+
+        //* Unrestricted token still lists sites
+        let unrestricted_request = CreateTokenRequest {
+            subdomain: None,
+            ..read_request
+        };
+        let unrestricted_token = create(&client, &first_user_token, &unrestricted_request)
+            .await
+            .expect("Failed to create token!")
+            .token;
+
+        let list_response = list(&client, &unrestricted_token, 1, 20).await;
+        assert!(list_response.is_ok());
+    }
+}
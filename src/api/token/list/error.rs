@@ -0,0 +1,30 @@
+use crate::{services::token::error::ServiceError as TokenServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListTokensError {
+    #[error(transparent)]
+    TokenServiceError(#[from] TokenServiceError),
+}
+
+impl From<ListTokensError> for StatusCode {
+    fn from(value: ListTokensError) -> Self {
+        match value {
+            ListTokensError::TokenServiceError(error) => Self::from(error),
+        }
+    }
+}
+
+impl IntoResponse for ListTokensError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
@@ -0,0 +1,42 @@
+use super::{error::ListTokensError, response::ListTokensResponse};
+use crate::{
+    api::token::TokenResponse, extractors::*, services::token::service::Service as TokenService,
+    state::State as AppState,
+};
+use axum::{extract::State, response::IntoResponse, Json};
+use std::sync::Arc;
+
+/// List all personal API tokens of the user.
+///
+/// Secrets of tokens are never returned again.
+/// Time of the last use helps to find tokens which are not needed anymore.
+#[utoipa::path(
+    get,
+    tag = "Token Management",
+    operation_id = "Get all tokens",
+    path = "/api/token",
+    responses(
+        (status = 200, description = "Tokens were successfully retrieved.",                          body = ListTokensResponse),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",   body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",      body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AuthJWT(user): AuthJWT,
+) -> Result<impl IntoResponse, ListTokensError> {
+    tracing::trace!(%user.id, "Retrieving tokens list...");
+
+    let tokens = TokenService::retrieve_tokens_for(user.id, state.connection()).await?;
+
+    tracing::trace!(%user.id, amount = tokens.len(), "Tokens list was successfully retrieved!");
+
+    Ok(Json(ListTokensResponse {
+        tokens: tokens
+            .into_iter()
+            .map(|(token, subdomain)| TokenResponse::from((token, subdomain.map(|subdomain| subdomain.name))))
+            .collect(),
+    }))
+}
@@ -0,0 +1,10 @@
+use crate::api::token::TokenResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({"tokens": [{"id": 42, "name": "ci", "scopes": ["deploy"], "subdomain": "docs", "expires_at": null, "last_used_at": "2024-10-25T12:00:00+00:00", "created_at": "2024-10-25T12:00:00+00:00"}]}))]
+pub struct ListTokensResponse {
+    /// Tokens of the user in order of creation
+    pub tokens: Vec<TokenResponse>,
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{tests::get, token::list::response::ListTokensResponse},
+        Details,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn list<T>(client: &TestClient, token: T) -> Result<ListTokensResponse, (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = get(client, "/api/token").authorization_bearer(token).await;
+
+        match response.status_code().is_success() {
+            true => Ok(response.json()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
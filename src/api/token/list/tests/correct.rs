@@ -0,0 +1,110 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{settings::tests::call::tests::settings, upload::tests::call::tests::upload},
+            token::{
+                create::{request::CreateTokenRequest, tests::call::tests::create},
+                list::tests::call::tests::list,
+            },
+        },
+        app,
+        services::token::models::TokenScope,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        //* No tokens yet
+        let list_response = list(&client, &first_user_token).await.expect("Failed to list tokens!");
+        assert!(list_response.tokens.is_empty());
+
+        let deploy_request = CreateTokenRequest {
+            name: "ci".to_owned(),
+            scopes: vec![TokenScope::Deploy, TokenScope::Deploy],
+            subdomain: Some(first_random_subdomain.clone()),
+            expires_in: None,
+        };
+        let deploy_response = create(&client, &first_user_token, &deploy_request)
+            .await
+            .expect("Failed to create token!");
+
+        let read_request = CreateTokenRequest {
+            name: "dashboard".to_owned(),
+            scopes: vec![TokenScope::Read, TokenScope::ManageOrigins],
+            subdomain: None,
+            expires_in: None,
+        };
+        let read_response = create(&client, &first_user_token, &read_request)
+            .await
+            .expect("Failed to create token!");
+
+        //* Read token is used once
+        let settings_response = settings(&client, &first_random_subdomain, &read_response.token).await;
+        assert!(settings_response.is_ok());
+
+        //* Tokens are listed in order of creation without secrets
+        let list_response = list(&client, &first_user_token).await.expect("Failed to list tokens!");
+        assert_eq!(list_response.tokens.len(), 2);
+        assert_eq!(list_response.tokens[0].id, deploy_response.details.id);
+        assert_eq!(list_response.tokens[0].scopes, vec![TokenScope::Deploy]);
+        assert_eq!(
+            list_response.tokens[0].subdomain.as_ref(),
+            Some(&first_random_subdomain)
+        );
+        assert!(list_response.tokens[0].last_used_at.is_none());
+        assert_eq!(list_response.tokens[1].name, "dashboard");
+        assert_eq!(
+            list_response.tokens[1].scopes,
+            vec![TokenScope::ManageOrigins, TokenScope::Read]
+        );
+        assert!(list_response.tokens[1].last_used_at.is_some());
+
+        //* Tokens can not list tokens even with read scope
+        let list_response = list(&client, &read_response.token).await;
+        assert_eq!(list_response.map_err(|(status, _)| status), Err(StatusCode::FORBIDDEN));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    services::token::{models::TokenScope, service::Service as TokenService},
+    state::State as AppState,
+};
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+use chrono::{DateTime, FixedOffset};
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub mod create;
+pub mod list;
+pub mod revoke;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(create::handler::implementation))
+        .route("/", get(list::handler::implementation))
+        .route("/:id", delete(revoke::handler::implementation))
+}
+
+/// Personal API token without its secret
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct TokenResponse {
+    pub id: i64,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    /// Name of the only site token can be used for
+    pub subdomain: Option<String>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<(ApiTokenModel, Option<String>)> for TokenResponse {
+    fn from((token, subdomain): (ApiTokenModel, Option<String>)) -> Self {
+        Self {
+            scopes: TokenService::scopes_of(&token),
+            id: token.id,
+            name: token.name,
+            subdomain,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            created_at: token.created_at,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{services::token::error::ServiceError as TokenServiceError, Details};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RevokeTokenError {
+    #[error(transparent)]
+    TokenServiceError(#[from] TokenServiceError),
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+}
+
+impl From<RevokeTokenError> for StatusCode {
+    fn from(value: RevokeTokenError) -> Self {
+        match value {
+            RevokeTokenError::TokenServiceError(error) => Self::from(error),
+            RevokeTokenError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for RevokeTokenError {
+    fn into_response(self) -> Response {
+        let reason = self.to_string();
+        let status_code: StatusCode = self.into();
+
+        tracing::error!(%reason, %status_code, "Error occurred while trying to handle request!");
+        (status_code, Json(Details { reason })).into_response()
+    }
+}
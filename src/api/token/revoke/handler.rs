@@ -0,0 +1,46 @@
+use super::error::RevokeTokenError;
+use crate::{extractors::*, services::token::service::Service as TokenService, state::State as AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sea_orm::TransactionTrait;
+use std::sync::Arc;
+
+/// Revoke personal API token by id.
+///
+/// Token is deleted and every request with it is rejected right away.
+#[utoipa::path(
+    delete,
+    tag = "Token Management",
+    operation_id = "Revoke token by id",
+    path = "/api/token/{id}",
+    params(
+        ("id" = i64, Path, description = "Id of the token to revoke"),
+    ),
+    responses(
+        (status = 204, description = "Token was successfully revoked."),
+        (status = 401, description = "Unauthorized: The JWT in the header is invalid or expired.",  body = Details),
+        (status = 403, description = "Forbidden: The token is owned by another user.",              body = Details),
+        (status = 404, description = "Not Found: The token was not found.",                         body = Details),
+        (status = 500, description = "Internal Server Error: An error occurred on the server.",     body = Details),
+    ),
+    security(("Bearer-JWT" = []))
+)]
+#[tracing::instrument(skip(state))]
+pub async fn implementation(
+    State(state): State<Arc<AppState>>,
+    AuthJWT(user): AuthJWT,
+    Path(token_id): Path<i64>,
+) -> Result<impl IntoResponse, RevokeTokenError> {
+    let transaction = state.connection().begin().await?;
+    tracing::trace!(%token_id, %user.id, "Revoking token...");
+
+    let rows_affected = TokenService::revoke_token_of(user.id, token_id, &transaction).await?;
+
+    tracing::trace!(%token_id, %rows_affected, %user.id, "Token was successfully revoked. Committing changes...");
+
+    transaction.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
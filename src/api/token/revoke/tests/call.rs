@@ -0,0 +1,21 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{api::tests::delete, Details};
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use std::fmt::Display;
+
+    pub async fn revoke<T>(client: &TestClient, token: T, id: i64) -> Result<(), (StatusCode, Details)>
+    where
+        T: Display,
+    {
+        let response = delete(client, &format!("/api/token/{id}"), Option::<()>::None)
+            .authorization_bearer(token)
+            .await;
+
+        match response.status_code().is_success() {
+            true => Ok(()),
+            false => Err((response.status_code(), response.json())),
+        }
+    }
+}
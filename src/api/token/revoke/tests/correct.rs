@@ -0,0 +1,91 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        api::{
+            auth::{
+                login::{request::LoginRequest, tests::call::test::login},
+                registration::{request::RegistrationRequest, tests::call::tests::registration},
+            },
+            site::{settings::tests::call::tests::settings, upload::tests::call::tests::upload},
+            token::{
+                create::{request::CreateTokenRequest, tests::call::tests::create},
+                revoke::tests::call::tests::revoke,
+            },
+        },
+        app,
+        services::token::models::TokenScope,
+    };
+    use axum::http::StatusCode;
+    use axum_test::TestServer as TestClient;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn correct() {
+        dotenvy::from_filename_override(".env.tests").ok();
+
+        let (app, _state) = app().await.expect("Failed to initialize application!");
+        let client = TestClient::new(app).expect("Failed to run server for testing");
+
+        let first_user_login = Uuid::new_v4();
+        let first_user_password = Uuid::new_v4();
+
+        //* Registration
+        let first_user_registration_request = RegistrationRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_registration_response = registration(&client, &first_user_registration_request).await;
+        assert!(first_user_registration_response.is_ok());
+
+        //* Login
+        let first_user_login_request = LoginRequest {
+            login: first_user_login.into(),
+            password: first_user_password.into(),
+        };
+        let first_user_login_response = login(&client, &first_user_login_request).await;
+        assert!(first_user_login_response.is_ok());
+        let first_user_token = first_user_login_response.expect("to never fail").token;
+
+        let first_random_subdomain = Uuid::new_v4().to_string();
+
+        //* Correct upload
+        let first_correct_upload_response = upload(
+            &client,
+            &first_user_token,
+            &first_random_subdomain,
+            "./assets/zips/correct-1.zip",
+        )
+        .await;
+        assert_eq!(first_correct_upload_response, Ok(()));
+
+        let read_request = CreateTokenRequest {
+            name: "dashboard".to_owned(),
+            scopes: vec![TokenScope::Read],
+            subdomain: None,
+            expires_in: None,
+        };
+        let create_response = create(&client, &first_user_token, &read_request)
+            .await
+            .expect("Failed to create token!");
+
+        let settings_response = settings(&client, &first_random_subdomain, &create_response.token).await;
+        assert!(settings_response.is_ok());
+
+        //* Token is rejected right after revocation
+        let revoke_response = revoke(&client, &first_user_token, create_response.details.id).await;
+        assert!(revoke_response.is_ok());
+
+        let settings_response = settings(&client, &first_random_subdomain, &create_response.token).await;
+        assert_eq!(
+            settings_response.map_err(|(status, _)| status),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+
+        //* Token was already revoked
+        let revoke_response = revoke(&client, &first_user_token, create_response.details.id).await;
+        assert_eq!(
+            revoke_response.map_err(|(status, _)| status),
+            Err(StatusCode::NOT_FOUND)
+        );
+    }
+}
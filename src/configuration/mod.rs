@@ -1,3 +1,4 @@
+use ipnet::IpNet;
 use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, path::PathBuf};
 pub mod env;
@@ -10,12 +11,38 @@ pub struct Configuration {
     max_sites_per_user: Option<u64>,
     max_users: Option<u64>,
     max_body_limit_size: Option<usize>,
+    max_precompress_size: Option<usize>,
     jwt_secret: String,
     port: u16,
     jwt_ttl_seconds: i64,
+    jwt_signing_key: Option<PathBuf>,
+    jwt_verification_keys: Option<Vec<PathBuf>>,
+    refresh_ttl_seconds: Option<i64>,
     sqlx_logging: bool,
     upload_folder: PathBuf,
     clean_obsolete_interval: Option<u64>,
+    max_deployments: Option<u64>,
+    preview_ttl_seconds: Option<i64>,
+    base_domains: Option<Vec<String>>,
+    trusted_proxies: Option<Vec<IpNet>>,
+    domain_claim_ttl_seconds: Option<i64>,
+    tls_port: Option<u16>,
+    tls_certificate: Option<PathBuf>,
+    tls_private_key: Option<PathBuf>,
+    acme_directory: Option<String>,
+    acme_contact: Option<String>,
+    acme_check_interval: Option<u64>,
+    auth_rate_limit: Option<u32>,
+    auth_rate_burst: Option<u32>,
+    api_rate_limit: Option<u32>,
+    api_rate_burst: Option<u32>,
+    page_rate_limit: Option<u32>,
+    page_rate_burst: Option<u32>,
+    login_attempts_per_login: Option<u32>,
+    login_attempts_per_address: Option<u32>,
+    login_lockout_seconds: Option<i64>,
+    admin_login: Option<String>,
+    admin_password: Option<String>,
 }
 
 impl Debug for Configuration {
@@ -25,12 +52,38 @@ impl Debug for Configuration {
             .field("max_sites_per_user", &self.max_sites_per_user)
             .field("max_users", &self.max_users)
             .field("max_body_limit_size", &self.max_body_limit_size)
+            .field("max_precompress_size", &self.max_precompress_size)
             .field("jwt_secret", &"***")
             .field("port", &self.port)
             .field("jwt_ttl_seconds", &self.jwt_ttl_seconds)
+            .field("jwt_signing_key", &self.jwt_signing_key)
+            .field("jwt_verification_keys", &self.jwt_verification_keys)
+            .field("refresh_ttl_seconds", &self.refresh_ttl_seconds)
             .field("sqlx_logging", &self.sqlx_logging)
             .field("upload_folder", &self.upload_folder)
             .field("clean_obsolete_interval", &self.clean_obsolete_interval)
+            .field("max_deployments", &self.max_deployments)
+            .field("preview_ttl_seconds", &self.preview_ttl_seconds)
+            .field("base_domains", &self.base_domains)
+            .field("trusted_proxies", &self.trusted_proxies)
+            .field("domain_claim_ttl_seconds", &self.domain_claim_ttl_seconds)
+            .field("tls_port", &self.tls_port)
+            .field("tls_certificate", &self.tls_certificate)
+            .field("tls_private_key", &self.tls_private_key)
+            .field("acme_directory", &self.acme_directory)
+            .field("acme_contact", &self.acme_contact)
+            .field("acme_check_interval", &self.acme_check_interval)
+            .field("auth_rate_limit", &self.auth_rate_limit)
+            .field("auth_rate_burst", &self.auth_rate_burst)
+            .field("api_rate_limit", &self.api_rate_limit)
+            .field("api_rate_burst", &self.api_rate_burst)
+            .field("page_rate_limit", &self.page_rate_limit)
+            .field("page_rate_burst", &self.page_rate_burst)
+            .field("login_attempts_per_login", &self.login_attempts_per_login)
+            .field("login_attempts_per_address", &self.login_attempts_per_address)
+            .field("login_lockout_seconds", &self.login_lockout_seconds)
+            .field("admin_login", &self.admin_login)
+            .field("admin_password", &self.admin_password.as_ref().map(|_| "***"))
             .finish()
     }
 }
@@ -52,6 +105,12 @@ impl Configuration {
         self.max_body_limit_size
     }
 
+    /// Total size of files in bytes compressed on a single upload (64 MiB by default).
+    /// Files beyond the limit are served without precompressed variants
+    pub fn max_precompress_size(&self) -> usize {
+        self.max_precompress_size.unwrap_or(64 * 1024 * 1024)
+    }
+
     pub fn jwt_secret(&self) -> &str {
         self.jwt_secret.as_ref()
     }
@@ -64,6 +123,22 @@ impl Configuration {
         self.jwt_ttl_seconds
     }
 
+    /// PEM private key (Ed25519 or RSA) tokens are signed with.
+    /// Tokens are signed with `JWT_SECRET` if it is not set
+    pub fn jwt_signing_key(&self) -> Option<&PathBuf> {
+        self.jwt_signing_key.as_ref()
+    }
+
+    /// PEM public keys of retired signing keys which are still accepted
+    pub fn jwt_verification_keys(&self) -> &[PathBuf] {
+        self.jwt_verification_keys.as_deref().unwrap_or_default()
+    }
+
+    /// Session expires if it was not refreshed for this amount of seconds (30 days by default)
+    pub fn refresh_ttl_seconds(&self) -> i64 {
+        self.refresh_ttl_seconds.unwrap_or(30 * 24 * 60 * 60)
+    }
+
     pub fn sqlx_logging(&self) -> bool {
         self.sqlx_logging
     }
@@ -75,4 +150,121 @@ impl Configuration {
     pub fn clean_obsolete_interval(&self) -> Option<u64> {
         self.clean_obsolete_interval
     }
+
+    /// Deployments of a site retained for rollback including the active one (10 by default)
+    pub fn max_deployments(&self) -> u64 {
+        self.max_deployments.unwrap_or(10).max(1)
+    }
+
+    /// Preview deployments are removed after this amount of seconds unless promoted (7 days by default)
+    pub fn preview_ttl_seconds(&self) -> i64 {
+        self.preview_ttl_seconds.unwrap_or(7 * 24 * 60 * 60).max(1)
+    }
+
+    /// Sites are resolved as `<subdomain>.<base domain>` from Host header
+    pub fn base_domains(&self) -> &[String] {
+        self.base_domains.as_deref().unwrap_or_default()
+    }
+
+    /// Peers which are allowed to set `x-subdomain` header
+    /// None means that header is honoured from any peer
+    /// only if base domains are not configured
+    pub fn trusted_proxies(&self) -> Option<&[IpNet]> {
+        self.trusted_proxies.as_deref()
+    }
+
+    /// Unverified custom domain can be claimed by another site after this amount of seconds (1 day by default)
+    pub fn domain_claim_ttl_seconds(&self) -> i64 {
+        self.domain_claim_ttl_seconds.unwrap_or(24 * 60 * 60).max(1)
+    }
+
+    /// Port of TLS listener. TLS is disabled if not set
+    pub fn tls_port(&self) -> Option<u16> {
+        self.tls_port
+    }
+
+    /// PEM chain of the default certificate served
+    /// when no certificate matches requested server name
+    pub fn tls_certificate(&self) -> Option<&PathBuf> {
+        self.tls_certificate.as_ref()
+    }
+
+    /// PEM private key of the default certificate
+    pub fn tls_private_key(&self) -> Option<&PathBuf> {
+        self.tls_private_key.as_ref()
+    }
+
+    /// Directory url of ACME server. Certificates are
+    /// issued automatically only if it is set
+    pub fn acme_directory(&self) -> Option<&str> {
+        self.acme_directory.as_deref()
+    }
+
+    /// Email which is used for ACME account
+    pub fn acme_contact(&self) -> Option<&str> {
+        self.acme_contact.as_deref()
+    }
+
+    /// Interval in seconds between checks of certificates expiry
+    pub fn acme_check_interval(&self) -> Option<u64> {
+        self.acme_check_interval
+    }
+
+    /// Requests per minute to `/api/auth` from a single client
+    pub fn auth_rate_limit(&self) -> Option<u32> {
+        self.auth_rate_limit
+    }
+
+    pub fn auth_rate_burst(&self) -> Option<u32> {
+        self.auth_rate_burst
+    }
+
+    /// Requests per minute to the rest of API from a single client
+    pub fn api_rate_limit(&self) -> Option<u32> {
+        self.api_rate_limit
+    }
+
+    pub fn api_rate_burst(&self) -> Option<u32> {
+        self.api_rate_burst
+    }
+
+    /// Requests per minute to pages of a site from a single client.
+    /// Can be overridden by site owner
+    pub fn page_rate_limit(&self) -> Option<u32> {
+        self.page_rate_limit
+    }
+
+    pub fn page_rate_burst(&self) -> Option<u32> {
+        self.page_rate_burst
+    }
+
+    /// Failed logins to a single account after which it is locked (5 by default).
+    /// Zero disables lockout
+    pub fn login_attempts_per_login(&self) -> u32 {
+        self.login_attempts_per_login.unwrap_or(5)
+    }
+
+    /// Failed logins from a single client after which it is locked (20 by default).
+    /// Forwarded requests are counted only if `TRUSTED_PROXIES` is set.
+    /// Failed credentials of protected sites are counted per site the same way.
+    /// Zero disables lockout
+    pub fn login_attempts_per_address(&self) -> u32 {
+        self.login_attempts_per_address.unwrap_or(20)
+    }
+
+    /// Duration of the first lockout in seconds (60 by default).
+    /// It is doubled with every next failure
+    pub fn login_lockout_seconds(&self) -> i64 {
+        self.login_lockout_seconds.unwrap_or(60)
+    }
+
+    /// User promoted to administrator on startup
+    pub fn admin_login(&self) -> Option<&str> {
+        self.admin_login.as_deref()
+    }
+
+    /// Administrator is registered with this password if it does not exist yet
+    pub fn admin_password(&self) -> Option<&str> {
+        self.admin_password.as_deref()
+    }
 }
@@ -1,12 +1,17 @@
 use crate::{
     auth::parameters::JwtDecodingParameters,
-    services::auth::{error::ServiceError as AuthServiceError, service::Service as AuthService},
+    http::host,
+    services::{
+        auth::{error::ServiceError as AuthServiceError, service::Service as AuthService},
+        session::{error::ServiceError as SessionServiceError, service::Service as SessionService},
+        token::{error::ServiceError as TokenServiceError, models::TokenScope, service::Service as TokenService},
+    },
     state::State,
     Details,
 };
 use axum::{
     async_trait,
-    extract::{FromRef, FromRequestParts},
+    extract::{FromRef, FromRequestParts, OriginalUri},
     http::{request::Parts, StatusCode},
     response::{IntoResponse, Response},
     Json,
@@ -29,9 +34,30 @@ pub enum AuthError {
     #[error(transparent)]
     AuthServiceError(#[from] AuthServiceError),
 
+    #[error(transparent)]
+    TokenServiceError(#[from] TokenServiceError),
+
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+
+    #[error("Token is not bound to a session.")]
+    SessionIsRequired,
+
+    #[error("Token does not grant access to this endpoint.")]
+    ScopeIsNotGranted,
+
+    #[error("Token is restricted to another subdomain.")]
+    SubdomainIsNotGranted,
+
     #[error("User was not found")]
     UserWasNotFound,
 
+    #[error("User is suspended.")]
+    UserIsSuspended,
+
+    #[error("Only administrators are allowed to do this.")]
+    AdminIsRequired,
+
     #[error(transparent)]
     DatabaseError(#[from] DbErr),
 }
@@ -49,8 +75,17 @@ impl From<AuthError> for StatusCode {
                 AuthServiceError::PasswordHashError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 AuthServiceError::LoginOccupied => StatusCode::CONFLICT,
                 AuthServiceError::UserWasNotFound => StatusCode::NOT_FOUND,
+                AuthServiceError::InvalidPassword => StatusCode::BAD_REQUEST,
+                AuthServiceError::LoginIsLocked(_) => StatusCode::TOO_MANY_REQUESTS,
+                AuthServiceError::KeyWasNotFound => StatusCode::UNAUTHORIZED,
+                AuthServiceError::UserIsSuspended => StatusCode::FORBIDDEN,
             },
+            AuthError::TokenServiceError(error) => StatusCode::from(error),
+            AuthError::SessionServiceError(error) => StatusCode::from(error),
+            AuthError::SessionIsRequired => StatusCode::UNAUTHORIZED,
+            AuthError::ScopeIsNotGranted | AuthError::SubdomainIsNotGranted => StatusCode::FORBIDDEN,
             AuthError::UserWasNotFound => StatusCode::UNAUTHORIZED,
+            AuthError::UserIsSuspended | AuthError::AdminIsRequired => StatusCode::FORBIDDEN,
             AuthError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -92,18 +127,136 @@ where
             _ => Err(AuthError::AuthorizationHeaderBadSchema),
         }?;
 
-        let parameters = JwtDecodingParameters {
-            token: token.as_ref(),
-            secret: app_state.configuration().jwt_secret(),
+        //? Personal API tokens are accepted alongside JWTs
+        //? but only for endpoints covered by their scopes
+        let user_id = match token.starts_with(TokenService::PREFIX) {
+            true => Self::personal(&token, parts, &app_state).await?,
+            false => {
+                let parameters = JwtDecodingParameters {
+                    token: token.as_ref(),
+                    secret: app_state.configuration().jwt_secret(),
+                    keys: app_state.keys(),
+                };
+
+                let claims = AuthService::decode_jwt(parameters)?;
+
+                //? JWTs of revoked sessions are rejected before they expire.
+                //? Tokens without session could not be revoked so they are rejected too
+                let session_id = claims.sid.ok_or(AuthError::SessionIsRequired)?;
+                let session = SessionService::fetch_active(claims.sub, session_id, app_state.connection()).await?;
+                parts.extensions.insert(session);
+
+                claims.sub
+            }
         };
 
-        let claims = AuthService::decode_jwt(parameters)?;
+        let user = match UserEntity::find_by_id(user_id).one(app_state.connection()).await? {
+            Some(user) => Ok(user),
+            None => Err(AuthError::UserWasNotFound),
+        }?;
+
+        //? Tokens issued before suspension are rejected too
+        if user.suspended_at.is_some() {
+            return Err(AuthError::UserIsSuspended);
+        }
+
+        Ok(Self(user))
+    }
+}
+
+impl AuthJWT {
+    /// Checks personal API token against the request and returns id of its owner
+    async fn personal(token: &str, parts: &Parts, state: &State) -> Result<i64, AuthError> {
+        let api_token = TokenService::authenticate(token, state.connection()).await?;
+
+        //? Extractor is called inside nested router
+        //? so the full path is kept only in original uri
+        let path = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map_or(parts.uri.path(), |OriginalUri(uri)| uri.path());
+
+        if !TokenService::scopes_of(&api_token)
+            .iter()
+            .any(|scope| scope.permits(&parts.method, path))
+        {
+            return Err(AuthError::ScopeIsNotGranted);
+        }
 
-        Ok(Self({
-            match UserEntity::find_by_id(claims.sub).one(app_state.connection()).await? {
-                Some(user) => Ok(user),
-                None => Err(AuthError::UserWasNotFound),
-            }?
-        }))
+        if let Some(subdomain_id) = api_token.subdomain_id {
+            //? Header of the granted site must not unlock endpoints of all sites
+            if TokenScope::is_owner_wide(path) {
+                return Err(AuthError::SubdomainIsNotGranted);
+            }
+
+            let name = parts
+                .headers
+                .get(host::X_SUBDOMAIN)
+                .and_then(|header| header.to_str().ok())
+                .map(str::to_ascii_lowercase)
+                .ok_or(AuthError::SubdomainIsNotGranted)?;
+
+            SubdomainEntity::find_by_id(subdomain_id)
+                .filter(SubdomainColumn::Name.eq(name))
+                .one(state.connection())
+                .await?
+                .ok_or(AuthError::SubdomainIsNotGranted)?;
+        }
+
+        tracing::trace!(%api_token.id, %api_token.owner_id, "Request is authenticated with personal API token");
+        Ok(api_token.owner_id)
+    }
+}
+
+/// User authenticated with JWT together with its session.
+/// Personal API tokens are not bound to sessions so they are rejected.
+pub struct SessionJWT {
+    pub user: UserModel,
+    pub session: SessionModel,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for SessionJWT
+where
+    Arc<State>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+
+    #[tracing::instrument(skip(parts, state))]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let user = AuthJWT::from_request_parts(parts, state).await?.0;
+
+        //? Session was checked and stored by AuthJWT
+        let session = parts
+            .extensions
+            .get::<SessionModel>()
+            .cloned()
+            .ok_or(AuthError::SessionIsRequired)?;
+
+        Ok(Self { user, session })
+    }
+}
+
+/// Administrator authenticated with JWT.
+/// Personal API tokens are rejected so they can not be used to manage the instance.
+pub struct AdminJWT(pub UserModel);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AdminJWT
+where
+    Arc<State>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+
+    #[tracing::instrument(skip(parts, state))]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let SessionJWT { user, .. } = SessionJWT::from_request_parts(parts, state).await?;
+
+        match user.is_admin {
+            true => Ok(Self(user)),
+            false => Err(AuthError::AdminIsRequired),
+        }
     }
 }
@@ -0,0 +1,41 @@
+use crate::{http::client, state::State};
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRef, FromRequestParts},
+    http::request::Parts,
+};
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+/// Real address of the client.
+/// `X-Forwarded-For` is honoured only from trusted proxies.
+/// It is unknown if server was started without connect info.
+pub struct ClientAddress(pub Option<IpAddr>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientAddress
+where
+    Arc<State>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    #[tracing::instrument(skip(parts, state))]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let app_state = Arc::from_ref(state);
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip());
+
+        Ok(Self(client::address(
+            &parts.headers,
+            peer,
+            app_state.configuration().trusted_proxies(),
+        )))
+    }
+}
@@ -69,7 +69,13 @@ where
         let user = AuthJWT::from_request_parts(parts, state).await?.0;
         let subdomain = SubdomainName::from_request_parts(parts, state).await?.0;
 
-        match app_state.configuration().max_sites_per_user() {
+        //? Limit of the user set by administrator overrides server wide one
+        let max_sites = user
+            .max_sites
+            .map(|max_sites| max_sites.max(0) as u64)
+            .or(app_state.configuration().max_sites_per_user());
+
+        match max_sites {
             Some(max_sites) => {
                 let users_sites_count = SubdomainEntity::find()
                     .filter(SubdomainColumn::OwnerId.eq(user.id))
@@ -1,11 +1,13 @@
 pub mod auth;
+pub mod client_address;
 pub mod guards;
 pub mod subdomain;
 pub mod subdomain_name;
 pub mod subdomain_owned;
 
 pub use self::{
-    auth::AuthJWT,
+    auth::{AdminJWT, AuthJWT, SessionJWT},
+    client_address::ClientAddress,
     guards::{registration::Guard as RegistrationGuard, upload::Guard as UploadGuard},
     subdomain::Subdomain,
     subdomain_name::SubdomainName,
@@ -1,37 +1,51 @@
-use crate::{state::State, Details};
+use crate::{
+    http::host,
+    services::{
+        deployment::{error::ServiceError as DeploymentServiceError, service::Service as DeploymentService},
+        domain::{error::ServiceError as DomainServiceError, service::Service as DomainService},
+    },
+    state::State,
+    Details,
+};
 use axum::{
     async_trait,
-    extract::{FromRef, FromRequestParts},
+    extract::{ConnectInfo, FromRef, FromRequestParts},
     http::{request::Parts, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use entity::prelude::*;
 use sea_orm::prelude::*;
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
+/// Site requested by the client.
+/// Resolved from `Host` header or `x-subdomain` set by trusted proxy.
+/// Hosts outside of base domains are looked up among verified custom domains.
+/// Name `<deployment id>--<subdomain>` resolves to the site as if the preview was promoted.
 pub struct Subdomain(pub SubdomainModel);
 
 #[derive(thiserror::Error, Debug)]
 pub enum SubdomainError {
-    #[error("x-subdomain header missing")]
-    XSubdomainHeaderMissing,
-    #[error("x-subdomain header contains bad characters")]
-    XSubdomainHeaderContainsBadChars,
-    #[error("Subdomain provided in x-subdomain header was not found")]
+    #[error("Subdomain could not be resolved from Host or x-subdomain header")]
+    SubdomainIsNotResolved,
+    #[error("Subdomain provided in Host or x-subdomain header was not found")]
     SubdomainWasNotFound,
     #[error(transparent)]
     DatabaseError(#[from] DbErr),
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
+    #[error(transparent)]
+    DeploymentServiceError(#[from] DeploymentServiceError),
 }
 
 impl From<SubdomainError> for StatusCode {
     fn from(value: SubdomainError) -> Self {
         match value {
-            SubdomainError::XSubdomainHeaderMissing | SubdomainError::XSubdomainHeaderContainsBadChars => {
-                StatusCode::BAD_REQUEST
-            }
+            SubdomainError::SubdomainIsNotResolved => StatusCode::BAD_REQUEST,
             SubdomainError::SubdomainWasNotFound => StatusCode::NOT_FOUND,
             SubdomainError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            SubdomainError::DomainServiceError(error) => StatusCode::from(error),
+            SubdomainError::DeploymentServiceError(error) => StatusCode::from(error),
         }
     }
 }
@@ -59,28 +73,51 @@ where
         //? Extracting app state to use database connection
         let app_state = Arc::from_ref(state);
 
-        //? Extracting x-subdomain header and converting to string
-        let header = parts
-            .headers
-            .get("x-subdomain")
-            .ok_or(SubdomainError::XSubdomainHeaderMissing)?
-            .to_str()
-            .map_err(|_| SubdomainError::XSubdomainHeaderContainsBadChars)?
-            .to_ascii_lowercase();
+        //? Peer address is known only if server was started with connect info
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip());
+
+        let name = host::subdomain(
+            &parts.headers,
+            &parts.uri,
+            peer,
+            app_state.configuration().base_domains(),
+            app_state.configuration().trusted_proxies(),
+        );
 
-        //? Check if header is empty
-        let header = match header.is_empty() {
-            true => Err(SubdomainError::XSubdomainHeaderMissing),
-            false => Ok(header),
-        }?;
+        let name = match name {
+            Some(name) => name,
+            None => {
+                let host = host::host(&parts.headers, &parts.uri).ok_or(SubdomainError::SubdomainIsNotResolved)?;
 
-        //? Lookup for
-        match SubdomainEntity::find()
-            .filter(SubdomainColumn::Name.eq(&header))
+                //? Unknown hosts are treated as unresolved rather than missing sites
+                return DomainService::subdomain_for(host, app_state.connection())
+                    .await?
+                    .map(Self)
+                    .ok_or(SubdomainError::SubdomainIsNotResolved);
+            }
+        };
+
+        //? Regular site is looked up by exact name first
+        if let Some(model) = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&name))
             .one(app_state.connection())
             .await?
         {
-            Some(model) => Ok(Self(model)),
+            return Ok(Self(model));
+        }
+
+        //? Names of previews can not be taken by new sites
+        //? so existing sites with such names still take precedence
+        match host::preview(&name) {
+            Some((deployment_id, subdomain_name)) => {
+                DeploymentService::preview(deployment_id, subdomain_name, app_state.connection())
+                    .await?
+                    .map(Self)
+                    .ok_or(SubdomainError::SubdomainWasNotFound)
+            }
             None => Err(SubdomainError::SubdomainWasNotFound),
         }
     }
@@ -1,4 +1,4 @@
-use super::{auth::AuthError, subdomain::SubdomainError, AuthJWT, Subdomain};
+use super::{auth::AuthError, subdomain_name::SubdomainNameError, AuthJWT, SubdomainName};
 use crate::{state::State, Details};
 use axum::{
     async_trait,
@@ -8,6 +8,7 @@ use axum::{
     Json,
 };
 use entity::prelude::*;
+use sea_orm::prelude::*;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -19,7 +20,11 @@ pub struct SubdomainOwned {
 #[derive(thiserror::Error, Debug)]
 pub enum SubdomainOwnedError {
     #[error(transparent)]
-    SubdomainError(#[from] SubdomainError),
+    SubdomainNameError(#[from] SubdomainNameError),
+    #[error("Subdomain provided in x-subdomain header was not found")]
+    SubdomainWasNotFound,
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
     #[error(transparent)]
     AuthError(#[from] AuthError),
     #[error("Subdomain provided in X-Subdomain header is owned by another user")]
@@ -29,7 +34,9 @@ pub enum SubdomainOwnedError {
 impl From<SubdomainOwnedError> for StatusCode {
     fn from(value: SubdomainOwnedError) -> Self {
         match value {
-            SubdomainOwnedError::SubdomainError(error) => StatusCode::from(error),
+            SubdomainOwnedError::SubdomainNameError(error) => StatusCode::from(error),
+            SubdomainOwnedError::SubdomainWasNotFound => StatusCode::NOT_FOUND,
+            SubdomainOwnedError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             SubdomainOwnedError::AuthError(error) => StatusCode::from(error),
             SubdomainOwnedError::SubdomainIsOwnedByAnotherUser => StatusCode::FORBIDDEN,
         }
@@ -56,7 +63,18 @@ where
 
     #[tracing::instrument(skip(parts, state))]
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        let subdomain = Subdomain::from_request_parts(parts, state).await?.0;
+        //? Management api is called directly by the owner
+        //? so the site is always named in x-subdomain header
+        let name = SubdomainName::from_request_parts(parts, state).await?.0;
+
+        let app_state = Arc::<State>::from_ref(state);
+
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(&name))
+            .one(app_state.connection())
+            .await?
+            .ok_or(SubdomainOwnedError::SubdomainWasNotFound)?;
+
         let user = AuthJWT::from_request_parts(parts, state).await?.0;
 
         match subdomain.owner_id == user.id {
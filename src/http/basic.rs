@@ -0,0 +1,48 @@
+use axum::http::{header, HeaderMap};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Cookie which keeps a pass issued after credentials were verified
+/// so they are not verified again on every request
+pub const PASS_COOKIE: &str = "sero-pass";
+
+/// Pass is valid for this amount of seconds
+pub const PASS_TTL_SECONDS: i64 = 15 * 60;
+
+/// Extracts username and password from `Authorization: Basic` header.
+/// Password may contain colons, username may not.
+pub fn credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, encoded) = value.trim().split_once(' ')?;
+
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+
+    Some((username.to_owned(), password.to_owned()))
+}
+
+/// Extracts pass from `Cookie` headers
+pub fn pass_from_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == PASS_COOKIE)
+        .map(|(_, value)| value.to_owned())
+}
+
+/// `Set-Cookie` value which keeps the pass until it expires
+pub fn pass_cookie<T>(pass: T) -> String
+where
+    T: AsRef<str>,
+{
+    format!(
+        "{PASS_COOKIE}={}; Max-Age={PASS_TTL_SECONDS}; Path=/; HttpOnly; SameSite=Lax",
+        pass.as_ref()
+    )
+}
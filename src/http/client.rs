@@ -0,0 +1,52 @@
+use axum::http::HeaderMap;
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+/// Header which is appended by every proxy on the way
+/// with address of the peer it has received request from
+pub const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Resolves real address of the client.
+///
+/// `X-Forwarded-For` is honoured only if peer is one of trusted proxies.
+/// Addresses are walked from the right so the first one
+/// which is not a trusted proxy is the client.
+/// Unlike `x-subdomain` the header is ignored without configured trusted proxies
+/// as otherwise any client could bypass access rules of sites.
+pub fn address(headers: &HeaderMap, peer: Option<IpAddr>, trusted_proxies: Option<&[IpNet]>) -> Option<IpAddr> {
+    let trusted_proxies = trusted_proxies.unwrap_or_default();
+    let is_trusted = |address: &IpAddr| trusted_proxies.iter().any(|proxy| proxy.contains(address));
+
+    //? IPv4 peers of dual stack listeners are seen as mapped IPv6
+    let mut client = peer?.to_canonical();
+
+    let forwarded = headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+
+    for address in forwarded.into_iter().rev() {
+        if !is_trusted(&client) {
+            break;
+        }
+
+        match address.parse::<IpAddr>() {
+            Ok(address) => client = address.to_canonical(),
+            //? Garbage can be prepended by client itself
+            Err(_) => break,
+        }
+    }
+
+    Some(client)
+}
+
+/// Checks if resolved address belongs to the client rather than to a proxy in front of it.
+///
+/// Without trusted proxies every forwarded request is seen with address of the proxy,
+/// so such addresses must not be used to tell clients apart.
+pub fn is_reliable(headers: &HeaderMap, trusted_proxies: Option<&[IpNet]>) -> bool {
+    trusted_proxies.is_some() || !headers.contains_key(X_FORWARDED_FOR)
+}
@@ -0,0 +1,62 @@
+use axum::http::{header, HeaderMap};
+use chrono::{DateTime, Utc};
+
+//* IMF-fixdate as described in RFC 7231
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+pub fn format_http_date(date: DateTime<Utc>) -> String {
+    date.format(HTTP_DATE_FORMAT).to_string()
+}
+
+pub fn parse_http_date<T>(value: T) -> Option<DateTime<Utc>>
+where
+    T: AsRef<str>,
+{
+    DateTime::parse_from_rfc2822(value.as_ref())
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// Wraps content hash into a strong entity tag
+pub fn etag<T>(hash: T) -> String
+where
+    T: AsRef<str>,
+{
+    format!("\"{}\"", hash.as_ref())
+}
+
+//? Weak comparison is used for If-None-Match (RFC 7232 section 3.2)
+fn weak_eq(left: &str, right: &str) -> bool {
+    left.trim_start_matches("W/") == right.trim_start_matches("W/")
+}
+
+/// Checks If-None-Match and If-Modified-Since headers
+/// against validators of the representation.
+/// If-Modified-Since is ignored if If-None-Match was provided.
+pub fn is_not_modified(headers: &HeaderMap, etag: Option<&str>, last_modified: Option<DateTime<Utc>>) -> bool {
+    if let Some(if_none_match) = headers.get(header::IF_NONE_MATCH) {
+        let Ok(if_none_match) = if_none_match.to_str() else {
+            return false;
+        };
+
+        return match etag {
+            Some(etag) => if_none_match
+                .split(',')
+                .map(str::trim)
+                .any(|candidate| candidate == "*" || weak_eq(candidate, etag)),
+            None => false,
+        };
+    }
+
+    match (
+        headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date),
+        last_modified,
+    ) {
+        //? Http dates have precision of seconds
+        (Some(since), Some(last_modified)) => last_modified.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
@@ -0,0 +1,154 @@
+use axum::http::{header, HeaderMap};
+use flate2::{write::GzEncoder, Compression};
+use std::{
+    io::{self, Write},
+    sync::Arc,
+};
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+/// Base two logarithm of brotli window which is the default of the reference encoder
+const BROTLI_WINDOW: u32 = 22;
+
+/// Content codings which are precompressed on deploy
+/// Variants are ordered by preference on negotiation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Brotli,
+    Zstd,
+    Gzip,
+}
+
+impl Encoding {
+    pub const ALL: [Encoding; 3] = [Encoding::Brotli, Encoding::Zstd, Encoding::Gzip];
+
+    /// Token used in `Accept-Encoding` and `Content-Encoding` headers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Brotli => "br",
+            Encoding::Zstd => "zstd",
+            Encoding::Gzip => "gzip",
+        }
+    }
+
+    /// Extension of precompressed files which can be shipped in archive
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Encoding::Brotli => "br",
+            Encoding::Zstd => "zst",
+            Encoding::Gzip => "gz",
+        }
+    }
+
+    pub fn from_token<T>(token: T) -> Option<Self>
+    where
+        T: AsRef<str>,
+    {
+        Self::ALL
+            .into_iter()
+            .find(|encoding| encoding.as_str().eq_ignore_ascii_case(token.as_ref()))
+    }
+
+    pub fn from_extension<T>(extension: T) -> Option<Self>
+    where
+        T: AsRef<str>,
+    {
+        Self::ALL
+            .into_iter()
+            .find(|encoding| encoding.extension() == extension.as_ref())
+    }
+
+    /// Compresses contents on blocking thread pool
+    /// so uploads do not stall workers serving other requests
+    pub async fn compress(&self, contents: Arc<[u8]>) -> io::Result<Vec<u8>> {
+        let encoding = *self;
+
+        tokio::task::spawn_blocking(move || encoding.encode(&contents))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    fn encode(&self, contents: &[u8]) -> io::Result<Vec<u8>> {
+        //? Quality is chosen to keep uploads of
+        //? several megabyte bundles inside of request timeout
+        match self {
+            Encoding::Brotli => {
+                let mut encoder = brotli::CompressorWriter::new(Vec::new(), BROTLI_BUFFER_SIZE, 9, BROTLI_WINDOW);
+                encoder.write_all(contents)?;
+                //? Stream is finished when encoder is turned into inner writer
+                Ok(encoder.into_inner())
+            }
+            //? Zero picks default level of zstd
+            Encoding::Zstd => zstd::stream::encode_all(contents, 0),
+            Encoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+                encoder.write_all(contents)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// Checks if it makes sense to compress files of provided type.
+/// Images, videos and archives are already compressed.
+pub fn is_compressible<T>(content_type: T) -> bool
+where
+    T: AsRef<str>,
+{
+    let essence = content_type.as_ref().split(';').next().unwrap_or_default().trim();
+
+    essence.starts_with("text/")
+        || matches!(
+            essence,
+            "application/javascript"
+                | "application/json"
+                | "application/xml"
+                | "application/wasm"
+                | "application/manifest+json"
+                | "image/svg+xml"
+                | "image/x-icon"
+                | "font/ttf"
+                | "font/otf"
+        )
+}
+
+/// Selects the most preferred available encoding
+/// according to q-values in `Accept-Encoding` header.
+/// None means that identity should be served.
+pub fn negotiate(headers: &HeaderMap, available: &[Encoding]) -> Option<Encoding> {
+    let accept_encoding = headers.get(header::ACCEPT_ENCODING)?.to_str().ok()?;
+
+    let preferences = accept_encoding
+        .split(',')
+        .filter_map(|coding| {
+            let mut parameters = coding.split(';').map(str::trim);
+            let token = parameters.next().filter(|token| !token.is_empty())?;
+
+            let quality = parameters
+                .find_map(|parameter| parameter.strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+
+            Some((token.to_ascii_lowercase(), quality))
+        })
+        .collect::<Vec<_>>();
+
+    let quality_of = |encoding: &Encoding| {
+        preferences
+            .iter()
+            .find(|(token, _)| token == encoding.as_str())
+            .or_else(|| preferences.iter().find(|(token, _)| token == "*"))
+            .map_or(0.0, |(_, quality)| *quality)
+    };
+
+    //? Encodings are ordered by preference so
+    //? the first one wins if qualities are equal
+    Encoding::ALL
+        .iter()
+        .filter(|encoding| available.contains(encoding))
+        .map(|encoding| (*encoding, quality_of(encoding)))
+        .filter(|(_, quality)| *quality > 0.0)
+        .fold(None, |best: Option<(Encoding, f32)>, (encoding, quality)| match best {
+            Some((_, best_quality)) if best_quality >= quality => best,
+            _ => Some((encoding, quality)),
+        })
+        .map(|(encoding, _)| encoding)
+}
@@ -0,0 +1,97 @@
+use axum::http::{header, HeaderMap, Uri};
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+/// Header which is set by reverse proxy in front of sero
+pub const X_SUBDOMAIN: &str = "x-subdomain";
+
+/// Separates id of preview deployment from name of the site
+pub const PREVIEW_SEPARATOR: &str = "--";
+
+/// Resolves name of the requested site.
+///
+/// `x-subdomain` header is honoured only if peer is one of trusted proxies.
+/// Without configured trusted proxies the header is honoured from any peer
+/// only if there are no base domains as sites can not be resolved otherwise.
+/// Otherwise the name is the leftmost label of `Host`
+/// if the rest of it is one of base domains.
+pub fn subdomain(
+    headers: &HeaderMap,
+    uri: &Uri,
+    peer: Option<IpAddr>,
+    base_domains: &[String],
+    trusted_proxies: Option<&[IpNet]>,
+) -> Option<String> {
+    let is_trusted = match trusted_proxies {
+        None => base_domains.is_empty(),
+        Some(trusted_proxies) => peer.is_some_and(|peer| trusted_proxies.iter().any(|proxy| proxy.contains(&peer))),
+    };
+
+    let forwarded = headers
+        .get(X_SUBDOMAIN)
+        .filter(|_| is_trusted)
+        .and_then(|header| header.to_str().ok())
+        .map(str::to_ascii_lowercase)
+        .filter(|name| !name.is_empty());
+
+    if forwarded.is_some() {
+        return forwarded;
+    }
+
+    let host = host(headers, uri)?;
+
+    base_domains.iter().find_map(|base_domain| {
+        let base_domain = base_domain.trim_matches('.').to_ascii_lowercase();
+
+        host.strip_suffix(&base_domain)
+            .and_then(|rest| rest.strip_suffix('.'))
+            .filter(|label| !label.is_empty() && !label.contains('.'))
+            .map(str::to_owned)
+    })
+}
+
+/// Splits name of preview `<deployment id>--<subdomain>`
+/// into id of the deployment and name of the site
+pub fn preview(name: &str) -> Option<(i64, &str)> {
+    let (id, subdomain) = name.split_once(PREVIEW_SEPARATOR)?;
+
+    //? Sign is accepted by parse but is not a part of generated names
+    match !subdomain.is_empty() && !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => id.parse().ok().map(|id| (id, subdomain)),
+        false => None,
+    }
+}
+
+/// Name the preview is served on
+pub fn preview_name<T>(deployment_id: i64, subdomain: T) -> String
+where
+    T: AsRef<str>,
+{
+    format!("{deployment_id}{PREVIEW_SEPARATOR}{}", subdomain.as_ref())
+}
+
+/// Requested hostname without port
+pub fn host(headers: &HeaderMap, uri: &Uri) -> Option<String> {
+    //? HTTP/2 requests carry authority in uri instead of Host header
+    headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| uri.host())
+        .map(hostname)
+        .filter(|host| !host.is_empty())
+}
+
+/// Strips port and trailing dot from host
+pub fn hostname<T>(host: T) -> String
+where
+    T: AsRef<str>,
+{
+    let host = host.as_ref();
+
+    let without_port = match host.starts_with('[') {
+        true => host.split_inclusive(']').next().unwrap_or(host),
+        false => host.split(':').next().unwrap_or(host),
+    };
+
+    without_port.trim_end_matches('.').to_ascii_lowercase()
+}
@@ -0,0 +1,8 @@
+pub mod basic;
+pub mod client;
+pub mod conditional;
+pub mod encoding;
+pub mod host;
+pub mod pattern;
+pub mod range;
+pub mod share;
@@ -0,0 +1,138 @@
+use std::{collections::HashMap, str::FromStr};
+
+/// Values captured by placeholders of matched pattern.
+/// Splat is stored with `splat` key.
+pub type Captures = HashMap<String, String>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String),
+    Splat,
+}
+
+/// Netlify-style path pattern used in `_headers` and `_redirects` files.
+/// Supports literal segments, `:name` placeholders matching exactly
+/// one segment and trailing `*` splat matching the rest of the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    segments: Vec<Segment>,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum PatternError {
+    #[error("Pattern must start with `/`!")]
+    MissingLeadingSlash,
+    #[error("Splat `*` is allowed only as the last segment!")]
+    SplatIsNotLast,
+    #[error("Placeholder must have a name!")]
+    UnnamedPlaceholder,
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let pattern = pattern.strip_prefix('/').ok_or(PatternError::MissingLeadingSlash)?;
+
+        let raw_segments = split(pattern);
+        let amount = raw_segments.len();
+
+        let segments = raw_segments
+            .into_iter()
+            .enumerate()
+            .map(|(index, segment)| match segment {
+                "*" if index + 1 == amount => Ok(Segment::Splat),
+                "*" => Err(PatternError::SplatIsNotLast),
+                ":" => Err(PatternError::UnnamedPlaceholder),
+                _ => Ok(match segment.strip_prefix(':') {
+                    Some(name) => Segment::Placeholder(name.to_owned()),
+                    None => Segment::Literal(segment.to_owned()),
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { segments })
+    }
+}
+
+impl Pattern {
+    /// Matches path against pattern.
+    /// Trailing slashes are ignored on both sides.
+    pub fn captures<T>(&self, path: T) -> Option<Captures>
+    where
+        T: AsRef<str>,
+    {
+        let path = path.as_ref().strip_prefix('/').unwrap_or(path.as_ref());
+        let path_segments = split(path);
+
+        let mut captures = Captures::new();
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            match segment {
+                //? Splat also matches empty rest so `/blog/*` matches `/blog`
+                Segment::Splat => {
+                    captures.insert(String::from("splat"), path_segments.get(index..)?.join("/"));
+                    return Some(captures);
+                }
+                Segment::Placeholder(name) => {
+                    captures.insert(name.clone(), (*path_segments.get(index)?).to_owned());
+                }
+                Segment::Literal(literal) => {
+                    if *path_segments.get(index)? != literal.as_str() {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        match path_segments.len() == self.segments.len() {
+            true => Some(captures),
+            false => None,
+        }
+    }
+
+    pub fn matches<T>(&self, path: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        self.captures(path).is_some()
+    }
+}
+
+fn split(path: &str) -> Vec<&str> {
+    path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Replaces `:name` placeholders and `:splat` in target
+/// with captured values. Unknown placeholders are kept as is.
+pub fn substitute<T>(target: T, captures: &Captures) -> String
+where
+    T: AsRef<str>,
+{
+    let target = target.as_ref();
+    let mut result = String::with_capacity(target.len());
+    let mut rest = target;
+
+    while let Some(position) = rest.find(':') {
+        result.push_str(&rest[..position]);
+        rest = &rest[position + 1..];
+
+        let length = rest
+            .find(|character: char| !(character.is_ascii_alphanumeric() || character == '_'))
+            .unwrap_or(rest.len());
+
+        match captures.get(&rest[..length]) {
+            Some(value) if length > 0 => result.push_str(value),
+            _ => {
+                result.push(':');
+                result.push_str(&rest[..length]);
+            }
+        }
+
+        rest = &rest[length..];
+    }
+
+    result.push_str(rest);
+    result
+}
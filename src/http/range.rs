@@ -0,0 +1,230 @@
+use super::conditional;
+use axum::{
+    body::Body,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::{future, stream, StreamExt};
+use std::{io, io::SeekFrom, path::Path};
+use tokio::{
+    fs,
+    io::{AsyncReadExt as _, AsyncSeekExt as _},
+};
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+
+//? Requests with more ranges than this are served in full
+//? to avoid opening a file descriptor for each part
+const MAX_RANGES: usize = 16;
+
+/// Inclusive range of bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    pub fn length(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    fn content_range(&self, size: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, size)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Ranges {
+    Satisfiable(Vec<ByteRange>),
+    Unsatisfiable,
+}
+
+/// Parses `Range` header value for representation of provided size.
+/// None means that header must be ignored and full representation is sent.
+pub fn parse<T>(value: T, size: u64) -> Option<Ranges>
+where
+    T: AsRef<str>,
+{
+    let specs = value.as_ref().trim().strip_prefix("bytes=")?;
+
+    let mut ranges = vec![];
+
+    for spec in specs.split(',').map(str::trim).filter(|spec| !spec.is_empty()) {
+        let (start, end) = spec.split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+
+        let range = match (start.is_empty(), end.is_empty()) {
+            //? Suffix range: last N bytes
+            (true, false) => {
+                let suffix = end.parse::<u64>().ok()?;
+                match suffix == 0 || size == 0 {
+                    true => None,
+                    false => Some(ByteRange {
+                        start: size.saturating_sub(suffix),
+                        end: size - 1,
+                    }),
+                }
+            }
+            (false, _) => {
+                let start = start.parse::<u64>().ok()?;
+                let end = match end.is_empty() {
+                    true => None,
+                    false => Some(end.parse::<u64>().ok()?),
+                };
+
+                if end.is_some_and(|end| end < start) {
+                    return None;
+                }
+
+                match start >= size {
+                    true => None,
+                    false => Some(ByteRange {
+                        start,
+                        end: end.map_or(size - 1, |end| end.min(size - 1)),
+                    }),
+                }
+            }
+            (true, true) => return None,
+        };
+
+        ranges.extend(range);
+    }
+
+    if ranges.len() > MAX_RANGES {
+        return None;
+    }
+
+    Some(match ranges.is_empty() {
+        true => Ranges::Unsatisfiable,
+        false => Ranges::Satisfiable(ranges),
+    })
+}
+
+/// If-Range requires strong comparison (RFC 7233 section 3.2)
+/// Range is only applied if representation was not changed
+pub fn if_range_matches(headers: &HeaderMap, etag: Option<&str>, last_modified: Option<DateTime<Utc>>) -> bool {
+    let Some(if_range) = headers.get(header::IF_RANGE) else {
+        return true;
+    };
+
+    let Ok(if_range) = if_range.to_str() else {
+        return false;
+    };
+
+    match if_range.starts_with('"') || if_range.starts_with("W/") {
+        true => etag.is_some_and(|etag| !etag.starts_with("W/") && etag == if_range),
+        false => match (conditional::parse_http_date(if_range), last_modified) {
+            (Some(date), Some(last_modified)) => date.timestamp() == last_modified.timestamp(),
+            _ => false,
+        },
+    }
+}
+
+/// Streams file honouring `Range` and `If-Range` headers.
+/// Ranges are only applied to successful responses.
+/// Validators are required to evaluate `If-Range`.
+pub async fn respond<P>(
+    status: StatusCode,
+    headers: &HeaderMap,
+    path: P,
+    content_type: String,
+    etag: Option<&str>,
+    last_modified: Option<DateTime<Utc>>,
+) -> io::Result<Response>
+where
+    P: AsRef<Path>,
+{
+    let mut file = fs::File::open(path.as_ref()).await?;
+    let size = file.metadata().await?.len();
+
+    let ranges = match headers.get(header::RANGE).and_then(|value| value.to_str().ok()) {
+        Some(value) if status == StatusCode::OK && if_range_matches(headers, etag, last_modified) => parse(value, size),
+        _ => None,
+    };
+
+    Ok(match ranges {
+        None => (
+            status,
+            [
+                (header::ACCEPT_RANGES, String::from("bytes")),
+                (header::CONTENT_TYPE, content_type),
+                (header::CONTENT_LENGTH, size.to_string()),
+            ],
+            Body::from_stream(ReaderStream::new(file)),
+        )
+            .into_response(),
+
+        Some(Ranges::Unsatisfiable) => (
+            StatusCode::RANGE_NOT_SATISFIABLE,
+            [
+                (header::ACCEPT_RANGES, String::from("bytes")),
+                (header::CONTENT_RANGE, format!("bytes */{size}")),
+            ],
+        )
+            .into_response(),
+
+        Some(Ranges::Satisfiable(ranges)) if ranges.len() == 1 => {
+            let range = ranges[0];
+            file.seek(SeekFrom::Start(range.start)).await?;
+
+            (
+                StatusCode::PARTIAL_CONTENT,
+                [
+                    (header::ACCEPT_RANGES, String::from("bytes")),
+                    (header::CONTENT_TYPE, content_type),
+                    (header::CONTENT_RANGE, range.content_range(size)),
+                    (header::CONTENT_LENGTH, range.length().to_string()),
+                ],
+                Body::from_stream(ReaderStream::new(file.take(range.length()))),
+            )
+                .into_response()
+        }
+
+        Some(Ranges::Satisfiable(ranges)) => {
+            let boundary = Uuid::new_v4().simple().to_string();
+
+            let mut parts = Vec::with_capacity(ranges.len());
+            let mut content_length = 0;
+
+            for range in ranges {
+                let part_headers = format!(
+                    "\r\n--{boundary}\r\nContent-Type: {content_type}\r\nContent-Range: {}\r\n\r\n",
+                    range.content_range(size)
+                );
+                content_length += part_headers.len() as u64 + range.length();
+
+                let mut part = fs::File::open(path.as_ref()).await?;
+                part.seek(SeekFrom::Start(range.start)).await?;
+
+                parts.push(
+                    stream::once(future::ready(Ok::<_, io::Error>(Bytes::from(part_headers))))
+                        .chain(ReaderStream::new(part.take(range.length()))),
+                );
+            }
+
+            let closing = format!("\r\n--{boundary}--\r\n");
+            content_length += closing.len() as u64;
+
+            let body = stream::iter(parts)
+                .flatten()
+                .chain(stream::once(future::ready(Ok(Bytes::from(closing)))));
+
+            (
+                StatusCode::PARTIAL_CONTENT,
+                [
+                    (header::ACCEPT_RANGES, String::from("bytes")),
+                    (
+                        header::CONTENT_TYPE,
+                        format!("multipart/byteranges; boundary={boundary}"),
+                    ),
+                    (header::CONTENT_LENGTH, content_length.to_string()),
+                ],
+                Body::from_stream(body),
+            )
+                .into_response()
+        }
+    })
+}
@@ -0,0 +1,64 @@
+use axum::http::{header, HeaderMap};
+
+/// Query parameter of share link carrying the token
+pub const SHARE_PARAMETER: &str = "sero-share";
+
+/// Cookie which keeps the token so visitor can keep browsing the site
+pub const SHARE_COOKIE: &str = "sero-share";
+
+/// Extracts share link token from query.
+/// Returns the token and the rest of the query without it.
+pub fn from_query(query: Option<&str>) -> Option<(String, Option<String>)> {
+    let query = query?;
+    let mut token = None;
+
+    let rest = query
+        .split('&')
+        .filter(|pair| match pair.split_once('=') {
+            Some((SHARE_PARAMETER, value)) if token.is_none() => {
+                token = Some(value.to_owned());
+                false
+            }
+            _ => !pair.is_empty(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    token.map(|token| (token, Some(rest).filter(|rest| !rest.is_empty())))
+}
+
+/// Address the visitor is redirected to once the token is moved to cookie.
+/// Leading slashes are collapsed as `//host` would be followed to another host.
+/// Browsers treat backslashes the same way.
+pub fn location(path: &str, rest: Option<&str>) -> String {
+    let path = format!("/{}", path.trim_start_matches(['/', '\\']));
+
+    match rest {
+        Some(rest) => format!("{path}?{rest}"),
+        None => path,
+    }
+}
+
+/// Extracts share link token from `Cookie` headers
+pub fn from_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == SHARE_COOKIE)
+        .map(|(_, value)| value.to_owned())
+}
+
+/// `Set-Cookie` value which keeps the token until it expires.
+/// Cookie has no `Domain` attribute so browser sends it only to the host of the site.
+pub fn cookie<T>(token: T, max_age: i64) -> String
+where
+    T: AsRef<str>,
+{
+    format!(
+        "{SHARE_COOKIE}={}; Max-Age={max_age}; Path=/; HttpOnly; SameSite=Lax",
+        token.as_ref()
+    )
+}
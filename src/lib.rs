@@ -1,22 +1,35 @@
 pub mod api;
 pub mod configuration;
 pub mod extractors;
+pub mod http;
+pub mod limits;
 pub mod openapi;
 pub mod services;
 pub mod state;
+pub mod tls;
 
 use self::openapi::ApiDoc;
-use axum::{body::Body, extract::DefaultBodyLimit, routing::get, Router};
+use acme::service::Service as AcmeService;
+use admin::service::Service as AdminService;
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, DefaultBodyLimit},
+    middleware,
+    routing::get,
+    Router,
+};
 use configuration::{reader::ConfigurationReader, *};
+use deployment::{error::ServiceError as DeploymentServiceError, service::Service as DeploymentService};
+use domain::service::Service as DomainService;
 use futures::StreamExt;
 use migration::{Migrator, MigratorTrait};
 use origin::service::Service as CorsService;
-use sea_orm::{ActiveModelTrait, ConnectOptions, Database, DbErr, IntoActiveModel};
+use sea_orm::{ActiveModelTrait, ConnectOptions, Database, DbErr, IntoActiveModel, TransactionTrait};
 use serde::{Deserialize, Serialize};
 use services::*;
 use site::service::Service as SiteService;
 use state::State;
-use std::{fmt::Debug, path::PathBuf, sync::Arc, time::Duration};
+use std::{fmt::Debug, net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
 use tokio::{fs, sync::oneshot};
 use tower_http::{
     cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer},
@@ -46,9 +59,12 @@ pub struct Details {
 /// specific subdomain
 /// sender here is a oneshot channel to send
 /// result back. That is a solution for collisions
+/// host is used to find site by custom domain
+/// if subdomain was not resolved
 pub struct CorsTask {
     pub origin: String,
     pub subdomain: String,
+    pub host: String,
     pub sender: oneshot::Sender<bool>,
 }
 
@@ -62,6 +78,8 @@ pub enum AppCreationError {
     IoError(#[from] std::io::Error),
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    KeyError(#[from] auth::keys::KeyError),
 }
 
 #[tracing::instrument]
@@ -93,7 +111,47 @@ pub async fn app() -> Result<(Router, Arc<State>), AppCreationError> {
     Migrator::up(&connection, None).await?;
     tracing::info!("Successfully finished running necessary database migrations!");
 
-    let state = Arc::new(State::new(connection, configuration.clone()));
+    if let Some(login) = configuration.admin_login() {
+        tracing::info!(%login, "Bootstrapping administrator...");
+        match AdminService::bootstrap(login, configuration.admin_password(), &connection).await {
+            Ok(Some(_)) => tracing::info!(%login, "Administrator was successfully bootstrapped!"),
+            Ok(None) => tracing::warn!(%login, "Administrator was not found! Set ADMIN_PASSWORD to register it"),
+            Err(cause) => tracing::warn!(%cause, %login, "Failed to bootstrap administrator!"),
+        }
+    }
+
+    if configuration.trusted_proxies().is_none() {
+        tracing::warn!("TRUSTED_PROXIES is not set! Forwarded requests are not limited per client address");
+
+        if !configuration.base_domains().is_empty() {
+            tracing::warn!("TRUSTED_PROXIES is not set! x-subdomain header will be ignored for pages");
+        }
+    }
+
+    tracing::info!("Loading JWT keys...");
+    let keys = auth::keys::JwtKeys::load(&configuration).await?;
+    tracing::info!(?keys, "JWT keys were successfully loaded!");
+
+    let state = Arc::new(State::new(connection, configuration.clone(), keys));
+
+    //* Certificates are loaded even if TLS listener is disabled
+    //* as they are cheap and listener is started by main
+    tracing::info!("Loading TLS certificates...");
+    if let Err(cause) = state.certificates().load_default(state.configuration()).await {
+        tracing::warn!(%cause, "Failed to load default certificate!");
+    }
+
+    match DomainService::active_certificates(state.connection()).await {
+        Ok(certificates) => {
+            for (domain, stored) in certificates {
+                match tls::certificate::parse(stored.chain, stored.private_key) {
+                    Ok(parsed) => state.certificates().insert(&domain.name, parsed.key),
+                    Err(cause) => tracing::warn!(%cause, %domain.name, "Failed to parse stored certificate!"),
+                }
+            }
+        }
+        Err(cause) => tracing::warn!(%cause, "Failed to retrieve stored certificates!"),
+    }
 
     //* This cloned state will be used in spawned task for cors
     let state_for_origins_task = state.clone();
@@ -112,22 +170,36 @@ pub async fn app() -> Result<(Router, Arc<State>), AppCreationError> {
     tracing::info!("Spawning task for checking allowed origins [Dynamic Cors Management]...");
     tokio::spawn(async move {
         while let Some(task) = cors_task_receiver.recv().await {
-            if let Err(send_error) = task.sender.send(
-                CorsService::check_if_origin_is_allowed_for(
-                    task.subdomain,
+            let subdomain = match task.subdomain.is_empty() {
+                false => Some(task.subdomain),
+                true => DomainService::subdomain_for(&task.host, state_for_origins_task.connection())
+                    .await
+                    .inspect_err(|cause| tracing::warn!(%cause, "Failed to find site by custom domain!"))
+                    .ok()
+                    .flatten()
+                    .map(|subdomain| subdomain.name),
+            };
+
+            //? Host which is not a site is probably management tool
+            let is_allowed = match subdomain {
+                None => true,
+                Some(subdomain) => CorsService::check_if_origin_is_allowed_for(
+                    subdomain,
                     task.origin,
                     state_for_origins_task.connection(),
                 )
                 .await
                 .inspect_err(|cause| tracing::warn!(%cause, "Failed to check if origin is allowed!"))
                 .unwrap_or(false),
-            ) {
+            };
+
+            if let Err(send_error) = task.sender.send(is_allowed) {
                 tracing::error!(%send_error, "Failed to send result of checking if origin is allowed!");
             };
         }
     });
 
-    //* This task is responsible for cleanup of obsolete files after uploads
+    //* This task is responsible for cleanup of expired previews and obsolete files after uploads
     //* And prevents the server from oom
     //* The task will start with interval defined in CLEAN_OBSOLETE_INTERVAL
     //* If this env was not set the default interval will be 60 seconds
@@ -153,6 +225,29 @@ pub async fn app() -> Result<(Router, Arc<State>), AppCreationError> {
             loop {
                 interval.tick().await;
                 tracing::debug!("Starting next iteration of cleanup task...");
+
+                //? Expired previews are removed first
+                //? so their files are cleaned up in the same iteration
+                let expired = async {
+                    let transaction = state_for_file_deletion_task.connection().begin().await?;
+                    let previews = DeploymentService::expire(&transaction).await?;
+                    transaction.commit().await?;
+                    Ok::<_, DeploymentServiceError>(previews)
+                }
+                .await
+                .inspect_err(|cause| tracing::warn!(%cause, "Failed to remove expired previews!"))
+                .unwrap_or_default();
+
+                for archive_path in expired.iter().filter_map(|preview| preview.archive_path.as_ref()) {
+                    tracing::debug!("Removing: {:?}", archive_path);
+                    fs::remove_file(archive_path)
+                        .await
+                        .inspect_err(
+                            |cause| tracing::warn!(%cause, "Failed to remove archive with path : {archive_path}"),
+                        )
+                        .ok();
+                }
+
                 if let Ok(mut stream) = SiteService::obsolete(state_for_file_deletion_task.connection())
                     .await
                     .inspect_err(|cause| tracing::warn!(%cause, "Failed to get stream with obsolete files!"))
@@ -181,34 +276,122 @@ pub async fn app() -> Result<(Router, Arc<State>), AppCreationError> {
         .await
     });
 
+    //* This task drops full buckets of rate limiter
+    //* so clients which stopped sending requests do not take memory
+    tracing::info!("Spawning task which is responsible for rate limiter sweep...");
+
+    let state_for_sweep_task = state.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(limits::limiter::SWEEP_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let dropped = state_for_sweep_task.limiter().sweep();
+            tracing::trace!(%dropped, "Full buckets of rate limiter were dropped");
+        }
+    });
+
+    //* This task is responsible for ACME certificates
+    //* It runs with interval defined in ACME_CHECK_INTERVAL (12 hours by default)
+    //* and right after a custom domain was verified
+    //* HTTP-01 can not prove wildcard names so sites under base domains
+    //* are served only with default certificate which must be provided
+    let is_acme_covering = state.configuration().base_domains().is_empty() || state.certificates().has_default();
+    let acme_directory = state.configuration().acme_directory().map(str::to_owned);
+
+    if acme_directory.is_some() && !is_acme_covering {
+        tracing::error!(
+            "Default certificate is not loaded! Sites under BASE_DOMAINS can not get certificates with HTTP-01 so ACME is disabled. Set TLS_CERTIFICATE covering *.<base domain>"
+        );
+    }
+
+    if let Some(directory) = acme_directory.filter(|_| is_acme_covering) {
+        tracing::info!("Spawning task which is responsible for ACME certificates...");
+
+        let state_for_acme_task = state.clone();
+
+        tokio::spawn(async move {
+            let span = tracing::span!(Level::TRACE, "ACME task");
+            span.in_scope(|| async move {
+                let account = loop {
+                    match AcmeService::account(&state_for_acme_task, &directory).await {
+                        Ok(account) => break account,
+                        Err(cause) => {
+                            tracing::warn!(%cause, "Failed to retrieve ACME account! Retrying in a minute...");
+                            tokio::time::sleep(Duration::from_secs(60)).await;
+                        }
+                    }
+                };
+
+                let default_interval = 12 * 60 * 60;
+
+                let duration = Duration::from_secs(
+                    state_for_acme_task
+                        .configuration()
+                        .acme_check_interval()
+                        .unwrap_or(default_interval),
+                );
+                let mut interval = tokio::time::interval(duration);
+
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {},
+                        _ = state_for_acme_task.challenges().renewal_requested() => {},
+                    }
+                    tracing::debug!("Checking ACME certificates...");
+                    AcmeService::renew(&state_for_acme_task, &account).await;
+                }
+            })
+            .await
+        });
+    }
+
     //* According to features of this server we need to check
-    //* AllowedOrigin for each request based on x-subdomain header
+    //* AllowedOrigin for each request based on resolved subdomain
     //* 1) We retrieve `Origin` header
-    //* 2) We retrieve `x-subdomain` header for
+    //* 2) We resolve subdomain from `Host` or trusted `x-subdomain` header
+    //*    or pass host to look it up among custom domains
     //* 3) We create oneshot channel to receive result of cors check
     //*    back to closure
     //* 4) We send [`CorsTask`] to the spawned task and wait for the result
 
     tracing::info!("Initializing tower_http::CorsLayer...");
 
+    let state_for_cors_layer = state.clone();
+
     let cors_layer = CorsLayer::new()
         .allow_methods(AllowMethods::any())
         .allow_headers(AllowHeaders::any())
         .allow_origin(AllowOrigin::async_predicate(move |origin, parts| {
             let retrieved_origin = origin.to_str().unwrap_or_default();
-            let retrieved_subdomain = parts.headers.get("x-subdomain").map(|s| s.to_str().unwrap_or_default());
+            let peer = parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(address)| address.ip());
+            let retrieved_subdomain = http::host::subdomain(
+                &parts.headers,
+                &parts.uri,
+                peer,
+                state_for_cors_layer.configuration().base_domains(),
+                state_for_cors_layer.configuration().trusted_proxies(),
+            );
+
+            let retrieved_host = http::host::host(&parts.headers, &parts.uri);
 
             let (sender, receiver) = tokio::sync::oneshot::channel();
             let task = CorsTask {
                 origin: retrieved_origin.to_owned(),
-                subdomain: retrieved_subdomain.unwrap_or_default().to_owned(),
+                subdomain: retrieved_subdomain.unwrap_or_default(),
+                host: retrieved_host.unwrap_or_default(),
                 sender,
             };
 
             async move {
                 //? If header was not provided
                 //? Allow as it probably management tool
-                if task.subdomain.is_empty() {
+                if task.subdomain.is_empty() && task.host.is_empty() {
                     return true;
                 }
 
@@ -249,8 +432,18 @@ pub async fn app() -> Result<(Router, Arc<State>), AppCreationError> {
         .merge(openapi)
         .route("/*path", get(api::site::page::handler::implementation))
         .route("/", get(api::site::page::handler::redirect::implementation))
+        .route(
+            "/.well-known/sero-challenge/:token",
+            get(api::domain::challenge::handler::implementation),
+        )
+        .route(
+            "/.well-known/acme-challenge/:token",
+            get(api::acme::challenge::handler::implementation),
+        )
+        .route("/.well-known/jwks.json", get(api::auth::jwks::handler::implementation))
         .layer(cors_layer)
         .nest("/api", api::router())
+        .layer(middleware::from_fn_with_state(state.clone(), limits::layer::limit))
         .layer(tracing_layer)
         .layer(TimeoutLayer::new(Duration::from_secs(10)))
         .with_state(state.clone());
@@ -0,0 +1,69 @@
+use std::time::{Duration, Instant};
+
+/// Parameters of token bucket
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limit {
+    /// Tokens added per second
+    rate: f64,
+    /// Maximum amount of tokens so requests can come in bursts
+    burst: f64,
+}
+
+impl Limit {
+    /// Allows `per_minute` requests on average and bursts of `burst` requests.
+    /// Burst defaults to `per_minute`. Zero rate means there is no limit.
+    pub fn per_minute(per_minute: u32, burst: Option<u32>) -> Option<Self> {
+        if per_minute == 0 {
+            return None;
+        }
+
+        Some(Self {
+            rate: f64::from(per_minute) / 60.0,
+            burst: f64::from(burst.unwrap_or(per_minute).max(1)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Bucket {
+    limit: Limit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    pub fn full(limit: Limit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst,
+            updated: now,
+        }
+    }
+
+    /// Takes one token.
+    /// Otherwise returns time after which the next token is added.
+    pub fn take(&mut self, limit: Limit, now: Instant) -> Result<(), Duration> {
+        //? Limit of a site can be changed by its owner at any time
+        self.limit = limit;
+        self.refill(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(Duration::from_secs_f64((1.0 - self.tokens) / self.limit.rate))
+    }
+
+    /// Full bucket is the same as a missing one so it can be dropped
+    pub fn is_full(&self, now: Instant) -> bool {
+        self.tokens + now.duration_since(self.updated).as_secs_f64() * self.limit.rate >= self.limit.burst
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.limit.rate).min(self.limit.burst);
+        self.updated = now;
+    }
+}
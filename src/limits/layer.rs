@@ -0,0 +1,151 @@
+use super::{
+    bucket::Limit,
+    limiter::{Key, Scope},
+};
+use crate::{
+    http::{client, host},
+    services::domain::service::Service as DomainService,
+    state::State as AppState,
+    Details,
+};
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use entity::prelude::*;
+use sea_orm::prelude::*;
+use std::{net::SocketAddr, sync::Arc};
+
+/// Token bucket rate limiting of every request.
+///
+/// Clients are told when to retry with `Retry-After` header.
+/// Requests are not limited if address of the client is unknown.
+#[tracing::instrument(skip(state, request, next))]
+pub async fn limit(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    let Some(scope) = Scope::of(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(address)| address.ip());
+
+    let Some(address) = client::address(request.headers(), peer, state.configuration().trusted_proxies()) else {
+        return next.run(request).await;
+    };
+
+    //? Site provided by client is not a part of the key
+    //? as rotating it would give a fresh bucket every time.
+    //? Pages are limited per site only if it was resolved
+    let (site, limit) = match scope {
+        Scope::Pages => {
+            let site = host::subdomain(
+                request.headers(),
+                request.uri(),
+                peer,
+                state.configuration().base_domains(),
+                state.configuration().trusted_proxies(),
+            )
+            .or_else(|| host::host(request.headers(), request.uri()));
+
+            match site {
+                Some(site) => page_limit(&state, &site).await,
+                None => (None, scope.limit(state.configuration())),
+            }
+        }
+        Scope::Auth | Scope::Api => (None, scope.limit(state.configuration())),
+    };
+
+    let Some(limit) = limit else {
+        return next.run(request).await;
+    };
+
+    let key = Key { scope, address, site };
+
+    match state.limiter().check(key, limit) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            //? Clients must not retry before the token is added
+            let retry_after = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+            tracing::debug!(?scope, %address, %retry_after, "Too many requests!");
+
+            let reason = format!("Too many requests! Retry after {retry_after} seconds");
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(Details { reason }),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Page limit of the site overrides server wide one.
+/// Id of the site is returned if the site exists.
+/// Previews are limited together with their site
+async fn page_limit(state: &AppState, site: &str) -> (Option<i64>, Option<Limit>) {
+    let default = Scope::Pages.limit(state.configuration());
+
+    //? Made up ids of previews must not grow the cache
+    //? so previews are cached by the name of their site
+    let parent = host::preview(site).map(|(_, subdomain)| subdomain);
+
+    if let Some((subdomain_id, cached)) = std::iter::once(site)
+        .chain(parent)
+        .find_map(|name| state.limiter().override_for(name))
+    {
+        return (Some(subdomain_id), cached.or(default));
+    }
+
+    let subdomain = resolve(state, site, parent).await;
+
+    match subdomain {
+        Ok(Some((name, subdomain))) => {
+            let limit = subdomain.rate_limit.and_then(|per_minute| {
+                Limit::per_minute(
+                    u32::try_from(per_minute).unwrap_or_default(),
+                    subdomain.rate_burst.and_then(|burst| u32::try_from(burst).ok()),
+                )
+            });
+
+            state.limiter().cache_override(name, subdomain.id, limit);
+            (Some(subdomain.id), limit.or(default))
+        }
+        //? Unknown sites are not cached as anyone can make up hosts
+        Ok(None) => (None, default),
+        Err(cause) => {
+            tracing::warn!(%cause, %site, "Failed to retrieve page limit of site!");
+            (None, default)
+        }
+    }
+}
+
+/// Site is either one of subdomains, a preview of one or a custom domain.
+/// Name the site should be cached by is returned along with the site
+async fn resolve<'a>(
+    state: &AppState,
+    site: &'a str,
+    parent: Option<&'a str>,
+) -> Result<Option<(&'a str, SubdomainModel)>, String> {
+    //? Existing sites with names of previews take precedence
+    for name in std::iter::once(site).chain(parent) {
+        let subdomain = SubdomainEntity::find()
+            .filter(SubdomainColumn::Name.eq(name))
+            .one(state.connection())
+            .await
+            .map_err(|cause| cause.to_string())?;
+
+        if let Some(subdomain) = subdomain {
+            return Ok(Some((name, subdomain)));
+        }
+    }
+
+    DomainService::subdomain_for(site, state.connection())
+        .await
+        .map(|subdomain| subdomain.map(|subdomain| (site, subdomain)))
+        .map_err(|cause| cause.to_string())
+}
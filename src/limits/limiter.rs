@@ -0,0 +1,132 @@
+use super::bucket::{Bucket, Limit};
+use crate::configuration::Configuration;
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+/// Interval between sweeps of full buckets
+pub const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Group of endpoints with its own limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// `/api/auth` endpoints
+    Auth,
+    /// The rest of management API
+    Api,
+    /// Pages of sites
+    Pages,
+}
+
+impl Scope {
+    /// Classifies request by its path.
+    /// Challenges and API documentation are never limited.
+    pub fn of<T>(path: T) -> Option<Self>
+    where
+        T: AsRef<str>,
+    {
+        let path = path.as_ref();
+        let starts_with = |prefix: &str| path == prefix || path.starts_with(&format!("{prefix}/"));
+
+        match path {
+            _ if starts_with("/api/auth") => Some(Scope::Auth),
+            _ if starts_with("/api/health") || starts_with("/api-docs") => None,
+            _ if starts_with("/api") => Some(Scope::Api),
+            _ if starts_with("/.well-known/sero-challenge") || starts_with("/.well-known/acme-challenge") => None,
+            _ if starts_with("/.well-known/jwks.json") => None,
+            _ if starts_with("/swagger-ui") || starts_with("/redoc") || starts_with("/rapidoc") => None,
+            _ => Some(Scope::Pages),
+        }
+    }
+
+    /// Server wide limit of the scope
+    pub fn limit(&self, configuration: &Configuration) -> Option<Limit> {
+        let (per_minute, burst) = match self {
+            Scope::Auth => (configuration.auth_rate_limit(), configuration.auth_rate_burst()),
+            Scope::Api => (configuration.api_rate_limit(), configuration.api_rate_burst()),
+            Scope::Pages => (configuration.page_rate_limit(), configuration.page_rate_burst()),
+        };
+
+        Limit::per_minute(per_minute?, burst)
+    }
+}
+
+/// Clients are limited separately in every scope.
+/// Pages are limited separately for every resolved site
+/// as anything provided by client could be rotated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub scope: Scope,
+    pub address: IpAddr,
+    pub site: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<Key, Bucket>>,
+    //? Ids and page limits of sites are cached by the name or the custom domain
+    //? to avoid a database query on every request
+    overrides: RwLock<HashMap<String, (i64, Option<Limit>)>>,
+}
+
+impl RateLimiter {
+    /// Takes a token from the bucket of the key.
+    /// Otherwise returns time after which request can be retried.
+    pub fn check(&self, key: Key, limit: Limit) -> Result<(), Duration> {
+        let now = Instant::now();
+
+        self.buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(key)
+            .or_insert_with(|| Bucket::full(limit, now))
+            .take(limit, now)
+    }
+
+    /// Drops full buckets as they are the same as missing ones.
+    /// Returns amount of dropped buckets
+    pub fn sweep(&self) -> usize {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let before = buckets.len();
+        buckets.retain(|_, bucket| !bucket.is_full(now));
+        before - buckets.len()
+    }
+
+    /// Cached id and page limit of the site.
+    /// None means that site was not cached yet.
+    pub fn override_for<T>(&self, site: T) -> Option<(i64, Option<Limit>)>
+    where
+        T: AsRef<str>,
+    {
+        self.overrides
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(site.as_ref())
+            .copied()
+    }
+
+    pub fn cache_override<T>(&self, site: T, subdomain_id: i64, limit: Option<Limit>)
+    where
+        T: Into<String>,
+    {
+        self.overrides
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(site.into(), (subdomain_id, limit));
+    }
+
+    /// Drops cached page limits of all sites.
+    /// Site can be reached by several custom domains
+    /// so it is easier to forget everything on change.
+    pub fn forget_overrides(&self) {
+        self.overrides
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+}
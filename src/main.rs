@@ -1,6 +1,6 @@
-use sero::{app, enable_logging};
+use sero::{app, enable_logging, tls};
 use std::net::SocketAddr;
-use tokio::{net::TcpListener, signal};
+use tokio::{net::TcpListener, signal, sync::watch};
 
 async fn shutdown_signal() {
     let ctrl_c = async {
@@ -23,6 +23,7 @@ async fn shutdown_signal() {
         _ = terminate => {},
     }
 }
+
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
@@ -49,13 +50,75 @@ async fn main() {
             return;
         }
     };
-    let service = app.into_make_service();
+
+    //? Signal handlers are installed once and shared by both listeners
+    let (shutdown_sender, shutdown) = watch::channel(());
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        tracing::info!("Shutting down...");
+        shutdown_sender.send(()).ok();
+    });
+
+    //? Peer address is required to check if request came from trusted proxy
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
+
+    let mut tls_server = None;
+
+    if let Some(tls_port) = state.configuration().tls_port() {
+        let tls_addr = SocketAddr::from((ip, tls_port));
+
+        match TcpListener::bind(tls_addr).await {
+            Ok(tls_listener) => {
+                tracing::info!("TLS listener is now up and listening on {}", tls_addr);
+                tls_server = Some(tokio::spawn(tls::server::serve(
+                    tls_listener,
+                    state.certificates().clone(),
+                    service.clone(),
+                    shutdown.clone(),
+                )));
+            }
+            Err(cause) => {
+                tracing::error!(%cause, "Failed to initialize tls listener!");
+                return;
+            }
+        }
+
+        //? Default certificate can be renewed without restart
+        #[cfg(unix)]
+        {
+            let state = state.clone();
+            tokio::spawn(async move {
+                let Ok(mut hangup) = signal::unix::signal(signal::unix::SignalKind::hangup()) else {
+                    tracing::warn!("Failed to install SIGHUP handler! Default certificate will not be reloaded");
+                    return;
+                };
+
+                while hangup.recv().await.is_some() {
+                    tracing::info!("Reloading default certificate...");
+                    if let Err(cause) = state.certificates().load_default(state.configuration()).await {
+                        tracing::error!(%cause, "Failed to reload default certificate!");
+                    }
+                }
+            });
+        }
+    }
 
     tracing::info!("Server is now up and listening on {}", addr);
 
     axum::serve(listener, service)
-        .with_graceful_shutdown(shutdown_signal())
+        .with_graceful_shutdown(async move {
+            let mut shutdown = shutdown;
+            shutdown.changed().await.ok();
+        })
         .await
         .inspect_err(|cause| tracing::error!(%cause, "Failed to start or execute server!"))
         .ok();
+
+    //? In-flight TLS connections are drained before exit
+    if let Some(tls_server) = tls_server {
+        tls_server
+            .await
+            .inspect_err(|cause| tracing::error!(%cause, "TLS server failed!"))
+            .ok();
+    }
 }
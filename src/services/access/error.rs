@@ -0,0 +1,25 @@
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("{0} is not a valid IP address or network in CIDR notation!")]
+    InvalidNetwork(String),
+    #[error("Access rule with id = {0} was not found!")]
+    AccessRuleWasNotFound(i64),
+    #[error("Access rule with id = {0} does not belong to subdomain with id {1}!")]
+    AccessRuleDoesNotBelongToSubdomain(i64, i64),
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::InvalidNetwork(_) => Self::BAD_REQUEST,
+            ServiceError::AccessRuleWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::AccessRuleDoesNotBelongToSubdomain(_, _) => Self::FORBIDDEN,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// What happens with clients from the network of the rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessAction {
+    /// Once there is at least one allow rule
+    /// only clients from allowed networks are served
+    Allow,
+    /// Clients from denied networks are never served
+    Deny,
+}
+
+impl AccessAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessAction::Allow => "allow",
+            AccessAction::Deny => "deny",
+        }
+    }
+}
+
+impl FromStr for AccessAction {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "allow" => Ok(AccessAction::Allow),
+            "deny" => Ok(AccessAction::Deny),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::{error::ServiceError, models::AccessAction};
+use entity::prelude::*;
+use ipnet::IpNet;
+use sea_orm::{prelude::*, QueryOrder, Set, TransactionTrait};
+use std::{fmt::Debug, net::IpAddr};
+
+pub struct Service;
+
+impl Service {
+    /// Parses network in CIDR notation.
+    /// Single address is treated as network of one host.
+    pub fn network<N>(network: N) -> Result<IpNet, ServiceError>
+    where
+        N: AsRef<str>,
+    {
+        let network = network.as_ref().trim();
+
+        network
+            .parse::<IpNet>()
+            .or_else(|_| network.parse::<IpAddr>().map(IpNet::from))
+            .map(|network| network.trunc())
+            .map_err(|_| ServiceError::InvalidNetwork(network.to_owned()))
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn add_rule_for<C, N>(
+        subdomain_id: i64,
+        network: N,
+        action: AccessAction,
+        connection: &C,
+    ) -> Result<AccessRuleModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        N: AsRef<str> + Debug,
+    {
+        let rule_to_be_inserted = AccessRuleActiveModel {
+            subdomain_id: Set(subdomain_id),
+            network: Set(Self::network(network)?.to_string()),
+            action: Set(action.as_str().to_owned()),
+            ..Default::default()
+        };
+
+        Ok(AccessRuleEntity::insert(rule_to_be_inserted)
+            .exec_with_returning(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_rules_for<C>(subdomain_id: i64, connection: &C) -> Result<Vec<AccessRuleModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(AccessRuleEntity::find()
+            .filter(AccessRuleColumn::SubdomainId.eq(subdomain_id))
+            .order_by_asc(AccessRuleColumn::Id)
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn delete_rules_for<C>(subdomain_id: i64, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let rows_affected = AccessRuleEntity::delete_many()
+            .filter(AccessRuleColumn::SubdomainId.eq(subdomain_id))
+            .exec(connection)
+            .await?
+            .rows_affected;
+        Ok(rows_affected)
+    }
+
+    pub async fn fetch_rule_of<C>(
+        subdomain_id: i64,
+        rule_id: i64,
+        connection: &C,
+    ) -> Result<AccessRuleModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let rule = AccessRuleEntity::find_by_id(rule_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::AccessRuleWasNotFound(rule_id))?;
+
+        if rule.subdomain_id != subdomain_id {
+            return Err(ServiceError::AccessRuleDoesNotBelongToSubdomain(rule_id, subdomain_id));
+        }
+
+        Ok(rule)
+    }
+
+    pub async fn delete_rule_of<C>(subdomain_id: i64, rule_id: i64, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let rule = Self::fetch_rule_of(subdomain_id, rule_id, connection).await?;
+
+        let rows_affected = rule.delete(connection).await?.rows_affected;
+        Ok(rows_affected)
+    }
+
+    /// Checks if client may reach the site.
+    /// Deny rules win over allow rules.
+    /// Once there is an allow rule only allowed networks are served.
+    #[tracing::instrument(skip(connection))]
+    pub async fn is_allowed_for<C>(
+        subdomain_id: i64,
+        address: Option<IpAddr>,
+        connection: &C,
+    ) -> Result<bool, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let rules = Self::retrieve_rules_for(subdomain_id, connection).await?;
+
+        let mut has_allow_rules = false;
+        let mut is_allowed = false;
+
+        for rule in rules.iter() {
+            //? Rules are validated on insert so broken ones are skipped
+            let (Ok(network), Ok(action)) = (rule.network.parse::<IpNet>(), rule.action.parse::<AccessAction>()) else {
+                tracing::warn!(?rule, "Access rule is malformed. Skipping...");
+                continue;
+            };
+
+            let matches = address.is_some_and(|address| network.contains(&address));
+
+            match action {
+                AccessAction::Deny if matches => return Ok(false),
+                AccessAction::Deny => {}
+                AccessAction::Allow => {
+                    has_allow_rules = true;
+                    is_allowed |= matches;
+                }
+            }
+        }
+
+        Ok(!has_allow_rules || is_allowed)
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+use tokio::sync::Notify;
+
+/// Delay before the first retry of a failed order.
+/// It is doubled with every next failure
+pub const RETRY_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// Failed orders are retried at least once a day
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, Copy)]
+struct Failure {
+    failures: u32,
+    retry_at: Instant,
+}
+
+/// Key authorizations of HTTP-01 challenges which are being validated.
+/// Also wakes renewal task when a new domain needs certificate
+/// and keeps failed names from being ordered again too soon.
+#[derive(Debug, Default)]
+pub struct Challenges {
+    pending: RwLock<HashMap<String, String>>,
+    failures: RwLock<HashMap<String, Failure>>,
+    renewal: Notify,
+}
+
+impl Challenges {
+    pub fn insert<T, K>(&self, token: T, key_authorization: K)
+    where
+        T: Into<String>,
+        K: Into<String>,
+    {
+        if let Ok(mut pending) = self.pending.write() {
+            pending.insert(token.into(), key_authorization.into());
+        }
+    }
+
+    pub fn remove<T>(&self, token: T)
+    where
+        T: AsRef<str>,
+    {
+        if let Ok(mut pending) = self.pending.write() {
+            pending.remove(token.as_ref());
+        }
+    }
+
+    pub fn key_authorization<T>(&self, token: T) -> Option<String>
+    where
+        T: AsRef<str>,
+    {
+        self.pending.read().ok()?.get(token.as_ref()).cloned()
+    }
+
+    /// Checks if order for the name can be placed.
+    /// Names which have never failed can always be ordered
+    pub fn can_order<T>(&self, name: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        self.failures
+            .read()
+            .ok()
+            .and_then(|failures| failures.get(name.as_ref()).copied())
+            .map_or(true, |failure| failure.retry_at <= Instant::now())
+    }
+
+    /// Postpones next order for the name.
+    /// Returns the delay before it can be ordered again
+    pub fn order_failed<T>(&self, name: T) -> Duration
+    where
+        T: Into<String>,
+    {
+        let Ok(mut failures) = self.failures.write() else {
+            return RETRY_AFTER;
+        };
+
+        let failure = failures.entry(name.into()).or_insert(Failure {
+            failures: 0,
+            retry_at: Instant::now(),
+        });
+
+        let delay = RETRY_AFTER
+            .saturating_mul(2u32.saturating_pow(failure.failures))
+            .min(MAX_RETRY_AFTER);
+
+        failure.failures = failure.failures.saturating_add(1);
+        failure.retry_at = Instant::now() + delay;
+
+        delay
+    }
+
+    pub fn order_succeeded<T>(&self, name: T)
+    where
+        T: AsRef<str>,
+    {
+        if let Ok(mut failures) = self.failures.write() {
+            failures.remove(name.as_ref());
+        }
+    }
+
+    /// Asks renewal task to check certificates now
+    pub fn request_renewal(&self) {
+        self.renewal.notify_one();
+    }
+
+    pub async fn renewal_requested(&self) {
+        self.renewal.notified().await;
+    }
+}
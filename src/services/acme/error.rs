@@ -0,0 +1,25 @@
+use crate::{services::domain::error::ServiceError as DomainServiceError, tls::certificate::CertificateError};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    AcmeError(#[from] instant_acme::Error),
+    #[error(transparent)]
+    KeyGenerationError(#[from] rcgen::Error),
+    #[error(transparent)]
+    FileSystemError(#[from] tokio::io::Error),
+    #[error(transparent)]
+    SerializationError(#[from] serde_json::Error),
+    #[error(transparent)]
+    CertificateError(#[from] CertificateError),
+    #[error(transparent)]
+    DomainServiceError(#[from] DomainServiceError),
+    #[error("Domain {0} can not be validated with HTTP-01 challenge!")]
+    ChallengeIsNotSupported(String),
+    #[error("Order for {0} has failed: {1}")]
+    OrderFailed(String, String),
+    #[error("Order for {0} was not completed in time!")]
+    OrderTimedOut(String),
+    #[error("Order for {0} has failed recently and is postponed!")]
+    OrderIsPostponed(String),
+}
@@ -0,0 +1,3 @@
+pub mod challenges;
+pub mod error;
+pub mod service;
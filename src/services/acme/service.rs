@@ -0,0 +1,304 @@
+use super::{challenges::Challenges, error::ServiceError};
+use crate::{
+    services::domain::service::Service as DomainService,
+    state::State,
+    tls::certificate::{self, ParsedCertificate},
+};
+use chrono::{Duration as ChronoDuration, Utc};
+use instant_acme::{
+    Account, AccountCredentials, AuthorizationStatus, ChallengeType, Identifier, NewAccount, NewOrder, Order,
+    OrderStatus,
+};
+use rcgen::{CertificateParams, DistinguishedName, KeyPair};
+use sha2::{Digest, Sha256};
+use std::{path::PathBuf, time::Duration};
+use tokio::fs;
+
+/// Folder inside upload folder with account credentials
+/// and certificates of base domains
+pub const ACME_FOLDER: &str = "acme";
+
+/// Certificates are renewed when they expire in less than that
+pub const RENEW_BEFORE_DAYS: i64 = 30;
+
+const POLL_ATTEMPTS: usize = 10;
+
+pub struct Service;
+
+impl Service {
+    fn folder(state: &State) -> PathBuf {
+        state.configuration().upload_folder().join(ACME_FOLDER)
+    }
+
+    fn is_due(not_after: chrono::DateTime<Utc>) -> bool {
+        not_after - Utc::now() < ChronoDuration::days(RENEW_BEFORE_DAYS)
+    }
+
+    /// Loads account for the directory or registers a new one.
+    /// Credentials are stored per directory so switching
+    /// from staging to production does not reuse foreign account.
+    #[tracing::instrument(skip(state))]
+    pub async fn account(state: &State, directory: &str) -> Result<Account, ServiceError> {
+        let folder = Self::folder(state);
+        fs::create_dir_all(&folder).await?;
+
+        let directory_hash = format!("{:x}", Sha256::digest(directory.as_bytes()));
+        let path = folder.join(format!("account-{}.json", &directory_hash[..16]));
+
+        if fs::try_exists(&path).await? {
+            let credentials = serde_json::from_slice::<AccountCredentials>(&fs::read(&path).await?)?;
+            tracing::info!(?path, "ACME account credentials were loaded");
+            return Ok(Account::from_credentials(credentials).await?);
+        }
+
+        let contact = state
+            .configuration()
+            .acme_contact()
+            .map(|contact| format!("mailto:{contact}"));
+        let contact = contact.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let (account, credentials) = Account::create(
+            &NewAccount {
+                contact: &contact,
+                terms_of_service_agreed: true,
+                only_return_existing: false,
+            },
+            directory,
+            None,
+        )
+        .await?;
+
+        fs::write(&path, serde_json::to_vec(&credentials)?).await?;
+        tracing::info!(?path, "ACME account was registered");
+
+        Ok(account)
+    }
+
+    /// Orders certificate for the names answering HTTP-01 challenges
+    /// from the router. Returns PEM chain and private key.
+    #[tracing::instrument(skip(account, challenges))]
+    pub async fn issue(
+        account: &Account,
+        names: &[String],
+        challenges: &Challenges,
+    ) -> Result<(String, String), ServiceError> {
+        let identifiers = names.iter().cloned().map(Identifier::Dns).collect::<Vec<_>>();
+        let mut order = account
+            .new_order(&NewOrder {
+                identifiers: &identifiers,
+            })
+            .await?;
+
+        let mut tokens = Vec::new();
+        let mut result = Ok(());
+
+        for authorization in order.authorizations().await? {
+            if matches!(authorization.status, AuthorizationStatus::Valid) {
+                continue;
+            }
+
+            let Some(challenge) = authorization
+                .challenges
+                .iter()
+                .find(|challenge| challenge.r#type == ChallengeType::Http01)
+            else {
+                result = Err(ServiceError::ChallengeIsNotSupported(format!(
+                    "{:?}",
+                    authorization.identifier
+                )));
+                break;
+            };
+
+            challenges.insert(&challenge.token, order.key_authorization(challenge).as_str());
+            tokens.push(challenge.token.clone());
+
+            if let Err(cause) = order.set_challenge_ready(&challenge.url).await {
+                result = Err(cause.into());
+                break;
+            }
+        }
+
+        //? Challenges must be answered until order is validated
+        //? and are forgotten whatever the result is
+        let result = match result {
+            Ok(()) => Self::complete(&mut order, names).await,
+            Err(cause) => Err(cause),
+        };
+
+        for token in tokens {
+            challenges.remove(token);
+        }
+
+        result
+    }
+
+    /// Issues certificate for the single name unless its previous order
+    /// has failed recently. Failures are remembered so names which can not
+    /// be validated do not exhaust rate limits of the directory
+    async fn issue_for(
+        account: &Account,
+        name: &str,
+        challenges: &Challenges,
+    ) -> Result<(String, String), ServiceError> {
+        if !challenges.can_order(name) {
+            return Err(ServiceError::OrderIsPostponed(name.to_owned()));
+        }
+
+        match Self::issue(account, &[name.to_owned()], challenges).await {
+            Ok(issued) => {
+                challenges.order_succeeded(name);
+                Ok(issued)
+            }
+            Err(cause) => {
+                let delay = challenges.order_failed(name);
+                tracing::debug!(%name, ?delay, "Next order is postponed");
+                Err(cause)
+            }
+        }
+    }
+
+    async fn complete(order: &mut Order, names: &[String]) -> Result<(String, String), ServiceError> {
+        let mut delay = Duration::from_millis(250);
+
+        for _ in 0..POLL_ATTEMPTS {
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(Duration::from_secs(10));
+
+            match order.refresh().await?.status {
+                OrderStatus::Ready => break,
+                OrderStatus::Invalid => {
+                    return Err(ServiceError::OrderFailed(
+                        names.join(", "),
+                        format!("{:?}", order.state().error),
+                    ))
+                }
+                _ => continue,
+            }
+        }
+
+        if !matches!(order.state().status, OrderStatus::Ready) {
+            return Err(ServiceError::OrderTimedOut(names.join(", ")));
+        }
+
+        let mut parameters = CertificateParams::new(names.to_vec())?;
+        parameters.distinguished_name = DistinguishedName::new();
+
+        let private_key = KeyPair::generate()?;
+        let request = parameters.serialize_request(&private_key)?;
+
+        order.finalize(request.der()).await?;
+
+        let mut delay = Duration::from_millis(250);
+        for _ in 0..POLL_ATTEMPTS {
+            if let Some(chain) = order.certificate().await? {
+                return Ok((chain, private_key.serialize_pem()));
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(Duration::from_secs(10));
+        }
+
+        Err(ServiceError::OrderTimedOut(names.join(", ")))
+    }
+
+    /// Issues or renews certificate of the base domain.
+    /// Certificate is stored in upload folder.
+    #[tracing::instrument(skip(state, account))]
+    pub async fn renew_base_domain(
+        state: &State,
+        account: &Account,
+        base_domain: &str,
+    ) -> Result<ParsedCertificate, ServiceError> {
+        let folder = Self::folder(state);
+        let chain_path = folder.join(format!("{base_domain}.pem"));
+        let private_key_path = folder.join(format!("{base_domain}.key"));
+
+        if let Ok(parsed) = certificate::load(&chain_path, &private_key_path).await {
+            if !Self::is_due(parsed.not_after) {
+                return Ok(parsed);
+            }
+        }
+
+        tracing::info!(%base_domain, "Issuing certificate for base domain...");
+        let (chain, private_key) = Self::issue_for(account, base_domain, state.challenges()).await?;
+
+        let parsed = certificate::parse(&chain, &private_key)?;
+        fs::write(&chain_path, chain).await?;
+        fs::write(&private_key_path, private_key).await?;
+
+        tracing::info!(%base_domain, not_after = %parsed.not_after, "Certificate for base domain was issued");
+        Ok(parsed)
+    }
+
+    /// Checks certificates of base domains and verified custom domains
+    /// and issues new ones if they are missing or expire soon.
+    /// Failure of one domain does not stop others from being renewed.
+    #[tracing::instrument(skip(state, account))]
+    pub async fn renew(state: &State, account: &Account) {
+        //? HTTP-01 can not prove wildcard names so only
+        //? base domains themselves get certificates
+        for base_domain in state.configuration().base_domains() {
+            let base_domain = base_domain.trim_matches('.').to_ascii_lowercase();
+
+            match Self::renew_base_domain(state, account, &base_domain).await {
+                Ok(parsed) => state.certificates().insert(&base_domain, parsed.key),
+                Err(ServiceError::OrderIsPostponed(_)) => {
+                    tracing::debug!(%base_domain, "Certificate for base domain is not issued as it has failed recently")
+                }
+                Err(cause) => tracing::warn!(%cause, %base_domain, "Failed to renew certificate of base domain!"),
+            }
+        }
+
+        let domains = match DomainService::verified_domains(state.connection()).await {
+            Ok(domains) => domains,
+            Err(cause) => {
+                tracing::warn!(%cause, "Failed to retrieve verified domains!");
+                return;
+            }
+        };
+
+        for (domain, stored) in domains {
+            let is_due = match &stored {
+                //? Uploaded certificates are managed by the user
+                Some(stored) if !stored.managed => false,
+                Some(stored) => Self::is_due(stored.expires_at.with_timezone(&Utc)),
+                None => true,
+            };
+
+            if !is_due {
+                continue;
+            }
+
+            tracing::info!(%domain.name, "Issuing certificate for custom domain...");
+            let issued = match Self::issue_for(account, &domain.name, state.challenges()).await {
+                Ok(issued) => issued,
+                Err(ServiceError::OrderIsPostponed(_)) => {
+                    tracing::debug!(%domain.name, "Certificate for custom domain is not issued as it has failed recently");
+                    continue;
+                }
+                Err(cause) => {
+                    tracing::warn!(%cause, %domain.name, "Failed to issue certificate for custom domain!");
+                    continue;
+                }
+            };
+
+            let (chain, private_key) = issued;
+            match DomainService::set_certificate_of(
+                domain.subdomain_id,
+                domain.id,
+                chain,
+                private_key,
+                true,
+                state.connection(),
+            )
+            .await
+            {
+                Ok((domain, parsed)) => {
+                    tracing::info!(%domain.name, not_after = %parsed.not_after, "Certificate for custom domain was issued");
+                    state.certificates().insert(&domain.name, parsed.key);
+                }
+                Err(cause) => tracing::warn!(%cause, %domain.name, "Failed to store issued certificate!"),
+            }
+        }
+    }
+}
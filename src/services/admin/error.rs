@@ -0,0 +1,41 @@
+use crate::services::{
+    auth::error::ServiceError as AuthServiceError, session::error::ServiceError as SessionServiceError,
+    site::error::ServiceError as SiteServiceError,
+};
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error(transparent)]
+    AuthServiceError(#[from] AuthServiceError),
+    #[error(transparent)]
+    SessionServiceError(#[from] SessionServiceError),
+    #[error(transparent)]
+    SiteServiceError(#[from] SiteServiceError),
+    #[error("User with id = {0} was not found!")]
+    UserWasNotFound(i64),
+    #[error("Site with id = {0} was not found!")]
+    SiteWasNotFound(i64),
+    #[error("Administrators can not suspend or demote themselves!")]
+    OwnAccountCanNotBeRestricted,
+    #[error("Existing user does not match configured administrator password!")]
+    AdministratorPasswordMismatch,
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::AuthServiceError(error) => Self::from(error),
+            ServiceError::SessionServiceError(error) => Self::from(error),
+            ServiceError::SiteServiceError(error) => Self::from(error),
+            ServiceError::UserWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::SiteWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::OwnAccountCanNotBeRestricted => Self::BAD_REQUEST,
+            ServiceError::AdministratorPasswordMismatch => Self::FORBIDDEN,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Debug)]
+pub struct UpdateUserParameters {
+    //* None keeps current value
+    pub admin: Option<bool>,
+    pub suspended: Option<bool>,
+    //* Zero resets the override
+    pub max_sites: Option<u64>,
+}
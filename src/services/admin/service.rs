@@ -0,0 +1,173 @@
+use super::{error::ServiceError, parameters::UpdateUserParameters};
+use crate::services::{
+    auth::{error::ServiceError as AuthServiceError, service::Service as AuthService},
+    session::service::Service as SessionService,
+    site::{parameters::ActionParameters, service::Service as SiteService},
+};
+use argon2::password_hash;
+use chrono::Utc;
+use entity::prelude::*;
+use sea_orm::{prelude::*, sea_query::OnConflict, QueryOrder, Set, TransactionTrait};
+
+pub struct Service;
+
+impl Service {
+    /// Promotes user from configuration to administrator.
+    /// User is registered first if it does not exist and password is provided.
+    /// Existing user is promoted only if provided password matches its own
+    #[tracing::instrument(skip(password, connection))]
+    pub async fn bootstrap<C>(
+        login: &str,
+        password: Option<&str>,
+        connection: &C,
+    ) -> Result<Option<UserModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if let Some(password) = password {
+            let user_to_be_inserted = UserActiveModel {
+                login: Set(login.to_owned()),
+                password: Set(AuthService::hash_password(password)?),
+                ..Default::default()
+            };
+
+            //? Several instances may start at once
+            //? so existing user is kept as is
+            let rows_affected = UserEntity::insert(user_to_be_inserted)
+                .on_conflict(OnConflict::column(UserColumn::Login).do_nothing().to_owned())
+                .exec_without_returning(connection)
+                .await?;
+
+            //? Registration is public so the login could be taken by anyone
+            if rows_affected == 0 {
+                let existing = UserEntity::find()
+                    .filter(UserColumn::Login.eq(login))
+                    .one(connection)
+                    .await?;
+
+                if let Some(existing) = existing {
+                    match AuthService::verify_password(password, &existing.password) {
+                        Ok(()) => {}
+                        Err(AuthServiceError::PasswordHashError(password_hash::Error::Password)) => {
+                            tracing::warn!(%login, "Password of existing user does not match ADMIN_PASSWORD! It is not promoted");
+                            return Err(ServiceError::AdministratorPasswordMismatch);
+                        }
+                        Err(error) => return Err(error.into()),
+                    }
+                }
+            }
+        }
+
+        UserEntity::update_many()
+            .filter(UserColumn::Login.eq(login))
+            .col_expr(UserColumn::IsAdmin, Expr::value(true))
+            .exec(connection)
+            .await?;
+
+        Ok(UserEntity::find()
+            .filter(UserColumn::Login.eq(login))
+            .one(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_users<C>(connection: &C) -> Result<Vec<UserModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(UserEntity::find().order_by_asc(UserColumn::Id).all(connection).await?)
+    }
+
+    /// Sites of every user together with their owners
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_sites<C>(connection: &C) -> Result<Vec<(SubdomainModel, Option<UserModel>)>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(SubdomainEntity::find()
+            .find_also_related(UserEntity)
+            .order_by_asc(SubdomainColumn::Id)
+            .all(connection)
+            .await?)
+    }
+
+    /// Suspension revokes all sessions of the user.
+    /// Personal API tokens are kept but rejected while user is suspended
+    #[tracing::instrument(skip(connection))]
+    pub async fn update_user<C>(
+        admin_id: i64,
+        user_id: i64,
+        parameters: UpdateUserParameters,
+        connection: &C,
+    ) -> Result<UserModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if admin_id == user_id && (parameters.suspended == Some(true) || parameters.admin == Some(false)) {
+            return Err(ServiceError::OwnAccountCanNotBeRestricted);
+        }
+
+        let user = UserEntity::find_by_id(user_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::UserWasNotFound(user_id))?;
+
+        let is_suspended = user.suspended_at.is_some();
+        let mut active_user: UserActiveModel = user.into();
+
+        if let Some(admin) = parameters.admin {
+            active_user.is_admin = Set(admin);
+        }
+
+        match parameters.suspended {
+            Some(true) if !is_suspended => {
+                active_user.suspended_at = Set(Some(Utc::now().into()));
+                SessionService::revoke_sessions_of(user_id, connection).await?;
+            }
+            Some(false) => active_user.suspended_at = Set(None),
+            _ => {}
+        }
+
+        if let Some(max_sites) = parameters.max_sites {
+            active_user.max_sites = Set((max_sites != 0).then(|| i64::try_from(max_sites).unwrap_or(i64::MAX)));
+        }
+
+        Ok(active_user.update(connection).await?)
+    }
+
+    /// Blocked site is disabled and can not be enabled by its owner.
+    /// Unblocked site is enabled back
+    #[tracing::instrument(skip(connection))]
+    pub async fn block_site<C>(subdomain_id: i64, blocked: bool, connection: &C) -> Result<SubdomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SiteWasNotFound(subdomain_id))?;
+
+        let mut active_subdomain: SubdomainActiveModel = subdomain.into();
+
+        active_subdomain.blocked = Set(blocked);
+        active_subdomain.enabled = Set(!blocked);
+        active_subdomain.updated_at = Set(Utc::now().into());
+
+        Ok(active_subdomain.update(connection).await?)
+    }
+
+    /// Removes site of any user. Files are marked as obsolete.
+    /// Archives of the site must be removed by caller after commit
+    #[tracing::instrument(skip(connection))]
+    pub async fn teardown_site<C>(subdomain_id: i64, connection: &C) -> Result<(u64, Vec<String>), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SiteWasNotFound(subdomain_id))?;
+
+        Ok(SiteService::teardown(ActionParameters { subdomain_id }, connection).await?)
+    }
+}
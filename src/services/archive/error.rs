@@ -4,6 +4,12 @@ use axum::http::StatusCode;
 pub enum ServiceError {
     #[error("Zip archive is empty!")]
     EmptyArchive,
+    #[error("Invalid _headers file at line {0}: {1}")]
+    InvalidHeadersFile(usize, String),
+    #[error("Invalid _redirects file at line {0}: {1}")]
+    InvalidRedirectsFile(usize, String),
+    #[error("Invalid sero.toml file: {0}")]
+    InvalidConfigurationFile(#[from] toml::de::Error),
     #[error("Subdomain with id = {0} was not found!")]
     SubdomainWasNotFound(i64),
     #[error(transparent)]
@@ -18,6 +24,9 @@ impl From<ServiceError> for StatusCode {
     fn from(value: ServiceError) -> Self {
         match value {
             ServiceError::EmptyArchive => Self::BAD_REQUEST,
+            ServiceError::InvalidHeadersFile(..) => Self::BAD_REQUEST,
+            ServiceError::InvalidRedirectsFile(..) => Self::BAD_REQUEST,
+            ServiceError::InvalidConfigurationFile(_) => Self::BAD_REQUEST,
             ServiceError::SubdomainWasNotFound(_) => Self::NOT_FOUND,
             ServiceError::ZipError(_) => Self::BAD_REQUEST,
             ServiceError::FileSystemError(_) => Self::INTERNAL_SERVER_ERROR,
@@ -1,4 +1,5 @@
 pub mod error;
 pub mod models;
 pub mod parameters;
+pub mod rules;
 pub mod service;
@@ -1,7 +1,56 @@
+use crate::{http::encoding::Encoding, site::models::TrailingSlash};
+use serde::Deserialize;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
 pub struct ArchiveFile {
     pub real_path: PathBuf,
     pub user_path: PathBuf,
+    pub content_type: String,
+    pub hash: String,
+    //* Size in bytes as stored on disk
+    pub size: usize,
+    //* None means identity
+    pub encoding: Option<Encoding>,
+}
+
+/// Header which is set on responses for paths matching pattern
+#[derive(Clone, Debug)]
+pub struct HeaderRule {
+    pub pattern: String,
+    pub name: String,
+    pub value: String,
+}
+
+/// Redirect or rewrite of paths matching source pattern
+#[derive(Clone, Debug)]
+pub struct RedirectRule {
+    pub source: String,
+    //* Conditions joined with `&` like `id=:id&page=1`
+    pub query: Option<String>,
+    pub destination: String,
+    pub status: u16,
+    //* Forced rule is applied even if requested file exists
+    pub force: bool,
+}
+
+/// Site settings from `sero.toml` in the root of archive.
+/// Omitted settings keep their current values
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchiveConfiguration {
+    /// Serve index.html for unknown extension-less paths
+    pub spa: Option<bool>,
+    /// Canonical form of pages resolved as `path.html` or `path/index.html`
+    pub trailing_slash: Option<TrailingSlash>,
+}
+
+/// Result of archive processing.
+/// Service files like `_headers`, `_redirects` and `sero.toml` are parsed instead of being served
+#[derive(Clone, Debug, Default)]
+pub struct ArchiveContents {
+    pub files: Vec<ArchiveFile>,
+    pub headers: Vec<HeaderRule>,
+    pub redirects: Vec<RedirectRule>,
+    pub configuration: ArchiveConfiguration,
 }
@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use std::path::Path;
 
 pub struct UploadParameters<T>
@@ -8,4 +9,10 @@ where
     pub subdomain_id: i64,
     pub contents: Bytes,
     pub upload_folder: T,
+    //* Some means that deployment is a preview
+    //* which is not served on the site until promoted
+    pub expires_at: Option<DateTime<Utc>>,
+    //* Total size of files which are precompressed on upload
+    //* The rest of files are served as is
+    pub precompress_limit: usize,
 }
@@ -0,0 +1,181 @@
+use super::{
+    error::ServiceError,
+    models::{HeaderRule, RedirectRule},
+};
+use crate::http::pattern::Pattern;
+use axum::http::{header, HeaderName, HeaderValue, StatusCode};
+
+/// Parses Netlify-style `_headers` file
+///
+/// ```text
+/// # Comment
+/// /assets/*
+///   Cache-Control: public, max-age=31536000
+///   X-Frame-Options: DENY
+/// ```
+///
+/// Every line which is not indented starts a new path pattern.
+/// Indented lines are headers applied to the paths matching the pattern.
+/// Headers which describe framing or encoding of the body are rejected.
+pub fn headers<T>(contents: T) -> Result<Vec<HeaderRule>, ServiceError>
+where
+    T: AsRef<str>,
+{
+    let mut rules = vec![];
+    let mut pattern: Option<&str> = None;
+
+    for (index, line) in contents.as_ref().lines().enumerate() {
+        let number = index + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            trimmed
+                .parse::<Pattern>()
+                .map_err(|cause| ServiceError::InvalidHeadersFile(number, cause.to_string()))?;
+
+            pattern = Some(trimmed);
+            continue;
+        }
+
+        let pattern = pattern
+            .ok_or_else(|| ServiceError::InvalidHeadersFile(number, String::from("Header must follow path pattern")))?;
+
+        let (name, value) = trimmed.split_once(':').ok_or_else(|| {
+            ServiceError::InvalidHeadersFile(number, String::from("Header must be in `Name: value` format"))
+        })?;
+
+        let (name, value) = (name.trim(), value.trim());
+
+        //? Validated here so serving never fails on invalid rule
+        let header_name =
+            HeaderName::try_from(name).map_err(|cause| ServiceError::InvalidHeadersFile(number, cause.to_string()))?;
+        if !is_overridable(&header_name) {
+            return Err(ServiceError::InvalidHeadersFile(
+                number,
+                format!("{header_name} is set by server and can not be overridden"),
+            ));
+        }
+        HeaderValue::try_from(value).map_err(|cause| ServiceError::InvalidHeadersFile(number, cause.to_string()))?;
+
+        rules.push(HeaderRule {
+            pattern: pattern.to_owned(),
+            name: name.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+
+    Ok(rules)
+}
+
+/// Checks if header can be set by `_headers` rule.
+/// Framing, encoding and hop-by-hop headers are set by server
+/// and overriding them would corrupt the response.
+pub fn is_overridable(name: &HeaderName) -> bool {
+    let reserved = [
+        header::CONTENT_LENGTH,
+        header::CONTENT_ENCODING,
+        header::CONTENT_RANGE,
+        header::CONTENT_TYPE,
+        header::TRANSFER_ENCODING,
+        header::CONNECTION,
+        header::TE,
+        header::TRAILER,
+        header::UPGRADE,
+        header::PROXY_AUTHENTICATE,
+        header::PROXY_AUTHORIZATION,
+    ];
+
+    !reserved.contains(name) && name.as_str() != "keep-alive"
+}
+
+/// Status codes which can be used in `_redirects` file.
+/// 200 and 404 rewrite the path while others redirect.
+const REDIRECT_STATUSES: [u16; 7] = [200, 301, 302, 303, 307, 308, 404];
+
+/// Parses Netlify-style `_redirects` file
+///
+/// ```text
+/// # from                 [query]      to                  [status]
+/// /blog/*                             /posts/:splat       301
+/// /store                 id=:id       /products/:id       302
+/// /app/*                              /app/index.html     200
+/// ```
+///
+/// Status defaults to 301. Rules are applied only if requested file
+/// does not exist unless status ends with `!` which forces the rule.
+pub fn redirects<T>(contents: T) -> Result<Vec<RedirectRule>, ServiceError>
+where
+    T: AsRef<str>,
+{
+    let mut rules = vec![];
+
+    for (index, line) in contents.as_ref().lines().enumerate() {
+        let number = index + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let invalid = |reason: &str| ServiceError::InvalidRedirectsFile(number, reason.to_owned());
+
+        let mut tokens = trimmed.split_whitespace().peekable();
+
+        let source = tokens.next().ok_or_else(|| invalid("Source is missing"))?;
+        source
+            .parse::<Pattern>()
+            .map_err(|cause| ServiceError::InvalidRedirectsFile(number, cause.to_string()))?;
+
+        let mut query = vec![];
+        while let Some(condition) = tokens.next_if(|token| !token.starts_with('/') && !token.contains("://")) {
+            let (key, value) = condition
+                .split_once('=')
+                .ok_or_else(|| invalid("Query condition must be in `key=value` format"))?;
+            query.push(format!("{key}={value}"));
+        }
+
+        let destination = tokens.next().ok_or_else(|| invalid("Destination is missing"))?;
+
+        let (status, force) = match tokens.next() {
+            Some(status) => {
+                let force = status.ends_with('!');
+                let status = status
+                    .trim_end_matches('!')
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|status| REDIRECT_STATUSES.contains(status))
+                    .ok_or_else(|| invalid("Unsupported status code"))?;
+                (status, force)
+            }
+            None => (StatusCode::MOVED_PERMANENTLY.as_u16(), false),
+        };
+
+        if tokens.next().is_some() {
+            return Err(invalid("Unexpected tokens after status code"));
+        }
+
+        //? Rewrites are served from the same site
+        //? Proxying to other hosts is not supported
+        let is_rewrite = matches!(status, 200 | 404);
+        if is_rewrite && !destination.starts_with('/') {
+            return Err(invalid("Rewrite destination must be a path"));
+        }
+
+        rules.push(RedirectRule {
+            source: source.to_owned(),
+            query: match query.is_empty() {
+                true => None,
+                false => Some(query.join("&")),
+            },
+            destination: destination.to_owned(),
+            status,
+            force,
+        });
+    }
+
+    Ok(rules)
+}
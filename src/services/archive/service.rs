@@ -1,17 +1,88 @@
-use super::{error::ServiceError, models::*, parameters::UploadParameters};
+use super::{error::ServiceError, models::*, parameters::UploadParameters, rules};
+use crate::http::encoding::{self, Encoding};
 use async_zip::base::read::seek::ZipFileReader;
+use chrono::Utc;
 use entity::prelude::*;
 use sea_orm::{prelude::*, Set, TransactionTrait};
+use sha2::{Digest, Sha256};
 use std::{
+    collections::HashSet,
     io::Cursor,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 use tokio::{fs, fs::File, io::AsyncWriteExt as _};
 
+//? Compression overhead does not pay off for tiny files
+const MIN_COMPRESSIBLE_SIZE: usize = 1024;
+
+//? Configures response headers per path and is never served
+const HEADERS_FILE: &str = "_headers";
+
+//? Configures redirects and rewrites and is never served
+const REDIRECTS_FILE: &str = "_redirects";
+
+//? Configures site settings and is never served
+const CONFIGURATION_FILE: &str = "sero.toml";
+
 pub struct Service;
 
 impl Service {
-    async fn process<U, B>(contents: B, upload_folder: U) -> Result<Vec<ArchiveFile>, ServiceError>
+    /// Guesses `Content-Type` from the extension of the path.
+    /// Textual types are served with utf-8 charset
+    /// as browsers will otherwise try to sniff encoding.
+    pub fn content_type<P>(path: P) -> String
+    where
+        P: AsRef<Path>,
+    {
+        let guessed = mime_guess::from_path(path).first_or_octet_stream();
+
+        let is_textual = guessed.type_() == mime::TEXT
+            || matches!(
+                guessed.essence_str(),
+                "application/javascript" | "application/json" | "application/xml" | "image/svg+xml"
+            );
+
+        match is_textual && guessed.get_param(mime::CHARSET).is_none() {
+            true => format!("{}; charset=utf-8", guessed.essence_str()),
+            false => guessed.to_string(),
+        }
+    }
+
+    //? Writes contents to a file with random name
+    //? to prevent collisions inside of upload folder
+    async fn save<U, B>(contents: B, upload_folder: U) -> Result<PathBuf, ServiceError>
+    where
+        U: AsRef<Path>,
+        B: AsRef<[u8]>,
+    {
+        let u1 = Uuid::new_v4();
+        let u2 = Uuid::new_v4();
+
+        let upload_folder_display = upload_folder.as_ref().display();
+
+        let filename_to_save = PathBuf::from(format!("{upload_folder_display}/{u1}{u2}"));
+        tracing::trace!(?filename_to_save, "Filename for saving entry was generated!");
+
+        //? Creating file
+        tracing::trace!(?filename_to_save, "Creating file...");
+        let mut out = File::create(&filename_to_save)
+            .await
+            .inspect_err(|cause| tracing::error!(%cause, ?filename_to_save, "Failed to create file for entry"))?;
+
+        tracing::trace!(?filename_to_save, "Writing contents to new file...");
+        out.write_all(contents.as_ref())
+            .await
+            .inspect_err(|cause| tracing::error!(?filename_to_save, %cause, "Failed to write entry contents!"))?;
+
+        Ok(filename_to_save)
+    }
+
+    async fn process<U, B>(
+        contents: B,
+        upload_folder: U,
+        precompress_limit: usize,
+    ) -> Result<ArchiveContents, ServiceError>
     where
         U: AsRef<Path>,
         B: AsRef<[u8]>,
@@ -33,7 +104,18 @@ impl Service {
             .collect::<Vec<_>>();
         tracing::trace!(amount = entries.len(), "Found files in zip!");
 
-        let mut paths = vec![];
+        //? Names are needed to detect precompressed siblings
+        //? like `app.js.gz` shipped together with `app.js`
+        let names = entries
+            .iter()
+            .filter_map(|(_, entry)| entry.filename().as_str().ok().map(PathBuf::from))
+            .collect::<HashSet<_>>();
+
+        let mut processed = ArchiveContents::default();
+
+        //? Compression is the most expensive part of upload
+        //? so amount of work a single upload can trigger is capped
+        let mut precompress_budget = precompress_limit;
 
         for (index, entry) in entries {
             tracing::trace!(%index, "Processing entry...");
@@ -48,23 +130,7 @@ impl Service {
             };
             tracing::trace!(?path, "Entry filepath was successfully retrieved");
 
-            //? Generating filename for enty
-            // Just random to prevent collisions
-            let u1 = Uuid::new_v4();
-            let u2 = Uuid::new_v4();
-
-            let upload_folder_display = upload_folder.as_ref().display();
-
-            let filename_to_save = PathBuf::from(format!("{upload_folder_display}/{u1}{u2}"));
-            tracing::trace!(?filename_to_save, "Filename for saving entry was generated!");
-
-            //? Creating file
-            tracing::trace!(?filename_to_save, "Creating file...");
-            let mut out = File::create(&filename_to_save)
-                .await
-                .inspect_err(|cause| tracing::error!(%cause, ?filename_to_save,"Failed to create file for entry"))?;
-
-            //? Writing entry contents to file
+            //? Reading entry contents
             let mut bytes = vec![];
             let mut reader = zip
                 .reader_with_entry(index)
@@ -77,24 +143,114 @@ impl Service {
                 .await
                 .inspect_err(|cause| tracing::error!(%cause, %index, "Failed to read entry to the end"))?;
 
-            tracing::trace!(%index, ?filename_to_save, "Writing entry to new file...");
-            out.write_all(&bytes).await.inspect_err(
-                |cause| tracing::error!(%index, ?filename_to_save, %cause, "Failed to write entry contents!"),
-            )?;
-
+            let bytes: Arc<[u8]> = Arc::from(bytes);
+
+            if path == Path::new(HEADERS_FILE) {
+                tracing::trace!(%index, "Parsing headers file...");
+                processed.headers = rules::headers(String::from_utf8_lossy(&bytes))?;
+                tracing::trace!(amount = processed.headers.len(), "Header rules were parsed!");
+                continue;
+            }
+
+            if path == Path::new(REDIRECTS_FILE) {
+                tracing::trace!(%index, "Parsing redirects file...");
+                processed.redirects = rules::redirects(String::from_utf8_lossy(&bytes))?;
+                tracing::trace!(amount = processed.redirects.len(), "Redirect rules were parsed!");
+                continue;
+            }
+
+            if path == Path::new(CONFIGURATION_FILE) {
+                tracing::trace!(%index, "Parsing configuration file...");
+                processed.configuration = toml::from_str(&String::from_utf8_lossy(&bytes))?;
+                tracing::trace!(configuration = ?processed.configuration, "Configuration was parsed!");
+                continue;
+            }
+
+            let filename_to_save = Self::save(&*bytes, &upload_folder).await?;
             tracing::trace!(%index, ?filename_to_save, "Entry was successfully written!");
 
-            paths.push(ArchiveFile {
+            //? Precompressed file becomes a variant of its original
+            //? and is not served on its own path
+            let precompressed = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .and_then(Encoding::from_extension)
+                .filter(|_| names.contains(&path.with_extension("")));
+
+            let user_path = match precompressed {
+                Some(_) => path.with_extension(""),
+                None => path,
+            };
+
+            let content_type = Self::content_type(&user_path);
+            tracing::trace!(%index, %content_type, "Content type was guessed for entry");
+
+            //? Content hash is used as ETag on serving
+            let hash = format!("{:x}", Sha256::digest(&*bytes));
+            tracing::trace!(%index, %hash, "Entry contents were hashed");
+
+            let is_compressible = precompressed.is_none()
+                && bytes.len() >= MIN_COMPRESSIBLE_SIZE
+                && encoding::is_compressible(&content_type);
+
+            if is_compressible && bytes.len() > precompress_budget {
+                tracing::trace!(%index, size = bytes.len(), %precompress_budget, "Precompression limit was reached. Skipping...");
+            }
+
+            if is_compressible && bytes.len() <= precompress_budget {
+                precompress_budget -= bytes.len();
+
+                for encoding in Encoding::ALL {
+                    let shipped = {
+                        let mut shipped = user_path.clone().into_os_string();
+                        shipped.push(".");
+                        shipped.push(encoding.extension());
+                        names.contains(&PathBuf::from(shipped))
+                    };
+
+                    if shipped {
+                        tracing::trace!(%index, encoding = encoding.as_str(), "Precompressed variant is shipped in archive");
+                        continue;
+                    }
+
+                    let compressed = encoding.compress(Arc::clone(&bytes)).await?;
+
+                    //? There is no reason to serve variant
+                    //? which does not save anything
+                    if compressed.len() >= bytes.len() {
+                        tracing::trace!(%index, encoding = encoding.as_str(), "Compressed variant is not smaller. Skipping...");
+                        continue;
+                    }
+
+                    let variant_filename = Self::save(&compressed, &upload_folder).await?;
+                    tracing::trace!(%index, encoding = encoding.as_str(), ?variant_filename, "Compressed variant was written!");
+
+                    processed.files.push(ArchiveFile {
+                        real_path: variant_filename,
+                        user_path: user_path.clone(),
+                        content_type: content_type.clone(),
+                        hash: format!("{:x}", Sha256::digest(&compressed)),
+                        size: compressed.len(),
+                        encoding: Some(encoding),
+                    });
+                }
+            }
+
+            processed.files.push(ArchiveFile {
                 real_path: filename_to_save,
-                user_path: path,
+                user_path,
+                content_type,
+                hash,
+                size: bytes.len(),
+                encoding: precompressed,
             });
         }
 
-        Ok(paths)
+        Ok(processed)
     }
 
     #[tracing::instrument(skip(connection, parameters))]
-    pub async fn upload<C, P, T>(parameters: P, connection: &C) -> Result<(), ServiceError>
+    pub async fn upload<C, P, T>(parameters: P, connection: &C) -> Result<DeploymentModel, ServiceError>
     where
         C: ConnectionTrait + TransactionTrait,
         P: Into<UploadParameters<T>>,
@@ -111,31 +267,24 @@ impl Service {
             None => Err(ServiceError::SubdomainWasNotFound(provided_parameters.subdomain_id)),
         }?;
 
-        //? Mark files for removal
-        //? The actual deletion will also be handled
-        tracing::trace!("Marking old files as obsolete...");
-        let rows_affected = FileEntity::update_many()
-            .filter(FileColumn::SubdomainId.eq(subdomain.id))
-            .col_expr(FileColumn::Obsolete, Expr::value(true))
-            .exec(connection)
-            .await?
-            .rows_affected;
-        tracing::trace!(%rows_affected, "Files were successfully marked as obsolete!");
-
-        //? Removing all cors rows related to subdomain
-        tracing::trace!("Removing all related origins...");
-        let rows_affected = OriginEntity::delete_many()
-            .filter(OriginColumn::SubdomainId.eq(Some(subdomain.id)))
-            .exec(connection)
-            .await?
-            .rows_affected;
-        tracing::trace!(%rows_affected, "Origins were successfully removed!");
+        //? Previous deployments are kept for rollback
+        //? and removed once they do not fit into retention limit
+        tracing::trace!("Creating deployment...");
+        let deployment = DeploymentEntity::insert(DeploymentActiveModel {
+            subdomain_id: Set(subdomain.id),
+            expires_at: Set(provided_parameters.expires_at.map(Into::into)),
+            ..Default::default()
+        })
+        .exec_with_returning(connection)
+        .await?;
+        tracing::trace!(%deployment.id, "Deployment was successfully created!");
 
         //? Writing new archive
         let new_archive_path = {
             let upload_folder_display = provided_parameters.upload_folder.as_ref().display();
             let subdomain_id = subdomain.id;
-            format!("{upload_folder_display}/{subdomain_id}.zip")
+            let deployment_id = deployment.id;
+            format!("{upload_folder_display}/{subdomain_id}-{deployment_id}.zip")
         };
         tracing::trace!("Archive will be written to {new_archive_path}");
 
@@ -171,30 +320,111 @@ impl Service {
         //TODO Possible solutions: stream or upload file one by one (this will be braking change)
         //? Processing all files
         tracing::trace!("Processing files from archive...");
-        let files_to_be_inserted = Self::process(&provided_parameters.contents, files_upload_folder).await?;
+        let processed = Self::process(
+            &provided_parameters.contents,
+            files_upload_folder,
+            provided_parameters.precompress_limit,
+        )
+        .await?;
+
+        tracing::trace!(amount = processed.files.len(), "Files were successfully processed!");
+
+        //? Archive with service files only has nothing to serve
+        if processed.files.is_empty() {
+            return Err(ServiceError::EmptyArchive);
+        }
 
-        tracing::trace!(
-            amount = files_to_be_inserted.len(),
-            "Files were successfully processed!"
-        );
         //? Saving files
-        let models = files_to_be_inserted.iter().map(|file| FileActiveModel {
+        let models = processed.files.iter().map(|file| FileActiveModel {
             subdomain_id: Set(Some(subdomain.id)),
+            deployment_id: Set(Some(deployment.id)),
             user_path: Set(file.user_path.display().to_string()),
             real_path: Set(file.real_path.display().to_string()),
+            content_type: Set(Some(file.content_type.clone())),
+            hash: Set(Some(file.hash.clone())),
+            size: Set(Some(i64::try_from(file.size).unwrap_or(i64::MAX))),
+            encoding: Set(file.encoding.map(|encoding| encoding.as_str().to_owned())),
             ..Default::default()
         });
 
         tracing::trace!("Saving paths to database...");
         FileEntity::insert_many(models).exec(connection).await?;
 
+        if !processed.headers.is_empty() {
+            let rules = processed.headers.iter().map(|rule| HeaderActiveModel {
+                subdomain_id: Set(subdomain.id),
+                deployment_id: Set(Some(deployment.id)),
+                pattern: Set(rule.pattern.clone()),
+                name: Set(rule.name.clone()),
+                value: Set(rule.value.clone()),
+                ..Default::default()
+            });
+
+            HeaderEntity::insert_many(rules).exec(connection).await?;
+            tracing::trace!(amount = processed.headers.len(), "Header rules were saved!");
+        }
+
+        if !processed.redirects.is_empty() {
+            let rules = processed.redirects.iter().map(|rule| RedirectActiveModel {
+                subdomain_id: Set(subdomain.id),
+                deployment_id: Set(Some(deployment.id)),
+                source: Set(rule.source.clone()),
+                query: Set(rule.query.clone()),
+                destination: Set(rule.destination.clone()),
+                status: Set(i32::from(rule.status)),
+                force: Set(rule.force),
+                ..Default::default()
+            });
+
+            RedirectEntity::insert_many(rules).exec(connection).await?;
+            tracing::trace!(amount = processed.redirects.len(), "Redirect rules were saved!");
+        }
+
+        tracing::trace!("Updating deployment in database...");
+        let mut active_deployment: DeploymentActiveModel = deployment.into();
+        active_deployment.archive_path = Set(Some(new_archive_path.clone()));
+        active_deployment.spa = Set(processed.configuration.spa);
+        active_deployment.trailing_slash = Set(processed
+            .configuration
+            .trailing_slash
+            .map(|trailing_slash| trailing_slash.as_str().to_owned()));
+        let deployment = active_deployment.update(connection).await?;
+
+        //? Preview is served on its own name only
+        if deployment.expires_at.is_some() {
+            tracing::trace!(%deployment.id, "Preview was successfully created!");
+            return Ok(deployment);
+        }
+
+        //? Removing all cors rows related to subdomain
+        tracing::trace!("Removing all related origins...");
+        let rows_affected = OriginEntity::delete_many()
+            .filter(OriginColumn::SubdomainId.eq(Some(subdomain.id)))
+            .exec(connection)
+            .await?
+            .rows_affected;
+        tracing::trace!(%rows_affected, "Origins were successfully removed!");
+
         tracing::trace!("Updating archive path in database...");
         //? Updating subdomain with new archive
+        //? New deployment becomes active at once
         let mut active: SubdomainActiveModel = subdomain.clone().into();
+        active.active_deployment_id = Set(Some(deployment.id));
         active.archive_path = Set(Some(new_archive_path));
+        active.updated_at = Set(Utc::now().into());
+        active.last_deployed_at = Set(Some(Utc::now().into()));
+        active.activated_at = Set(Some(Utc::now().into()));
+
+        if let Some(spa) = processed.configuration.spa {
+            active.spa = Set(spa);
+        }
+
+        if let Some(trailing_slash) = processed.configuration.trailing_slash {
+            active.trailing_slash = Set(trailing_slash.as_str().to_owned());
+        }
 
         active.update(connection).await?;
 
-        Ok(())
+        Ok(deployment)
     }
 }
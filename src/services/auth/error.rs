@@ -1,5 +1,6 @@
 use argon2::password_hash;
 use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
 use sea_orm::prelude::*;
 use std::fmt::Debug;
 
@@ -15,6 +16,14 @@ pub enum ServiceError {
     LoginOccupied,
     #[error("User was not found by login.")]
     UserWasNotFound,
+    #[error("Password does not match.")]
+    InvalidPassword,
+    #[error("Too many failed login attempts! Login is locked until {0}.")]
+    LoginIsLocked(DateTime<Utc>),
+    #[error("Token was signed with unknown key.")]
+    KeyWasNotFound,
+    #[error("User is suspended.")]
+    UserIsSuspended,
 }
 
 impl From<ServiceError> for StatusCode {
@@ -25,6 +34,10 @@ impl From<ServiceError> for StatusCode {
             ServiceError::PasswordHashError(_error) => Self::INTERNAL_SERVER_ERROR,
             ServiceError::LoginOccupied => Self::CONFLICT,
             ServiceError::UserWasNotFound => Self::NOT_FOUND,
+            ServiceError::InvalidPassword => Self::BAD_REQUEST,
+            ServiceError::LoginIsLocked(_) => Self::TOO_MANY_REQUESTS,
+            ServiceError::KeyWasNotFound => Self::UNAUTHORIZED,
+            ServiceError::UserIsSuspended => Self::FORBIDDEN,
         }
     }
 }
@@ -0,0 +1,209 @@
+use crate::Configuration;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use rcgen::KeyPair;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Debug;
+use utoipa::ToSchema;
+use x509_parser::{
+    oid_registry::OID_SIG_ED25519, pem::parse_x509_pem, prelude::FromDer, public_key::PublicKey,
+    x509::SubjectPublicKeyInfo,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum KeyError {
+    #[error("Failed to read key: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Failed to parse PEM: {0}")]
+    PemError(String),
+    #[error("Failed to parse key: {0}")]
+    InvalidKey(String),
+    #[error(transparent)]
+    JwtError(#[from] jsonwebtoken::errors::Error),
+    #[error("Only Ed25519 and RSA keys are supported!")]
+    UnsupportedKey,
+}
+
+/// Public key in JWK format as described in RFC 7517
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct Jwk {
+    pub kty: String,
+    #[serde(rename = "use")]
+    pub usage: String,
+    pub alg: String,
+    pub kid: String,
+    //* Ed25519 keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    //* RSA keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+}
+
+pub struct VerificationKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub key: DecodingKey,
+    pub jwk: Jwk,
+}
+
+pub struct SigningKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub key: EncodingKey,
+}
+
+/// Asymmetric keys of JWTs.
+/// Without signing key tokens are signed with HS256 and `JWT_SECRET`
+#[derive(Default)]
+pub struct JwtKeys {
+    signing: Option<SigningKey>,
+    //* Public key of signing key goes first
+    verification: Vec<VerificationKey>,
+}
+
+impl Debug for JwtKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeys")
+            .field("signing", &self.signing.as_ref().map(|key| &key.kid))
+            .field(
+                "verification",
+                &self.verification.iter().map(|key| &key.kid).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl JwtKeys {
+    /// Reads keys from files set in configuration
+    pub async fn load(configuration: &Configuration) -> Result<Self, KeyError> {
+        let signing = match configuration.jwt_signing_key() {
+            Some(path) => Some(tokio::fs::read(path).await?),
+            None => None,
+        };
+
+        let mut verification = Vec::new();
+        for path in configuration.jwt_verification_keys() {
+            verification.push(tokio::fs::read(path).await?);
+        }
+
+        Self::parse(signing, &verification)
+    }
+
+    /// Parses PKCS#8 signing key and public verification keys
+    pub fn parse<S, V>(signing: Option<S>, verification: &[V]) -> Result<Self, KeyError>
+    where
+        S: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        let mut keys = Self::default();
+
+        if let Some(signing) = signing {
+            let pem = std::str::from_utf8(signing.as_ref()).map_err(|cause| KeyError::PemError(cause.to_string()))?;
+            let pair = KeyPair::from_pem(pem).map_err(|cause| KeyError::InvalidKey(cause.to_string()))?;
+
+            let public = Self::verification_key(&pair.public_key_der())?;
+            let key = match public.algorithm {
+                Algorithm::EdDSA => EncodingKey::from_ed_pem(pem.as_bytes())?,
+                _ => EncodingKey::from_rsa_pem(pem.as_bytes())?,
+            };
+
+            keys.signing = Some(SigningKey {
+                kid: public.kid.clone(),
+                algorithm: public.algorithm,
+                key,
+            });
+            keys.verification.push(public);
+        }
+
+        for pem in verification {
+            let (_, pem) = parse_x509_pem(pem.as_ref()).map_err(|cause| KeyError::PemError(cause.to_string()))?;
+            let public = Self::verification_key(&pem.contents)?;
+
+            //? Signing key may be listed among verification keys during rotation
+            if keys.find(&public.kid).is_none() {
+                keys.verification.push(public);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    pub fn signing(&self) -> Option<&SigningKey> {
+        self.signing.as_ref()
+    }
+
+    pub fn find<T>(&self, kid: T) -> Option<&VerificationKey>
+    where
+        T: AsRef<str>,
+    {
+        self.verification.iter().find(|key| key.kid == kid.as_ref())
+    }
+
+    /// Public keys published at `/.well-known/jwks.json`
+    pub fn jwks(&self) -> Vec<Jwk> {
+        self.verification.iter().map(|key| key.jwk.clone()).collect()
+    }
+
+    /// Builds verification key from DER encoded SubjectPublicKeyInfo
+    /// Key id is derived from the public key so it is stable across restarts
+    fn verification_key(der: &[u8]) -> Result<VerificationKey, KeyError> {
+        let (_, info) = SubjectPublicKeyInfo::from_der(der).map_err(|cause| KeyError::InvalidKey(cause.to_string()))?;
+        let kid = URL_SAFE_NO_PAD.encode(&Sha256::digest(der)[..12]);
+
+        if info.algorithm.algorithm == OID_SIG_ED25519 {
+            let raw = info.subject_public_key.data.as_ref();
+
+            return Ok(VerificationKey {
+                algorithm: Algorithm::EdDSA,
+                key: DecodingKey::from_ed_der(raw),
+                jwk: Jwk {
+                    kty: "OKP".to_owned(),
+                    usage: "sig".to_owned(),
+                    alg: "EdDSA".to_owned(),
+                    kid: kid.clone(),
+                    crv: Some("Ed25519".to_owned()),
+                    x: Some(URL_SAFE_NO_PAD.encode(raw)),
+                    n: None,
+                    e: None,
+                },
+                kid,
+            });
+        }
+
+        match info.parsed() {
+            Ok(PublicKey::RSA(rsa)) => {
+                //? DER integers may carry leading zero which is not allowed in JWK
+                let modulus = Self::unsigned(rsa.modulus);
+                let exponent = Self::unsigned(rsa.exponent);
+
+                Ok(VerificationKey {
+                    algorithm: Algorithm::RS256,
+                    key: DecodingKey::from_rsa_raw_components(modulus, exponent),
+                    jwk: Jwk {
+                        kty: "RSA".to_owned(),
+                        usage: "sig".to_owned(),
+                        alg: "RS256".to_owned(),
+                        kid: kid.clone(),
+                        crv: None,
+                        x: None,
+                        n: Some(URL_SAFE_NO_PAD.encode(modulus)),
+                        e: Some(URL_SAFE_NO_PAD.encode(exponent)),
+                    },
+                    kid,
+                })
+            }
+            _ => Err(KeyError::UnsupportedKey),
+        }
+    }
+
+    fn unsigned(integer: &[u8]) -> &[u8] {
+        let start = integer.iter().position(|byte| *byte != 0).unwrap_or(integer.len());
+        &integer[start..]
+    }
+}
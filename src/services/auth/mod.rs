@@ -1,3 +1,4 @@
 pub mod error;
+pub mod keys;
 pub mod parameters;
 pub mod service;
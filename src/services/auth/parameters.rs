@@ -1,11 +1,15 @@
+use super::keys::JwtKeys;
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
-pub struct JwtGenerationParameters<T>
+pub struct JwtGenerationParameters<'k, T>
 where
     T: AsRef<str>,
 {
     pub secret: T,
     pub ttl: i64,
+    /// Secret is used only if there is no signing key
+    pub keys: &'k JwtKeys,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -13,6 +17,11 @@ pub struct TokenClaims {
     pub sub: i64,
     pub iat: i64,
     pub exp: i64,
+    /// Session the token was issued for.
+    /// Tokens issued before sessions were introduced do not have it
+    /// and are rejected as they can not be revoked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<i64>,
 }
 
 pub struct UserCredentials<T>
@@ -23,10 +32,22 @@ where
     pub password: T,
 }
 
-pub struct JwtDecodingParameters<T>
+pub struct JwtDecodingParameters<'k, T>
 where
     T: AsRef<str>,
 {
     pub token: T,
+    /// Tokens without key id are verified with secret
     pub secret: T,
+    pub keys: &'k JwtKeys,
+}
+
+pub struct LockoutParameters {
+    /// Failures from unknown address are counted only per login
+    pub address: Option<IpAddr>,
+    /// Thresholds of failures. Zero disables lockout
+    pub attempts_per_login: u32,
+    pub attempts_per_address: u32,
+    /// Duration of the first lockout which is doubled with every next failure
+    pub lockout_seconds: i64,
 }
@@ -1,18 +1,36 @@
 use super::{error::ServiceError, parameters::*};
 use argon2::{
-    password_hash::{rand_core::OsRng, SaltString},
+    password_hash::{self, rand_core::OsRng, SaltString},
     Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
 };
 use chrono::{prelude::*, Duration};
 use entity::prelude::*;
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
-use sea_orm::{prelude::*, Set, TransactionTrait};
+use sea_orm::{
+    prelude::*,
+    sea_query::{Expr, OnConflict},
+    Condition, Set, TransactionTrait,
+};
+
+/// Failures are forgotten if the last one was earlier
+const ATTEMPTS_TTL_SECONDS: i64 = 24 * 60 * 60;
+/// Upper bound of doubled lockout
+const MAX_LOCKOUT_SECONDS: i64 = 24 * 60 * 60;
+
+const LOGIN_ATTEMPT: &str = "login";
+const ADDRESS_ATTEMPT: &str = "address";
+/// Failed Basic authentication on a site counted per site and client
+pub const SITE_ATTEMPT: &str = "site";
 
 pub struct Service;
 
 impl Service {
     #[tracing::instrument(skip(parameters))]
-    pub fn generate_jwt<T>(user_id: i64, parameters: JwtGenerationParameters<T>) -> Result<String, ServiceError>
+    pub fn generate_jwt<T>(
+        user_id: i64,
+        session_id: i64,
+        parameters: JwtGenerationParameters<T>,
+    ) -> Result<String, ServiceError>
     where
         T: AsRef<str>,
     {
@@ -20,13 +38,22 @@ impl Service {
             sub: user_id,
             exp: (Utc::now() + Duration::try_seconds(parameters.ttl).unwrap_or_default()).timestamp(),
             iat: Utc::now().timestamp(),
+            sid: Some(session_id),
         };
 
-        Ok(jsonwebtoken::encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(parameters.secret.as_ref().as_bytes()),
-        )?)
+        match parameters.keys.signing() {
+            Some(signing) => {
+                let mut header = Header::new(signing.algorithm);
+                header.kid = Some(signing.kid.clone());
+
+                Ok(jsonwebtoken::encode(&header, &claims, &signing.key)?)
+            }
+            None => Ok(jsonwebtoken::encode(
+                &Header::default(),
+                &claims,
+                &EncodingKey::from_secret(parameters.secret.as_ref().as_bytes()),
+            )?),
+        }
     }
 
     #[tracing::instrument(skip(parameters))]
@@ -34,37 +61,252 @@ impl Service {
     where
         T: AsRef<str>,
     {
-        let validation = Validation::default();
+        let header = jsonwebtoken::decode_header(parameters.token.as_ref())?;
 
-        let decoded = jsonwebtoken::decode::<TokenClaims>(
-            parameters.token.as_ref(),
-            &DecodingKey::from_secret(parameters.secret.as_ref().as_bytes()),
-            &validation,
-        )?;
+        //? Validation allows only algorithm of the key
+        //? so public key can not be used as HMAC secret
+        let decoded = match header.kid {
+            Some(kid) => {
+                let key = parameters.keys.find(kid).ok_or(ServiceError::KeyWasNotFound)?;
+
+                jsonwebtoken::decode::<TokenClaims>(
+                    parameters.token.as_ref(),
+                    &key.key,
+                    &Validation::new(key.algorithm),
+                )?
+            }
+            //? Tokens signed with secret are accepted for backward compatibility
+            None => jsonwebtoken::decode::<TokenClaims>(
+                parameters.token.as_ref(),
+                &DecodingKey::from_secret(parameters.secret.as_ref().as_bytes()),
+                &Validation::default(),
+            )?,
+        };
 
         Ok(decoded.claims)
     }
 
-    #[tracing::instrument(skip(connection, credentials))]
-    pub async fn login<T, C>(credentials: UserCredentials<T>, connection: &C) -> Result<UserModel, ServiceError>
+    /// Hashes password with argon2 and random salt
+    pub fn hash_password<T>(password: T) -> Result<String, ServiceError>
+    where
+        T: AsRef<str>,
+    {
+        let salt = SaltString::generate(&mut OsRng);
+
+        Ok(Argon2::default()
+            .hash_password(password.as_ref().as_bytes(), &salt)?
+            .to_string())
+    }
+
+    /// Checks password against argon2 hash produced by [`Service::hash_password`]
+    pub fn verify_password<T>(password: T, hash: &str) -> Result<(), ServiceError>
+    where
+        T: AsRef<str>,
+    {
+        let parsed_hash = PasswordHash::new(hash)?;
+
+        Ok(Argon2::default().verify_password(password.as_ref().as_bytes(), &parsed_hash)?)
+    }
+
+    /// Checks credentials and counts failures per login and per client address.
+    ///
+    /// Login is locked once either of them reaches its threshold.
+    /// Both counters are reset on success.
+    #[tracing::instrument(skip(connection, credentials, lockout))]
+    pub async fn login<T, C>(
+        credentials: UserCredentials<T>,
+        lockout: LockoutParameters,
+        connection: &C,
+    ) -> Result<UserModel, ServiceError>
     where
         C: ConnectionTrait + TransactionTrait,
         T: AsRef<str>,
     {
-        let user = match UserEntity::find()
-            .filter(UserColumn::Login.eq(credentials.login.as_ref()))
+        let now = Utc::now();
+        let login = credentials.login.as_ref();
+        let address = lockout.address.map(|address| address.to_string());
+
+        let attempts = LoginAttemptEntity::find()
+            .filter(Self::attempts_of(login, address.as_deref()))
+            .all(connection)
+            .await?;
+
+        //? Password is not verified during lockout
+        //? so guesses can not be checked at all
+        if let Some(locked_until) = attempts
+            .iter()
+            .filter_map(|attempt| attempt.locked_until)
+            .map(DateTime::<Utc>::from)
+            .filter(|locked_until| *locked_until > now)
+            .max()
+        {
+            return Err(ServiceError::LoginIsLocked(locked_until));
+        }
+
+        let user = UserEntity::find()
+            .filter(UserColumn::Login.eq(login))
+            .one(connection)
+            .await?;
+
+        let Some(user) = user else {
+            //? Unknown logins are counted only per address
+            //? otherwise anyone could fill the table with made up logins
+            if let Some(address) = address.as_deref() {
+                let threshold = lockout.attempts_per_address;
+                Self::fail(ADDRESS_ATTEMPT, address, threshold, &lockout, connection).await?;
+            }
+
+            return Err(ServiceError::UserWasNotFound);
+        };
+
+        match Self::verify_password(credentials.password, &user.password) {
+            Ok(()) => {
+                LoginAttemptEntity::delete_many()
+                    .filter(Self::attempts_of(login, address.as_deref()))
+                    .exec(connection)
+                    .await?;
+
+                //? Password is checked first so suspension does not reveal the login
+                match user.suspended_at {
+                    Some(_) => Err(ServiceError::UserIsSuspended),
+                    None => Ok(user),
+                }
+            }
+            Err(ServiceError::PasswordHashError(password_hash::Error::Password)) => {
+                let threshold = lockout.attempts_per_login;
+                Self::fail(LOGIN_ATTEMPT, login, threshold, &lockout, connection).await?;
+
+                if let Some(address) = address.as_deref() {
+                    let threshold = lockout.attempts_per_address;
+                    Self::fail(ADDRESS_ATTEMPT, address, threshold, &lockout, connection).await?;
+                }
+
+                Err(ServiceError::InvalidPassword)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn attempts_of(login: &str, address: Option<&str>) -> Condition {
+        let attempt_of = |kind: &str, subject: &str| {
+            Condition::all()
+                .add(LoginAttemptColumn::Kind.eq(kind))
+                .add(LoginAttemptColumn::Subject.eq(subject))
+        };
+
+        Condition::any()
+            .add(attempt_of(LOGIN_ATTEMPT, login))
+            .add_option(address.map(|address| attempt_of(ADDRESS_ATTEMPT, address)))
+    }
+
+    /// Time until which the subject is locked out if it is
+    #[tracing::instrument(skip(connection))]
+    pub async fn locked_until<C>(
+        kind: &str,
+        subject: &str,
+        connection: &C,
+    ) -> Result<Option<DateTime<Utc>>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let now = Utc::now();
+
+        Ok(LoginAttemptEntity::find()
+            .filter(LoginAttemptColumn::Kind.eq(kind))
+            .filter(LoginAttemptColumn::Subject.eq(subject))
             .one(connection)
             .await?
-        {
-            Some(user) => Ok(user),
-            None => Err(ServiceError::UserWasNotFound),
-        }?;
+            .and_then(|attempt| attempt.locked_until)
+            .map(DateTime::<Utc>::from)
+            .filter(|locked_until| *locked_until > now))
+    }
+
+    /// Forgets failures of the subject after successful attempt
+    #[tracing::instrument(skip(connection))]
+    pub async fn forget_failures<C>(kind: &str, subject: &str, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(LoginAttemptEntity::delete_many()
+            .filter(LoginAttemptColumn::Kind.eq(kind))
+            .filter(LoginAttemptColumn::Subject.eq(subject))
+            .exec(connection)
+            .await?
+            .rows_affected)
+    }
+
+    /// Counts one more failure and locks the subject once threshold is reached
+    pub async fn fail<C>(
+        kind: &str,
+        subject: &str,
+        threshold: u32,
+        lockout: &LockoutParameters,
+        connection: &C,
+    ) -> Result<(), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let now = Utc::now();
+        let ttl = Duration::try_seconds(ATTEMPTS_TTL_SECONDS).unwrap_or_default();
+
+        let attempt_to_be_inserted = LoginAttemptActiveModel {
+            kind: Set(kind.to_owned()),
+            subject: Set(subject.to_owned()),
+            failures: Set(1),
+            locked_until: Set(None),
+            updated_at: Set(now.into()),
+            ..Default::default()
+        };
 
-        let parsed_hash = PasswordHash::new(&user.password)?;
+        //? Failure is counted in a single statement so concurrent
+        //? attempts can not read the same count and skip the lockout.
+        //? Counter starts over if the last failure is older than TTL
+        let failures = Expr::case(
+            Expr::col((LoginAttemptEntity, LoginAttemptColumn::UpdatedAt)).lt(now - ttl),
+            1,
+        )
+        .finally(Expr::col((LoginAttemptEntity, LoginAttemptColumn::Failures)).add(1));
 
-        Argon2::default().verify_password(credentials.password.as_ref().as_bytes(), &parsed_hash)?;
+        let attempt = LoginAttemptEntity::insert(attempt_to_be_inserted)
+            .on_conflict(
+                OnConflict::columns([LoginAttemptColumn::Kind, LoginAttemptColumn::Subject])
+                    .value(LoginAttemptColumn::Failures, failures)
+                    .update_column(LoginAttemptColumn::UpdatedAt)
+                    .to_owned(),
+            )
+            .exec_with_returning(connection)
+            .await?;
 
-        Ok(user)
+        let locked_until = Self::lockout_for(attempt.failures, threshold, lockout.lockout_seconds)
+            .map(|duration| DateTimeWithTimeZone::from(now + duration));
+
+        tracing::debug!(%kind, %subject, %attempt.failures, ?locked_until, "Login attempt failed!");
+
+        //? Only the latest failure sets the lockout
+        //? so concurrent ones can not shorten it
+        LoginAttemptEntity::update_many()
+            .col_expr(LoginAttemptColumn::LockedUntil, Expr::value(locked_until))
+            .filter(LoginAttemptColumn::Id.eq(attempt.id))
+            .filter(LoginAttemptColumn::Failures.eq(attempt.failures))
+            .exec(connection)
+            .await?;
+
+        Ok(())
+    }
+
+    //? The first lockout happens right on threshold
+    //? and is doubled with every next failure
+    fn lockout_for(failures: i32, threshold: u32, lockout_seconds: i64) -> Option<Duration> {
+        if threshold == 0 {
+            return None;
+        }
+
+        let excess = u32::try_from(failures).ok()?.checked_sub(threshold)?;
+        let seconds = lockout_seconds
+            .saturating_mul(2_i64.saturating_pow(excess))
+            .min(MAX_LOCKOUT_SECONDS);
+
+        Duration::try_seconds(seconds)
     }
 
     #[tracing::instrument(skip(connection, credentials))]
@@ -80,11 +322,7 @@ impl Service {
         {
             Some(_) => Err(ServiceError::LoginOccupied),
             None => {
-                let salt = SaltString::generate(&mut OsRng);
-
-                let hashed_password = Argon2::default()
-                    .hash_password(credentials.password.as_ref().as_bytes(), &salt)?
-                    .to_string();
+                let hashed_password = Self::hash_password(credentials.password)?;
 
                 let user_to_be_inserted = UserActiveModel {
                     login: Set(credentials.login.as_ref().to_owned()),
@@ -0,0 +1,25 @@
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("{0} is not a valid file extension!")]
+    InvalidExtension(String),
+    #[error("{0} is not a valid content type!")]
+    InvalidContentType(String),
+    #[error("Content type for extension {0} was not found!")]
+    ContentTypeWasNotFound(String),
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::InvalidExtension(_) => Self::BAD_REQUEST,
+            ServiceError::InvalidContentType(_) => Self::BAD_REQUEST,
+            ServiceError::ContentTypeWasNotFound(_) => Self::NOT_FOUND,
+        }
+    }
+}
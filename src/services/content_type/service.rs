@@ -0,0 +1,128 @@
+use super::error::ServiceError;
+use axum::http::HeaderValue;
+use entity::prelude::*;
+use sea_orm::{prelude::*, sea_query::OnConflict, QueryOrder, Set, TransactionTrait};
+use std::{fmt::Debug, path::Path};
+
+pub struct Service;
+
+impl Service {
+    /// Normalizes extension to lowercase without leading dot
+    pub fn extension<E>(extension: E) -> Result<String, ServiceError>
+    where
+        E: AsRef<str>,
+    {
+        let extension = extension.as_ref().trim().trim_start_matches('.').to_ascii_lowercase();
+
+        match !extension.is_empty()
+            && extension.len() <= 32
+            && extension
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+        {
+            true => Ok(extension),
+            false => Err(ServiceError::InvalidExtension(extension)),
+        }
+    }
+
+    /// Checks that value is a media type which can be sent in a header
+    pub fn value<V>(value: V) -> Result<String, ServiceError>
+    where
+        V: AsRef<str>,
+    {
+        let value = value.as_ref().trim();
+
+        match value.parse::<mime::Mime>().is_ok() && HeaderValue::from_str(value).is_ok() {
+            true => Ok(value.to_owned()),
+            false => Err(ServiceError::InvalidContentType(value.to_owned())),
+        }
+    }
+
+    /// Sets content type for files with the extension.
+    /// Existing override of the extension is replaced
+    #[tracing::instrument(skip(connection))]
+    pub async fn set_for<C, E, V>(
+        subdomain_id: i64,
+        extension: E,
+        value: V,
+        connection: &C,
+    ) -> Result<ContentTypeModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        E: AsRef<str> + Debug,
+        V: AsRef<str> + Debug,
+    {
+        let content_type_to_be_inserted = ContentTypeActiveModel {
+            subdomain_id: Set(subdomain_id),
+            extension: Set(Self::extension(extension)?),
+            value: Set(Self::value(value)?),
+            ..Default::default()
+        };
+
+        Ok(ContentTypeEntity::insert(content_type_to_be_inserted)
+            .on_conflict(
+                OnConflict::columns([ContentTypeColumn::SubdomainId, ContentTypeColumn::Extension])
+                    .update_column(ContentTypeColumn::Value)
+                    .to_owned(),
+            )
+            .exec_with_returning(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_for<C>(subdomain_id: i64, connection: &C) -> Result<Vec<ContentTypeModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(ContentTypeEntity::find()
+            .filter(ContentTypeColumn::SubdomainId.eq(subdomain_id))
+            .order_by_asc(ContentTypeColumn::Extension)
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn delete_for<C, E>(subdomain_id: i64, extension: E, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        E: AsRef<str> + Debug,
+    {
+        let extension = Self::extension(extension)?;
+
+        let rows_affected = ContentTypeEntity::delete_many()
+            .filter(ContentTypeColumn::SubdomainId.eq(subdomain_id))
+            .filter(ContentTypeColumn::Extension.eq(&extension))
+            .exec(connection)
+            .await?
+            .rows_affected;
+
+        match rows_affected {
+            0 => Err(ServiceError::ContentTypeWasNotFound(extension)),
+            _ => Ok(rows_affected),
+        }
+    }
+
+    /// Finds override of the site for extension of the path
+    #[tracing::instrument(skip(connection))]
+    pub async fn resolve_for<C, P>(subdomain_id: i64, path: P, connection: &C) -> Result<Option<String>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: AsRef<Path> + Debug,
+    {
+        let Some(extension) = path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase())
+        else {
+            return Ok(None);
+        };
+
+        Ok(ContentTypeEntity::find()
+            .filter(ContentTypeColumn::SubdomainId.eq(subdomain_id))
+            .filter(ContentTypeColumn::Extension.eq(extension))
+            .one(connection)
+            .await?
+            .map(|content_type| content_type.value))
+    }
+}
@@ -0,0 +1,25 @@
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("Subdomain with id = {0} was not found!")]
+    SubdomainWasNotFound(i64),
+    #[error("Deployment with id = {0} was not found!")]
+    DeploymentWasNotFound(i64),
+    #[error("Preview with id = {0} was not found or has expired!")]
+    PreviewWasNotFound(i64),
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::SubdomainWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::DeploymentWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::PreviewWasNotFound(_) => Self::NOT_FOUND,
+        }
+    }
+}
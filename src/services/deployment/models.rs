@@ -0,0 +1,11 @@
+use entity::prelude::*;
+
+/// Deployment together with statistics of its files.
+/// Precompressed variants are not counted
+#[derive(Debug, Clone)]
+pub struct DeploymentSummary {
+    pub deployment: DeploymentModel,
+    pub active: bool,
+    pub files: u64,
+    pub bytes: u64,
+}
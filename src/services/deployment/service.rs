@@ -0,0 +1,325 @@
+use super::{error::ServiceError, models::DeploymentSummary};
+use chrono::Utc;
+use entity::prelude::*;
+use sea_orm::{prelude::*, sea_query::Alias, FromQueryResult, QueryOrder, QuerySelect, Set, TransactionTrait};
+use std::fmt::Debug;
+
+#[derive(FromQueryResult)]
+struct DeploymentStatistics {
+    deployment_id: Option<i64>,
+    files: i64,
+    bytes: Option<i64>,
+}
+
+pub struct Service;
+
+impl Service {
+    /// Retained deployments of the subdomain from the newest to the oldest.
+    /// Previews are not listed
+    #[tracing::instrument(skip(connection))]
+    pub async fn list<C>(subdomain_id: i64, connection: &C) -> Result<Vec<DeploymentSummary>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound(subdomain_id))?;
+
+        let deployments = subdomain
+            .find_related(DeploymentEntity)
+            .filter(DeploymentColumn::ExpiresAt.is_null())
+            .order_by_desc(DeploymentColumn::Id)
+            .all(connection)
+            .await?;
+
+        Self::summaries(&subdomain, deployments, connection).await
+    }
+
+    /// Previews of the subdomain which have not expired yet from the newest to the oldest
+    #[tracing::instrument(skip(connection))]
+    pub async fn previews<C>(subdomain_id: i64, connection: &C) -> Result<Vec<DeploymentSummary>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound(subdomain_id))?;
+
+        let previews = subdomain
+            .find_related(DeploymentEntity)
+            .filter(DeploymentColumn::ExpiresAt.gt(Utc::now()))
+            .order_by_desc(DeploymentColumn::Id)
+            .all(connection)
+            .await?;
+
+        Self::summaries(&subdomain, previews, connection).await
+    }
+
+    /// Site as it will be served once the preview is promoted.
+    /// Expired previews are not resolved even before they are removed
+    #[tracing::instrument(skip(connection))]
+    pub async fn preview<C, S>(
+        deployment_id: i64,
+        subdomain_name: S,
+        connection: &C,
+    ) -> Result<Option<SubdomainModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        S: AsRef<str> + Debug,
+    {
+        let Some((preview, Some(mut subdomain))) = DeploymentEntity::find_by_id(deployment_id)
+            .find_also_related(SubdomainEntity)
+            .filter(DeploymentColumn::ExpiresAt.gt(Utc::now()))
+            .filter(SubdomainColumn::Name.eq(subdomain_name.as_ref()))
+            .one(connection)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        subdomain.active_deployment_id = Some(preview.id);
+
+        //? Previews are served with settings from their own sero.toml
+        if let Some(spa) = preview.spa {
+            subdomain.spa = spa;
+        }
+
+        if let Some(trailing_slash) = preview.trailing_slash {
+            subdomain.trailing_slash = trailing_slash;
+        }
+
+        Ok(Some(subdomain))
+    }
+
+    /// Makes preview a regular deployment which is served on the site at once.
+    /// Deployments which do not fit into retention limit are pruned
+    /// and returned so their archives can be removed after commit
+    #[tracing::instrument(skip(connection))]
+    pub async fn promote<C>(
+        subdomain_id: i64,
+        deployment_id: i64,
+        keep: u64,
+        connection: &C,
+    ) -> Result<(DeploymentModel, Vec<DeploymentModel>), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound(subdomain_id))?;
+
+        let preview = Self::find_preview(subdomain.id, deployment_id, connection).await?;
+
+        let mut active_preview: DeploymentActiveModel = preview.into();
+        active_preview.expires_at = Set(None);
+        let deployment = active_preview.update(connection).await?;
+
+        let mut active = Self::activate(subdomain, &deployment);
+        active.last_deployed_at = Set(Some(Utc::now().into()));
+        active.update(connection).await?;
+
+        let pruned = Self::prune(subdomain_id, keep, connection).await?;
+
+        Ok((deployment, pruned))
+    }
+
+    /// Removes preview before it expires
+    #[tracing::instrument(skip(connection))]
+    pub async fn discard<C>(
+        subdomain_id: i64,
+        deployment_id: i64,
+        connection: &C,
+    ) -> Result<DeploymentModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let preview = Self::find_preview(subdomain_id, deployment_id, connection).await?;
+
+        Self::remove(vec![preview], connection)
+            .await?
+            .pop()
+            .ok_or(ServiceError::DeploymentWasNotFound(deployment_id))
+    }
+
+    /// Removes expired previews of all sites
+    #[tracing::instrument(skip(connection))]
+    pub async fn expire<C>(connection: &C) -> Result<Vec<DeploymentModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let previews = DeploymentEntity::find()
+            .filter(DeploymentColumn::ExpiresAt.lte(Utc::now()))
+            .all(connection)
+            .await?;
+
+        Self::remove(previews, connection).await
+    }
+
+    /// Makes retained deployment active again
+    #[tracing::instrument(skip(connection))]
+    pub async fn rollback<C>(
+        subdomain_id: i64,
+        deployment_id: i64,
+        connection: &C,
+    ) -> Result<DeploymentModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound(subdomain_id))?;
+
+        //? Previews are made live only by promotion
+        let deployment = DeploymentEntity::find_by_id(deployment_id)
+            .filter(DeploymentColumn::SubdomainId.eq(subdomain.id))
+            .filter(DeploymentColumn::ExpiresAt.is_null())
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::DeploymentWasNotFound(deployment_id))?;
+
+        Self::activate(subdomain, &deployment).update(connection).await?;
+
+        Ok(deployment)
+    }
+
+    /// Removes deployments which do not fit into retention limit.
+    /// Active deployment and previews are never removed here
+    #[tracing::instrument(skip(connection))]
+    pub async fn prune<C>(subdomain_id: i64, keep: u64, connection: &C) -> Result<Vec<DeploymentModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let subdomain = SubdomainEntity::find_by_id(subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound(subdomain_id))?;
+
+        let deployments = subdomain
+            .find_related(DeploymentEntity)
+            .filter(DeploymentColumn::ExpiresAt.is_null())
+            .order_by_desc(DeploymentColumn::Id)
+            .offset(keep)
+            .all(connection)
+            .await?
+            .into_iter()
+            .filter(|deployment| subdomain.active_deployment_id != Some(deployment.id))
+            .collect();
+
+        Self::remove(deployments, connection).await
+    }
+
+    //? Site is switched with a single update
+    //? so it is never served half deployed
+    fn activate(subdomain: SubdomainModel, deployment: &DeploymentModel) -> SubdomainActiveModel {
+        let mut active: SubdomainActiveModel = subdomain.into();
+        active.active_deployment_id = Set(Some(deployment.id));
+        active.archive_path = Set(deployment.archive_path.clone());
+        active.updated_at = Set(Utc::now().into());
+        active.activated_at = Set(Some(Utc::now().into()));
+
+        //? Settings from sero.toml of the deployment are restored
+        if let Some(spa) = deployment.spa {
+            active.spa = Set(spa);
+        }
+
+        if let Some(trailing_slash) = deployment.trailing_slash.as_ref() {
+            active.trailing_slash = Set(trailing_slash.clone());
+        }
+
+        active
+    }
+
+    async fn find_preview<C>(
+        subdomain_id: i64,
+        deployment_id: i64,
+        connection: &C,
+    ) -> Result<DeploymentModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        DeploymentEntity::find_by_id(deployment_id)
+            .filter(DeploymentColumn::SubdomainId.eq(subdomain_id))
+            .filter(DeploymentColumn::ExpiresAt.gt(Utc::now()))
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::PreviewWasNotFound(deployment_id))
+    }
+
+    //? Files of removed deployments are marked as obsolete
+    //? and rules are removed together with deployments.
+    //? Archives must be removed by caller after commit
+    async fn remove<C>(deployments: Vec<DeploymentModel>, connection: &C) -> Result<Vec<DeploymentModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if deployments.is_empty() {
+            return Ok(deployments);
+        }
+
+        let ids = deployments.iter().map(|deployment| deployment.id).collect::<Vec<_>>();
+
+        let rows_affected = FileEntity::update_many()
+            .filter(FileColumn::DeploymentId.is_in(ids.clone()))
+            .col_expr(FileColumn::Obsolete, Expr::value(true))
+            .exec(connection)
+            .await?
+            .rows_affected;
+        tracing::trace!(%rows_affected, "Files of removed deployments were marked as obsolete!");
+
+        let rows_affected = DeploymentEntity::delete_many()
+            .filter(DeploymentColumn::Id.is_in(ids))
+            .exec(connection)
+            .await?
+            .rows_affected;
+        tracing::trace!(%rows_affected, "Deployments were removed!");
+
+        Ok(deployments)
+    }
+
+    async fn summaries<C>(
+        subdomain: &SubdomainModel,
+        deployments: Vec<DeploymentModel>,
+        connection: &C,
+    ) -> Result<Vec<DeploymentSummary>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        //? Sum of bigint is numeric in postgres
+        let statistics = FileEntity::find()
+            .select_only()
+            .column(FileColumn::DeploymentId)
+            .column_as(Expr::col(FileColumn::Id).count(), "files")
+            .column_as(
+                Expr::expr(Expr::col(FileColumn::Size).sum()).cast_as(Alias::new("bigint")),
+                "bytes",
+            )
+            .filter(FileColumn::DeploymentId.is_in(deployments.iter().map(|deployment| deployment.id)))
+            .filter(FileColumn::Encoding.is_null())
+            .group_by(FileColumn::DeploymentId)
+            .into_model::<DeploymentStatistics>()
+            .all(connection)
+            .await?;
+
+        Ok(deployments
+            .into_iter()
+            .map(|deployment| {
+                let statistic = statistics
+                    .iter()
+                    .find(|statistic| statistic.deployment_id == Some(deployment.id));
+
+                DeploymentSummary {
+                    active: subdomain.active_deployment_id == Some(deployment.id),
+                    files: statistic.map_or(0, |statistic| statistic.files.max(0) as u64),
+                    bytes: statistic
+                        .and_then(|statistic| statistic.bytes)
+                        .map_or(0, |bytes| bytes.max(0) as u64),
+                    deployment,
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,61 @@
+use axum::http::header;
+use std::{net::SocketAddr, time::Duration};
+
+/// Path prefix under which verification tokens are served
+pub const CHALLENGE_PATH: &str = "/.well-known/sero-challenge";
+
+/// Fetches verification tokens from custom domains.
+///
+/// By default domain is resolved with DNS so the request reaches
+/// whatever server the domain points to. Address can be pinned
+/// to send every request to a local stand-in instead.
+#[derive(Debug, Clone)]
+pub struct Challenger {
+    client: reqwest::Client,
+    address: Option<SocketAddr>,
+}
+
+impl Default for Challenger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Challenger {
+    pub fn new() -> Self {
+        //? Building client fails only if TLS backend can not be initialized
+        //? and challenges are requested over plain HTTP.
+        //? Redirects are not followed so the domain can not
+        //? point verification requests to internal addresses
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap_or_default();
+
+        Self { client, address: None }
+    }
+
+    pub fn with_address(address: SocketAddr) -> Self {
+        Self {
+            address: Some(address),
+            ..Self::new()
+        }
+    }
+
+    /// Requests `http://<domain>/.well-known/sero-challenge/<token>`
+    /// and returns response body if status was successful
+    #[tracing::instrument(skip(self))]
+    pub async fn fetch(&self, domain: &str, token: &str) -> Result<String, reqwest::Error> {
+        let request = match self.address {
+            //? Host header keeps the domain so stand-in can tell domains apart
+            Some(address) => self
+                .client
+                .get(format!("http://{address}{CHALLENGE_PATH}/{token}"))
+                .header(header::HOST, domain),
+            None => self.client.get(format!("http://{domain}{CHALLENGE_PATH}/{token}")),
+        };
+
+        request.send().await?.error_for_status()?.text().await
+    }
+}
@@ -0,0 +1,44 @@
+use crate::tls::certificate::CertificateError;
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("Domain {0} is not a valid domain name!")]
+    InvalidDomainName(String),
+    #[error("Domain {0} belongs to one of base domains and can not be added as custom domain!")]
+    DomainBelongsToBaseDomain(String),
+    #[error("Domain {0} is already taken!")]
+    DomainIsAlreadyTaken(String),
+    #[error("Domain with id = {0} was not found!")]
+    DomainWasNotFound(i64),
+    #[error("Domain with id = {0} does not belong to subdomain with id {1}!")]
+    DomainDoesNotBelongToSubdomain(i64, i64),
+    #[error("Ownership of domain {0} was not verified: {1}")]
+    VerificationFailed(String, String),
+    #[error(transparent)]
+    InvalidCertificate(#[from] CertificateError),
+    #[error("Certificate is not valid for domain {0}!")]
+    CertificateDoesNotCoverDomain(String),
+    #[error("Domain with id = {0} does not have certificate!")]
+    CertificateWasNotFound(i64),
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::InvalidDomainName(_) => Self::BAD_REQUEST,
+            ServiceError::DomainBelongsToBaseDomain(_) => Self::BAD_REQUEST,
+            ServiceError::DomainIsAlreadyTaken(_) => Self::CONFLICT,
+            ServiceError::DomainWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::DomainDoesNotBelongToSubdomain(_, _) => Self::FORBIDDEN,
+            ServiceError::VerificationFailed(_, _) => Self::UNPROCESSABLE_ENTITY,
+            ServiceError::InvalidCertificate(_) => Self::BAD_REQUEST,
+            ServiceError::CertificateDoesNotCoverDomain(_) => Self::BAD_REQUEST,
+            ServiceError::CertificateWasNotFound(_) => Self::NOT_FOUND,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod challenge;
+pub mod error;
+pub mod service;
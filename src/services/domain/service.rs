@@ -0,0 +1,318 @@
+use super::{challenge::Challenger, error::ServiceError};
+use crate::{
+    http::host,
+    tls::certificate::{self, ParsedCertificate},
+};
+use chrono::{Duration, Utc};
+use entity::prelude::*;
+use sea_orm::{prelude::*, IntoActiveModel, Set, TransactionTrait};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+pub struct Service;
+
+impl Service {
+    /// Lowercases domain and checks that it is a valid hostname
+    /// with at least two labels and not numeric top level label
+    pub fn normalize<D>(domain: D) -> Result<String, ServiceError>
+    where
+        D: AsRef<str>,
+    {
+        let normalized = host::hostname(domain.as_ref().trim());
+        let labels = normalized.split('.').collect::<Vec<_>>();
+
+        let is_valid_label = |label: &&str| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '-')
+        };
+
+        let is_valid = normalized.len() <= 253
+            && labels.len() >= 2
+            && labels.iter().all(is_valid_label)
+            && labels
+                .last()
+                .is_some_and(|tld| !tld.chars().all(|character| character.is_ascii_digit()));
+
+        match is_valid {
+            true => Ok(normalized),
+            false => Err(ServiceError::InvalidDomainName(domain.as_ref().to_owned())),
+        }
+    }
+
+    /// Claims domain for the site until its ownership is verified.
+    /// Unverified claims older than `claim_ttl_seconds` are dropped
+    /// so the domain can not be held without proving ownership
+    #[tracing::instrument(skip(connection))]
+    pub async fn add_domain_for<C, D>(
+        subdomain_id: i64,
+        domain: D,
+        base_domains: &[String],
+        claim_ttl_seconds: i64,
+        connection: &C,
+    ) -> Result<DomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        D: AsRef<str> + Debug,
+    {
+        let name = Self::normalize(domain)?;
+
+        //? Hosts under base domains are already routed to subdomains
+        let belongs_to_base_domain = base_domains.iter().any(|base_domain| {
+            let base_domain = base_domain.trim_matches('.').to_ascii_lowercase();
+            name == base_domain || name.ends_with(&format!(".{base_domain}"))
+        });
+
+        if belongs_to_base_domain {
+            return Err(ServiceError::DomainBelongsToBaseDomain(name));
+        }
+
+        let expired_at = Utc::now() - Duration::try_seconds(claim_ttl_seconds).unwrap_or_default();
+
+        DomainEntity::delete_many()
+            .filter(DomainColumn::Name.eq(&name))
+            .filter(DomainColumn::Verified.eq(false))
+            .filter(DomainColumn::CreatedAt.lt(expired_at))
+            .exec(connection)
+            .await?;
+
+        if DomainEntity::find()
+            .filter(DomainColumn::Name.eq(&name))
+            .one(connection)
+            .await?
+            .is_some()
+        {
+            return Err(ServiceError::DomainIsAlreadyTaken(name));
+        }
+
+        let domain_to_be_inserted = DomainActiveModel {
+            subdomain_id: Set(subdomain_id),
+            name: Set(name),
+            token: Set(Uuid::new_v4().simple().to_string()),
+            verified: Set(false),
+            ..Default::default()
+        };
+
+        Ok(DomainEntity::insert(domain_to_be_inserted)
+            .exec_with_returning(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_domains_for<C>(subdomain_id: i64, connection: &C) -> Result<Vec<DomainModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(DomainEntity::find()
+            .filter(DomainColumn::SubdomainId.eq(subdomain_id))
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn fetch_domain_of<C>(
+        subdomain_id: i64,
+        domain_id: i64,
+        connection: &C,
+    ) -> Result<DomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let domain = DomainEntity::find_by_id(domain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::DomainWasNotFound(domain_id))?;
+
+        if domain.subdomain_id != subdomain_id {
+            return Err(ServiceError::DomainDoesNotBelongToSubdomain(domain_id, subdomain_id));
+        }
+
+        Ok(domain)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn delete_domain_of<C>(subdomain_id: i64, domain_id: i64, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let domain = Self::fetch_domain_of(subdomain_id, domain_id, connection).await?;
+        Ok(domain.delete(connection).await?.rows_affected)
+    }
+
+    /// Fetches token from the domain and marks it verified if it matches.
+    /// Already verified domain is checked again so ownership can be reconfirmed.
+    #[tracing::instrument(skip(challenger, connection))]
+    pub async fn verify_domain_of<C>(
+        subdomain_id: i64,
+        domain_id: i64,
+        challenger: &Challenger,
+        connection: &C,
+    ) -> Result<DomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let domain = Self::fetch_domain_of(subdomain_id, domain_id, connection).await?;
+
+        let body = challenger
+            .fetch(&domain.name, &domain.token)
+            .await
+            .map_err(|cause| ServiceError::VerificationFailed(domain.name.clone(), cause.to_string()))?;
+
+        if body.trim() != domain.token {
+            return Err(ServiceError::VerificationFailed(
+                domain.name,
+                String::from("Served token does not match expected one"),
+            ));
+        }
+
+        let mut active_domain = domain.into_active_model();
+        active_domain.verified = Set(true);
+        active_domain.verified_at = Set(Some(Utc::now().into()));
+
+        Ok(active_domain.update(connection).await?)
+    }
+
+    /// Checks if token was issued for the domain.
+    /// Used to answer challenge requests reaching this server.
+    #[tracing::instrument(skip(connection))]
+    pub async fn is_challenge_of<C, H, T>(host: H, token: T, connection: &C) -> Result<bool, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        H: AsRef<str> + Debug,
+        T: AsRef<str> + Debug,
+    {
+        Ok(DomainEntity::find()
+            .filter(DomainColumn::Name.eq(host::hostname(host.as_ref())))
+            .filter(DomainColumn::Token.eq(token.as_ref()))
+            .one(connection)
+            .await?
+            .is_some())
+    }
+
+    /// Finds site which verified custom domain points to
+    #[tracing::instrument(skip(connection))]
+    pub async fn subdomain_for<C, H>(host: H, connection: &C) -> Result<Option<SubdomainModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        H: AsRef<str> + Debug,
+    {
+        Ok(SubdomainEntity::find()
+            .inner_join(DomainEntity)
+            .filter(DomainColumn::Name.eq(host::hostname(host.as_ref())))
+            .filter(DomainColumn::Verified.eq(true))
+            .one(connection)
+            .await?)
+    }
+
+    /// Stores certificate of the domain replacing previous one.
+    /// Certificate must be valid for the domain.
+    /// Managed certificates are renewed with ACME.
+    #[tracing::instrument(skip(chain, private_key, connection))]
+    pub async fn set_certificate_of<C>(
+        subdomain_id: i64,
+        domain_id: i64,
+        chain: String,
+        private_key: String,
+        managed: bool,
+        connection: &C,
+    ) -> Result<(DomainModel, ParsedCertificate), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let domain = Self::fetch_domain_of(subdomain_id, domain_id, connection).await?;
+
+        let parsed = certificate::parse(&chain, &private_key)?;
+        if !parsed.covers(&domain.name) {
+            return Err(ServiceError::CertificateDoesNotCoverDomain(domain.name));
+        }
+
+        CertificateEntity::delete_many()
+            .filter(CertificateColumn::DomainId.eq(domain.id))
+            .exec(connection)
+            .await?;
+
+        let certificate_to_be_inserted = CertificateActiveModel {
+            domain_id: Set(domain.id),
+            chain: Set(chain),
+            private_key: Set(private_key),
+            expires_at: Set(parsed.not_after.into()),
+            managed: Set(managed),
+            ..Default::default()
+        };
+
+        CertificateEntity::insert(certificate_to_be_inserted)
+            .exec(connection)
+            .await?;
+
+        Ok((domain, parsed))
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn clear_certificate_of<C>(
+        subdomain_id: i64,
+        domain_id: i64,
+        connection: &C,
+    ) -> Result<DomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let domain = Self::fetch_domain_of(subdomain_id, domain_id, connection).await?;
+
+        let rows_affected = CertificateEntity::delete_many()
+            .filter(CertificateColumn::DomainId.eq(domain.id))
+            .exec(connection)
+            .await?
+            .rows_affected;
+
+        match rows_affected {
+            0 => Err(ServiceError::CertificateWasNotFound(domain_id)),
+            _ => Ok(domain),
+        }
+    }
+
+    /// Parses stored certificate of the domain
+    #[tracing::instrument(skip(connection))]
+    pub async fn certificate_for<C>(
+        domain: &DomainModel,
+        connection: &C,
+    ) -> Result<Option<ParsedCertificate>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        match domain.find_related(CertificateEntity).one(connection).await? {
+            Some(stored) => Ok(Some(certificate::parse(stored.chain, stored.private_key)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Certificates of verified domains which should be served
+    #[tracing::instrument(skip(connection))]
+    pub async fn active_certificates<C>(connection: &C) -> Result<Vec<(DomainModel, CertificateModel)>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(Self::verified_domains(connection)
+            .await?
+            .into_iter()
+            .filter_map(|(domain, certificate)| Some((domain, certificate?)))
+            .collect())
+    }
+
+    /// Verified domains with their certificates if any
+    #[tracing::instrument(skip(connection))]
+    pub async fn verified_domains<C>(
+        connection: &C,
+    ) -> Result<Vec<(DomainModel, Option<CertificateModel>)>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(DomainEntity::find()
+            .filter(DomainColumn::Verified.eq(true))
+            .find_also_related(CertificateEntity)
+            .all(connection)
+            .await?)
+    }
+}
@@ -1,4 +1,12 @@
+pub mod access;
+pub mod acme;
+pub mod admin;
 pub mod archive;
 pub mod auth;
+pub mod content_type;
+pub mod deployment;
+pub mod domain;
 pub mod origin;
+pub mod session;
 pub mod site;
+pub mod token;
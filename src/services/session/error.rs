@@ -0,0 +1,31 @@
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("Refresh token is invalid!")]
+    RefreshTokenIsInvalid,
+    #[error("Refresh token was already used! Session was revoked.")]
+    RefreshTokenWasReused,
+    #[error("Session has expired or was revoked!")]
+    SessionIsNotActive,
+    #[error("Session with id = {0} was not found!")]
+    SessionWasNotFound(i64),
+    #[error("Session with id = {0} does not belong to user with id {1}!")]
+    SessionDoesNotBelongToUser(i64, i64),
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::RefreshTokenIsInvalid => Self::UNAUTHORIZED,
+            ServiceError::RefreshTokenWasReused => Self::UNAUTHORIZED,
+            ServiceError::SessionIsNotActive => Self::UNAUTHORIZED,
+            ServiceError::SessionWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::SessionDoesNotBelongToUser(_, _) => Self::FORBIDDEN,
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use super::error::ServiceError;
+use chrono::{DateTime, Duration, Utc};
+use entity::prelude::*;
+use sea_orm::{prelude::*, sea_query::Expr, QueryOrder, Set, TransactionTrait};
+use sha2::{Digest, Sha256};
+
+pub struct Service;
+
+impl Service {
+    /// Starts a session on login and returns it with the first refresh token
+    #[tracing::instrument(skip(connection))]
+    pub async fn start_for<C>(
+        user_id: i64,
+        user_agent: Option<String>,
+        address: Option<String>,
+        ttl: i64,
+        connection: &C,
+    ) -> Result<(SessionModel, String), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let session_to_be_inserted = SessionActiveModel {
+            user_id: Set(user_id),
+            user_agent: Set(user_agent),
+            address: Set(address),
+            expires_at: Set(Self::expiration(ttl).into()),
+            ..Default::default()
+        };
+
+        let session = SessionEntity::insert(session_to_be_inserted)
+            .exec_with_returning(connection)
+            .await?;
+
+        let refresh_token = Self::issue_refresh_token(session.id, connection).await?;
+
+        Ok((session, refresh_token))
+    }
+
+    /// Exchanges refresh token for a new one and extends the session.
+    ///
+    /// Every refresh token is accepted once. If a used token comes again
+    /// it was most likely stolen so the whole session is revoked.
+    #[tracing::instrument(skip(refresh_token, connection))]
+    pub async fn refresh<C, T>(
+        refresh_token: T,
+        ttl: i64,
+        connection: &C,
+    ) -> Result<(SessionModel, String), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        T: AsRef<str>,
+    {
+        let now = Utc::now();
+
+        let (refresh_token, session) = RefreshTokenEntity::find()
+            .find_also_related(SessionEntity)
+            .filter(RefreshTokenColumn::TokenHash.eq(Self::hash(refresh_token)))
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::RefreshTokenIsInvalid)?;
+
+        let session = session
+            .filter(|session| Self::is_active(session, now))
+            .ok_or(ServiceError::SessionIsNotActive)?;
+
+        //? Token is marked used only if it was not used yet
+        //? so parallel refreshes with the same token can not both succeed
+        let rows_affected = RefreshTokenEntity::update_many()
+            .col_expr(RefreshTokenColumn::UsedAt, Expr::value(DateTimeWithTimeZone::from(now)))
+            .filter(RefreshTokenColumn::Id.eq(refresh_token.id))
+            .filter(RefreshTokenColumn::UsedAt.is_null())
+            .exec(connection)
+            .await?
+            .rows_affected;
+
+        if rows_affected == 0 {
+            tracing::warn!(%session.id, %session.user_id, "Refresh token was reused! Revoking session...");
+            Self::revoke(session, connection).await?;
+            return Err(ServiceError::RefreshTokenWasReused);
+        }
+
+        let mut active_session: SessionActiveModel = session.into();
+        active_session.refreshed_at = Set(now.into());
+        active_session.expires_at = Set(Self::expiration(ttl).into());
+        let session = active_session.update(connection).await?;
+
+        let refresh_token = Self::issue_refresh_token(session.id, connection).await?;
+
+        Ok((session, refresh_token))
+    }
+
+    /// Active session of the user. Used to reject JWTs of revoked sessions
+    #[tracing::instrument(skip(connection))]
+    pub async fn fetch_active<C>(user_id: i64, session_id: i64, connection: &C) -> Result<SessionModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        SessionEntity::find_by_id(session_id)
+            .filter(SessionColumn::UserId.eq(user_id))
+            .one(connection)
+            .await?
+            .filter(|session| Self::is_active(session, Utc::now()))
+            .ok_or(ServiceError::SessionIsNotActive)
+    }
+
+    /// Active sessions of the user in order of creation
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_sessions_for<C>(user_id: i64, connection: &C) -> Result<Vec<SessionModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(SessionEntity::find()
+            .filter(SessionColumn::UserId.eq(user_id))
+            .filter(SessionColumn::RevokedAt.is_null())
+            .filter(SessionColumn::ExpiresAt.gt(Utc::now()))
+            .order_by_asc(SessionColumn::Id)
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn revoke_session_of<C>(user_id: i64, session_id: i64, connection: &C) -> Result<(), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let session = SessionEntity::find_by_id(session_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SessionWasNotFound(session_id))?;
+
+        if session.user_id != user_id {
+            return Err(ServiceError::SessionDoesNotBelongToUser(session_id, user_id));
+        }
+
+        Self::revoke(session, connection).await
+    }
+
+    /// Revokes every active session of the user so JWTs and refresh tokens stop working
+    #[tracing::instrument(skip(connection))]
+    pub async fn revoke_sessions_of<C>(user_id: i64, connection: &C) -> Result<(), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let sessions = SessionEntity::find()
+            .filter(SessionColumn::UserId.eq(user_id))
+            .filter(SessionColumn::RevokedAt.is_null())
+            .all(connection)
+            .await?;
+
+        for session in sessions {
+            Self::revoke(session, connection).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn revoke<C>(session: SessionModel, connection: &C) -> Result<(), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        //? Refresh tokens are not needed anymore
+        RefreshTokenEntity::delete_many()
+            .filter(RefreshTokenColumn::SessionId.eq(session.id))
+            .exec(connection)
+            .await?;
+
+        if session.revoked_at.is_none() {
+            let mut active_session: SessionActiveModel = session.into();
+            active_session.revoked_at = Set(Some(Utc::now().into()));
+            active_session.update(connection).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn issue_refresh_token<C>(session_id: i64, connection: &C) -> Result<String, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let refresh_token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+        let refresh_token_to_be_inserted = RefreshTokenActiveModel {
+            session_id: Set(session_id),
+            token_hash: Set(Self::hash(&refresh_token)),
+            ..Default::default()
+        };
+
+        RefreshTokenEntity::insert(refresh_token_to_be_inserted)
+            .exec(connection)
+            .await?;
+
+        Ok(refresh_token)
+    }
+
+    fn is_active(session: &SessionModel, now: DateTime<Utc>) -> bool {
+        session.revoked_at.is_none() && DateTime::<Utc>::from(session.expires_at) > now
+    }
+
+    fn expiration(ttl: i64) -> DateTime<Utc> {
+        Utc::now() + Duration::try_seconds(ttl).unwrap_or_default()
+    }
+
+    fn hash<T>(token: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        format!("{:x}", Sha256::digest(token.as_ref().as_bytes()))
+    }
+}
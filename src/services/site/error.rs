@@ -1,3 +1,4 @@
+use crate::services::auth::error::ServiceError as AuthServiceError;
 use axum::http::StatusCode;
 use sea_orm::DbErr;
 use std::fmt::Debug;
@@ -14,6 +15,16 @@ pub enum ServiceError {
     SubdomainIsOwnedByAnotherUser,
     #[error("Subdomain provided in x-subdomain header was not found")]
     SubdomainWasNotFound,
+    #[error(transparent)]
+    AuthServiceError(#[from] AuthServiceError),
+    #[error("Username {0} is provided more than once")]
+    DuplicatedUsername(String),
+    #[error("Site was disabled by administrator")]
+    SiteIsBlocked,
+    #[error("File {0} was not found")]
+    FileWasNotFound(String),
+    #[error("Subdomain {0} has the form of preview name and can not be taken")]
+    SubdomainNameIsReserved(String),
 }
 
 impl From<ServiceError> for StatusCode {
@@ -24,6 +35,11 @@ impl From<ServiceError> for StatusCode {
             ServiceError::ArchiveNotFound => Self::NOT_FOUND,
             ServiceError::SubdomainIsOwnedByAnotherUser => Self::FORBIDDEN,
             ServiceError::SubdomainWasNotFound => Self::NOT_FOUND,
+            ServiceError::AuthServiceError(error) => Self::from(error),
+            ServiceError::DuplicatedUsername(_) => Self::BAD_REQUEST,
+            ServiceError::SiteIsBlocked => Self::FORBIDDEN,
+            ServiceError::FileWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::SubdomainNameIsReserved(_) => Self::BAD_REQUEST,
         }
     }
 }
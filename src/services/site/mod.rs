@@ -1,3 +1,4 @@
 pub mod error;
+pub mod models;
 pub mod parameters;
 pub mod service;
@@ -0,0 +1,52 @@
+use entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Policy of canonical trailing slash for pages
+/// resolved as `path.html` or `path/index.html`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingSlash {
+    /// Pages are served with and without trailing slash
+    #[default]
+    Ignore,
+    /// `/docs` is redirected to `/docs/`
+    Always,
+    /// `/docs/` is redirected to `/docs`
+    Never,
+}
+
+impl TrailingSlash {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrailingSlash::Ignore => "ignore",
+            TrailingSlash::Always => "always",
+            TrailingSlash::Never => "never",
+        }
+    }
+}
+
+impl FromStr for TrailingSlash {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ignore" => Ok(TrailingSlash::Ignore),
+            "always" => Ok(TrailingSlash::Always),
+            "never" => Ok(TrailingSlash::Never),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Site together with statistics of its deployed files.
+/// Precompressed variants are not counted
+#[derive(Debug, Clone)]
+pub struct SiteSummary {
+    pub subdomain: SubdomainModel,
+    pub files: u64,
+    pub bytes: u64,
+    //* None if archive was never uploaded or is missing on disk
+    pub archive_size: Option<u64>,
+}
@@ -1,11 +1,117 @@
+use super::models::TrailingSlash;
 use axum::http::StatusCode;
-use std::{fmt::Debug, path::PathBuf};
+use chrono::{DateTime, Utc};
+use entity::prelude::*;
+use std::{
+    fmt::{self, Debug},
+    net::IpAddr,
+};
 
 #[derive(Debug)]
 pub struct ActionParameters {
     pub subdomain_id: i64,
 }
 
+#[derive(Debug)]
+pub struct ConfigureParameters {
+    pub subdomain_id: i64,
+    //* None keeps current value
+    pub spa: Option<bool>,
+    pub trailing_slash: Option<TrailingSlash>,
+    pub private: Option<bool>,
+    //* Zero resets the override
+    pub rate_limit: Option<u32>,
+    pub rate_burst: Option<u32>,
+}
+
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct ProtectParameters {
+    pub subdomain_id: i64,
+    //* Replaces all current credentials
+    pub credentials: Vec<Credentials>,
+}
+
+pub struct AuthorizeParameters {
+    pub subdomain_id: i64,
+    //* Credentials provided by the client if any
+    pub credentials: Option<Credentials>,
+    //* Pass issued after previous successful verification
+    pub pass: Option<String>,
+    //* Key passes are signed with
+    pub secret: String,
+    //* Failures are counted per site and client.
+    //* They are not counted if client is unknown
+    pub address: Option<IpAddr>,
+    //* Failures after which client is locked out. Zero disables lockout
+    pub attempts: u32,
+    pub lockout_seconds: i64,
+}
+
+impl Debug for AuthorizeParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthorizeParameters")
+            .field("subdomain_id", &self.subdomain_id)
+            .field("credentials", &self.credentials)
+            .field("pass", &self.pass.as_ref().map(|_| "***"))
+            .field("secret", &"***")
+            .field("address", &self.address)
+            .field("attempts", &self.attempts)
+            .field("lockout_seconds", &self.lockout_seconds)
+            .finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Authorization {
+    //* Site is public or client presented a valid pass
+    Granted,
+    //* Credentials were verified and client should get the pass
+    //* so they are not verified again on every request
+    Verified(String),
+    //* Credentials are missing or wrong
+    Denied,
+    //* Client failed too many times and is not checked until then
+    Locked(DateTime<Utc>),
+}
+
+#[derive(Debug)]
+pub struct ListParameters {
+    pub owner_id: i64,
+    //* Pages are counted from one
+    pub page: u64,
+    pub per_page: u64,
+}
+
+#[derive(Debug)]
+pub struct ListFilesParameters {
+    pub subdomain_id: i64,
+    //* Only files with user path starting with prefix are listed
+    pub prefix: Option<String>,
+    //* Pages are counted from one
+    pub page: u64,
+    pub per_page: u64,
+}
+
+#[derive(Debug)]
+pub struct ShareParameters {
+    pub subdomain_id: i64,
+    pub expires_at: DateTime<Utc>,
+}
+
 #[derive(Debug)]
 pub struct AssociateParameters<T>
 where
@@ -24,27 +130,70 @@ where
     pub subdomain_id: i64,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum SiteRedirect {
+    //* Client should be redirected to location
+    //* Query parameters matched by the rule are not passed through
+    Redirect {
+        location: String,
+        status: StatusCode,
+        force: bool,
+        matched: Vec<String>,
+    },
+    //* Path should be served instead of requested one
+    Rewrite {
+        path: String,
+        status: StatusCode,
+        force: bool,
+    },
+}
+
+impl SiteRedirect {
+    //* Forced rule is applied even if requested file exists
+    pub fn is_forced(&self) -> bool {
+        match self {
+            SiteRedirect::Redirect { force, .. } => *force,
+            SiteRedirect::Rewrite { force, .. } => *force,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum SiteFile {
     //* File was successfully retrieved
-    Found(PathBuf),
+    Found(FileModel),
+    //* File was not found and index.html
+    //* of single page application is served instead
+    Fallback(FileModel),
     //* Subdomain is disabled
-    //* Some(path) means there is 503.html
+    //* Some(file) means there is 503.html
     //* None means there is no 503.html
-    Disabled(Option<PathBuf>),
+    Disabled(Option<FileModel>),
 
     //* File was not found
-    //* Some(path) means there is 404.html
+    //* Some(file) means there is 404.html
     //* None means there is no 404.html
-    NotFound(Option<PathBuf>),
+    NotFound(Option<FileModel>),
+
+    //* Client is not allowed to reach the site
+    //* Some(file) means there is 403.html
+    //* None means there is no 403.html
+    Forbidden(Option<FileModel>),
+
+    //* Path is not canonical according to
+    //* trailing slash policy of the site
+    Moved(String),
 }
 
 impl SiteFile {
-    pub fn path(&self) -> Option<&PathBuf> {
+    pub fn file(&self) -> Option<&FileModel> {
         match self {
-            SiteFile::Found(path) => Some(path),
+            SiteFile::Found(file) => Some(file),
+            SiteFile::Fallback(file) => Some(file),
             SiteFile::NotFound(inner) => inner.as_ref(),
             SiteFile::Disabled(inner) => inner.as_ref(),
+            SiteFile::Forbidden(inner) => inner.as_ref(),
+            SiteFile::Moved(_) => None,
         }
     }
 }
@@ -53,8 +202,11 @@ impl From<&SiteFile> for StatusCode {
     fn from(value: &SiteFile) -> Self {
         match value {
             SiteFile::Found(_) => StatusCode::OK,
+            SiteFile::Fallback(_) => StatusCode::OK,
             SiteFile::Disabled(_) => StatusCode::SERVICE_UNAVAILABLE,
             SiteFile::NotFound(_) => StatusCode::NOT_FOUND,
+            SiteFile::Forbidden(_) => StatusCode::FORBIDDEN,
+            SiteFile::Moved(_) => StatusCode::MOVED_PERMANENTLY,
         }
     }
 }
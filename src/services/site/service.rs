@@ -1,14 +1,56 @@
-use super::{error::ServiceError, parameters::*};
+use super::{
+    error::ServiceError,
+    models::{SiteSummary, TrailingSlash},
+    parameters::*,
+};
+use crate::{
+    http::{
+        basic, host,
+        pattern::{self, Pattern},
+    },
+    services::auth::{
+        parameters::LockoutParameters,
+        service::{Service as AuthService, SITE_ATTEMPT},
+    },
+};
+use axum::http::StatusCode;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
 use entity::prelude::*;
 use futures::Stream;
-use sea_orm::{prelude::*, ConnectionTrait, ModelTrait, Set, StreamTrait, TransactionTrait};
-use std::{fmt::Debug, path::PathBuf};
+use hmac::{Hmac, Mac};
+use sea_orm::{
+    prelude::*,
+    sea_query::{Alias, LikeExpr, Query, SelectStatement},
+    ConnectionTrait, FromQueryResult, ModelTrait, PaginatorTrait, QueryOrder, QuerySelect, Set, StreamTrait,
+    TransactionTrait,
+};
+use sha2::Sha256;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    path::Path,
+    sync::OnceLock,
+};
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(FromQueryResult)]
+struct FileStatistics {
+    subdomain_id: Option<i64>,
+    files: i64,
+    bytes: Option<i64>,
+}
 
 pub struct Service;
 
 impl Service {
+    /// Removes the site and marks its files as obsolete.
+    /// Returns amount of obsolete files and archives of every deployment
+    /// which must be removed by caller after commit
     #[tracing::instrument(skip(connection))]
-    pub async fn teardown<C, P>(parameters: P, connection: &C) -> Result<u64, ServiceError>
+    pub async fn teardown<C, P>(parameters: P, connection: &C) -> Result<(u64, Vec<String>), ServiceError>
     where
         C: ConnectionTrait + TransactionTrait,
         P: Into<ActionParameters> + Debug,
@@ -27,9 +69,27 @@ impl Service {
             .await?
             .rows_affected;
 
+        //? Deployments are removed together with the site
+        //? so their archives are collected beforehand
+        let mut archives_to_be_removed = DeploymentEntity::find()
+            .filter(DeploymentColumn::SubdomainId.eq(subdomain.id))
+            .all(connection)
+            .await?
+            .into_iter()
+            .filter_map(|deployment| deployment.archive_path)
+            .collect::<Vec<_>>();
+
+        //? Sites uploaded before deployments were tracked
+        //? have only the archive of the site itself
+        if let Some(archive_path) = subdomain.archive_path.clone() {
+            if !archives_to_be_removed.contains(&archive_path) {
+                archives_to_be_removed.push(archive_path);
+            }
+        }
+
         subdomain.delete(connection).await?;
 
-        Ok(files_to_be_removed)
+        Ok((files_to_be_removed, archives_to_be_removed))
     }
 
     #[tracing::instrument(skip(connection))]
@@ -47,64 +107,618 @@ impl Service {
             .and_then(|subdomain| subdomain.archive_path.ok_or(ServiceError::ArchiveNotFound))
     }
 
+    /// Resolves requested path to a file of the site.
+    /// Lookup order is `path`, `path.html` and `path/index.html`
+    /// so clean urls of static site generators are supported
+    /// Files of active deployment of the provided site are served
     #[tracing::instrument(skip(connection))]
-    pub async fn file<C, P, S>(parameters: P, connection: &C) -> Result<SiteFile, ServiceError>
+    pub async fn file<C, S>(subdomain: &SubdomainModel, path: S, connection: &C) -> Result<SiteFile, ServiceError>
     where
         C: ConnectionTrait + TransactionTrait,
-        P: Into<FileSearchParameters<S>> + Debug,
-        S: AsRef<str>,
+        S: AsRef<str> + Debug,
     {
-        let provided_parameters = parameters.into();
+        if !subdomain.enabled {
+            return match subdomain
+                .find_related(FileEntity)
+                .filter(FileColumn::DeploymentId.eq(subdomain.active_deployment_id))
+                .filter(FileColumn::Encoding.is_null())
+                .filter(FileColumn::UserPath.eq("503.html"))
+                .one(connection)
+                .await?
+            {
+                Some(file) => Ok(SiteFile::Disabled(Some(file))),
+                None => Ok(SiteFile::Disabled(None)),
+            };
+        }
 
-        let subdomain = match SubdomainEntity::find_by_id(provided_parameters.subdomain_id)
+        let requested = path.as_ref();
+        let has_trailing_slash = requested.ends_with('/');
+        let base = requested.trim_matches('/');
+
+        let candidates = match base.is_empty() {
+            true => vec![String::from("index.html")],
+            false => vec![base.to_owned(), format!("{base}.html"), format!("{base}/index.html")],
+        };
+
+        let found = subdomain
+            .find_related(FileEntity)
+            .filter(FileColumn::DeploymentId.eq(subdomain.active_deployment_id))
+            .filter(FileColumn::Encoding.is_null())
+            .filter(FileColumn::UserPath.is_in(candidates.clone()))
+            .all(connection)
+            .await?;
+
+        //? The first candidate in lookup order wins
+        let resolved = candidates.iter().enumerate().find_map(|(position, candidate)| {
+            found
+                .iter()
+                .find(|file| &file.user_path == candidate)
+                .map(|file| (position, file.clone()))
+        });
+
+        match resolved {
+            Some((position, file)) => {
+                let policy = subdomain.trailing_slash.parse().unwrap_or_default();
+
+                //? Only pages resolved with lookup are redirected
+                //? Exact files are always served as requested
+                let is_page = position > 0 && !base.is_empty();
+
+                Ok(match (is_page, policy, has_trailing_slash) {
+                    (true, TrailingSlash::Always, false) => SiteFile::Moved(format!("/{base}/")),
+                    (true, TrailingSlash::Never, true) => SiteFile::Moved(format!("/{base}")),
+                    _ => SiteFile::Found(file),
+                })
+            }
+            None if subdomain.spa && Path::new(base).extension().is_none() => {
+                //? Client-side routes of single page applications
+                //? are served with index.html while assets still 404
+                match subdomain
+                    .find_related(FileEntity)
+                    .filter(FileColumn::DeploymentId.eq(subdomain.active_deployment_id))
+                    .filter(FileColumn::Encoding.is_null())
+                    .filter(FileColumn::UserPath.eq("index.html"))
+                    .one(connection)
+                    .await?
+                {
+                    Some(file) => Ok(SiteFile::Fallback(file)),
+                    None => Self::not_found(subdomain, connection).await,
+                }
+            }
+            None => Self::not_found(subdomain, connection).await,
+        }
+    }
+
+    async fn not_found<C>(subdomain: &SubdomainModel, connection: &C) -> Result<SiteFile, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        match subdomain
+            .find_related(FileEntity)
+            .filter(FileColumn::DeploymentId.eq(subdomain.active_deployment_id))
+            .filter(FileColumn::Encoding.is_null())
+            .filter(FileColumn::UserPath.eq("404.html"))
             .one(connection)
             .await?
         {
-            Some(subdomain) => Ok(subdomain),
-            None => Err(ServiceError::SubdomainWasNotFound),
-        }?;
+            Some(file) => Ok(SiteFile::NotFound(Some(file))),
+            None => Ok(SiteFile::NotFound(None)),
+        }
+    }
+
+    /// Resolves custom 403.html of the site
+    /// for clients which are not allowed to reach it
+    #[tracing::instrument(skip(connection))]
+    pub async fn forbidden<C>(subdomain: &SubdomainModel, connection: &C) -> Result<SiteFile, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        match FileEntity::find()
+            .filter(FileColumn::DeploymentId.eq(subdomain.active_deployment_id))
+            .filter(FileColumn::Encoding.is_null())
+            .filter(FileColumn::UserPath.eq("403.html"))
+            .one(connection)
+            .await?
+        {
+            Some(file) => Ok(SiteFile::Forbidden(Some(file))),
+            None => Ok(SiteFile::Forbidden(None)),
+        }
+    }
+
+    /// Evaluates redirect rules of the subdomain in declaration order.
+    /// The first rule matching both path and query wins.
+    #[tracing::instrument(skip(connection))]
+    pub async fn redirect<C, S>(
+        subdomain: &SubdomainModel,
+        path: S,
+        query: &HashMap<String, String>,
+        connection: &C,
+    ) -> Result<Option<SiteRedirect>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        S: AsRef<str> + Debug,
+    {
+        let rules = RedirectEntity::find()
+            .filter(RedirectColumn::DeploymentId.eq(subdomain.active_deployment_id))
+            .order_by_asc(RedirectColumn::Id)
+            .all(connection)
+            .await?;
 
-        let parsed_path = PathBuf::from(provided_parameters.path.as_ref());
+        for rule in rules {
+            let Some(mut captures) = rule
+                .source
+                .parse::<Pattern>()
+                .ok()
+                .and_then(|source| source.captures(path.as_ref()))
+            else {
+                continue;
+            };
 
-        let path = match parsed_path.extension() {
-            Some(_) => provided_parameters.path.as_ref().to_owned(),
-            None => parsed_path
-                .with_extension("html")
-                .to_str()
+            let conditions = rule
+                .query
+                .as_deref()
                 .unwrap_or_default()
-                .to_owned(),
+                .split('&')
+                .filter_map(|condition| condition.split_once('='))
+                .collect::<Vec<_>>();
+
+            //? Condition value starting with `:` captures
+            //? parameter while any other requires exact match
+            let conditions_are_met = conditions.iter().all(|&(key, expected)| match query.get(key) {
+                Some(value) => match expected.strip_prefix(':') {
+                    Some(name) => {
+                        captures.insert(name.to_owned(), value.clone());
+                        true
+                    }
+                    None => expected == value,
+                },
+                None => false,
+            });
+
+            if !conditions_are_met {
+                continue;
+            }
+
+            let destination = pattern::substitute(&rule.destination, &captures);
+            let status = u16::try_from(rule.status)
+                .ok()
+                .and_then(|status| StatusCode::from_u16(status).ok())
+                .unwrap_or(StatusCode::MOVED_PERMANENTLY);
+
+            tracing::trace!(%rule.id, %destination, %status, %rule.force, "Redirect rule matched");
+
+            return Ok(Some(match status.is_redirection() {
+                true => SiteRedirect::Redirect {
+                    location: destination,
+                    status,
+                    force: rule.force,
+                    matched: conditions.into_iter().map(|(key, _)| key.to_owned()).collect(),
+                },
+                false => SiteRedirect::Rewrite {
+                    path: destination,
+                    status,
+                    force: rule.force,
+                },
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Finds header rules of the subdomain matching the path
+    /// Rules are returned in the order they were declared
+    #[tracing::instrument(skip(connection))]
+    pub async fn headers<C, S>(
+        subdomain: &SubdomainModel,
+        path: S,
+        connection: &C,
+    ) -> Result<Vec<HeaderModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        S: AsRef<str> + Debug,
+    {
+        Ok(HeaderEntity::find()
+            .filter(HeaderColumn::DeploymentId.eq(subdomain.active_deployment_id))
+            .order_by_asc(HeaderColumn::Id)
+            .all(connection)
+            .await?
+            .into_iter()
+            .filter(|rule| {
+                rule.pattern
+                    .parse::<Pattern>()
+                    .is_ok_and(|pattern| pattern.matches(path.as_ref()))
+            })
+            .collect())
+    }
+
+    /// Finds precompressed variants of the file
+    #[tracing::instrument(skip(connection))]
+    pub async fn variants<C>(file: &FileModel, connection: &C) -> Result<Vec<FileModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(FileEntity::find()
+            .filter(FileColumn::DeploymentId.eq(file.deployment_id))
+            .filter(FileColumn::UserPath.eq(&file.user_path))
+            .filter(FileColumn::Encoding.is_not_null())
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn configure<C, P>(parameters: P, connection: &C) -> Result<SubdomainModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ConfigureParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let subdomain = SubdomainEntity::find_by_id(provided_parameters.subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound)?;
+
+        let mut active_subdomain: SubdomainActiveModel = subdomain.into();
+
+        if let Some(spa) = provided_parameters.spa {
+            active_subdomain.spa = Set(spa);
+        }
+
+        if let Some(trailing_slash) = provided_parameters.trailing_slash {
+            active_subdomain.trailing_slash = Set(trailing_slash.as_str().to_owned());
+        }
+
+        if let Some(private) = provided_parameters.private {
+            active_subdomain.private = Set(private);
+        }
+
+        if let Some(rate_limit) = provided_parameters.rate_limit {
+            active_subdomain.rate_limit = Set(Self::override_of(rate_limit));
+        }
+
+        if let Some(rate_burst) = provided_parameters.rate_burst {
+            active_subdomain.rate_burst = Set(Self::override_of(rate_burst));
+        }
+
+        active_subdomain.updated_at = Set(Utc::now().into());
+
+        Ok(active_subdomain.update(connection).await?)
+    }
+
+    //? Zero resets override to the server wide limit
+    fn override_of(value: u32) -> Option<i32> {
+        (value != 0).then(|| i32::try_from(value).unwrap_or(i32::MAX))
+    }
+
+    /// Replaces credentials required to view the site.
+    /// Passwords are hashed with argon2 the same way user passwords are.
+    #[tracing::instrument(skip(connection))]
+    pub async fn protect<C, P>(parameters: P, connection: &C) -> Result<Vec<CredentialModel>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ProtectParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let mut usernames = HashSet::new();
+        for credentials in provided_parameters.credentials.iter() {
+            if !usernames.insert(credentials.username.as_str()) {
+                return Err(ServiceError::DuplicatedUsername(credentials.username.clone()));
+            }
+        }
+
+        CredentialEntity::delete_many()
+            .filter(CredentialColumn::SubdomainId.eq(provided_parameters.subdomain_id))
+            .exec(connection)
+            .await?;
+
+        let credentials_to_be_inserted = provided_parameters
+            .credentials
+            .into_iter()
+            .map(|credentials| {
+                Ok(CredentialActiveModel {
+                    subdomain_id: Set(provided_parameters.subdomain_id),
+                    username: Set(credentials.username),
+                    password: Set(AuthService::hash_password(credentials.password)?),
+                    ..Default::default()
+                })
+            })
+            .collect::<Result<Vec<_>, ServiceError>>()?;
+
+        //? insert_many fails on empty iterator
+        if !credentials_to_be_inserted.is_empty() {
+            CredentialEntity::insert_many(credentials_to_be_inserted)
+                .exec(connection)
+                .await?;
+        }
+
+        Ok(CredentialEntity::find()
+            .filter(CredentialColumn::SubdomainId.eq(provided_parameters.subdomain_id))
+            .order_by_asc(CredentialColumn::Id)
+            .all(connection)
+            .await?)
+    }
+
+    /// Makes the site public again
+    #[tracing::instrument(skip(connection))]
+    pub async fn unprotect<C, P>(parameters: P, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ActionParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        Ok(CredentialEntity::delete_many()
+            .filter(CredentialColumn::SubdomainId.eq(provided_parameters.subdomain_id))
+            .exec(connection)
+            .await?
+            .rows_affected)
+    }
+
+    /// Checks if client may view the site.
+    /// Site without credentials is public.
+    /// Valid pass is accepted instead of credentials. Otherwise credentials
+    /// are verified on blocking pool and a new pass is returned on success
+    #[tracing::instrument(skip(connection))]
+    pub async fn authorize<C, P>(parameters: P, connection: &C) -> Result<Authorization, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<AuthorizeParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let credentials = CredentialEntity::find()
+            .filter(CredentialColumn::SubdomainId.eq(provided_parameters.subdomain_id))
+            .all(connection)
+            .await?;
+
+        if credentials.is_empty() {
+            return Ok(Authorization::Granted);
+        }
+
+        let is_passed = provided_parameters.pass.as_deref().is_some_and(|pass| {
+            Self::is_valid_pass(
+                &provided_parameters.secret,
+                provided_parameters.subdomain_id,
+                &credentials,
+                pass,
+            )
+        });
+
+        if is_passed {
+            return Ok(Authorization::Granted);
+        }
+
+        let Some(provided) = provided_parameters.credentials else {
+            return Ok(Authorization::Denied);
+        };
+
+        let subject = provided_parameters
+            .address
+            .map(|address| format!("{}:{address}", provided_parameters.subdomain_id));
+
+        //? Password is not verified during lockout
+        //? so guesses can not be checked at all
+        if let Some(subject) = subject.as_deref() {
+            if let Some(locked_until) = AuthService::locked_until(SITE_ATTEMPT, subject, connection).await? {
+                return Ok(Authorization::Locked(locked_until));
+            }
+        }
+
+        let stored = credentials
+            .into_iter()
+            .find(|credentials| credentials.username == provided.username);
+
+        //? Only one hash is verified as argon2 is slow on purpose
+        //? and it is done on blocking pool to not stall the runtime.
+        //? Unknown username is checked against dummy hash
+        //? so it takes as long as a wrong password
+        let hash = stored.as_ref().map(|stored| stored.password.clone());
+        let is_verified = tokio::task::spawn_blocking(move || {
+            let hash = match hash {
+                Some(hash) => hash,
+                None => Self::dummy_hash().ok()?,
+            };
+            Some(AuthService::verify_password(&provided.password, &hash).is_ok())
+        })
+        .await
+        .inspect_err(|cause| tracing::warn!(%cause, "Failed to verify credentials!"))
+        .ok()
+        .flatten()
+        .unwrap_or(false);
+
+        let stored = match stored.filter(|_| is_verified) {
+            Some(stored) => stored,
+            None => {
+                if let Some(subject) = subject.as_deref() {
+                    let lockout = LockoutParameters {
+                        address: provided_parameters.address,
+                        attempts_per_login: 0,
+                        attempts_per_address: provided_parameters.attempts,
+                        lockout_seconds: provided_parameters.lockout_seconds,
+                    };
+                    AuthService::fail(
+                        SITE_ATTEMPT,
+                        subject,
+                        provided_parameters.attempts,
+                        &lockout,
+                        connection,
+                    )
+                    .await?;
+                }
+
+                return Ok(Authorization::Denied);
+            }
+        };
+
+        if let Some(subject) = subject.as_deref() {
+            AuthService::forget_failures(SITE_ATTEMPT, subject, connection).await?;
+        }
+
+        let expiration =
+            (Utc::now() + chrono::Duration::try_seconds(basic::PASS_TTL_SECONDS).unwrap_or_default()).timestamp();
+        let signature = Self::pass_signature(
+            &provided_parameters.secret,
+            provided_parameters.subdomain_id,
+            &stored,
+            expiration,
+        )
+        .finalize()
+        .into_bytes();
+
+        Ok(Authorization::Verified(format!(
+            "{}.{expiration}.{}",
+            URL_SAFE_NO_PAD.encode(&stored.username),
+            URL_SAFE_NO_PAD.encode(signature)
+        )))
+    }
+
+    //? Hash is computed once with the same parameters as real ones
+    fn dummy_hash() -> Result<String, ServiceError> {
+        static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+        if let Some(hash) = DUMMY_HASH.get() {
+            return Ok(hash.clone());
+        }
+
+        let hash = AuthService::hash_password(Uuid::new_v4().to_string())?;
+        Ok(DUMMY_HASH.get_or_init(|| hash).clone())
+    }
+
+    /// Pass is `<username>.<expiration>.<signature>` where signature is HMAC-SHA256
+    /// of subdomain id, username, password hash and expiration.
+    /// Changing credentials of the site invalidates issued passes
+    fn is_valid_pass(secret: &str, subdomain_id: i64, credentials: &[CredentialModel], pass: &str) -> bool {
+        let mut parts = pass.splitn(3, '.');
+
+        let (Some(username), Some(expiration), Some(signature)) = (parts.next(), parts.next(), parts.next()) else {
+            return false;
         };
 
-        let files_filter = match subdomain.enabled {
-            true => FileColumn::UserPath.eq(path),
-            false => FileColumn::UserPath.eq("503.html"),
+        let Some(username) = URL_SAFE_NO_PAD
+            .decode(username)
+            .ok()
+            .and_then(|username| String::from_utf8(username).ok())
+        else {
+            return false;
         };
 
-        match subdomain
-            .find_related(FileEntity)
-            .filter(FileColumn::Obsolete.eq(false))
-            .filter(files_filter)
+        let (Ok(expiration), Ok(signature)) = (expiration.parse::<i64>(), URL_SAFE_NO_PAD.decode(signature)) else {
+            return false;
+        };
+
+        let Some(stored) = credentials.iter().find(|credentials| credentials.username == username) else {
+            return false;
+        };
+
+        //? Comparison is done in constant time
+        Self::pass_signature(secret, subdomain_id, stored, expiration)
+            .verify_slice(&signature)
+            .is_ok()
+            && DateTime::from_timestamp(expiration, 0).is_some_and(|expires_at| expires_at > Utc::now())
+    }
+
+    fn pass_signature(secret: &str, subdomain_id: i64, credentials: &CredentialModel, expiration: i64) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(
+            format!(
+                "pass.{subdomain_id}.{}.{}.{expiration}",
+                URL_SAFE_NO_PAD.encode(&credentials.username),
+                credentials.password
+            )
+            .as_bytes(),
+        );
+        mac
+    }
+
+    /// Mints a share link token of a private site.
+    /// Token is `<expiration>.<signature>` where signature is HMAC-SHA256
+    /// of subdomain id and expiration keyed with the secret of the site.
+    /// The secret is generated when the first token is minted.
+    #[tracing::instrument(skip(connection))]
+    pub async fn share<C, P>(parameters: P, connection: &C) -> Result<String, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ShareParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let subdomain = SubdomainEntity::find_by_id(provided_parameters.subdomain_id)
             .one(connection)
             .await?
-        {
-            Some(file) => Ok(match subdomain.enabled {
-                true => SiteFile::Found(file.real_path.into()),
-                false => SiteFile::Disabled(Some(file.real_path.into())),
-            }),
-            None => match subdomain.enabled {
-                true => match subdomain
-                    .find_related(FileEntity)
-                    .filter(FileColumn::Obsolete.eq(false))
-                    .filter(FileColumn::UserPath.eq("404.html"))
-                    .one(connection)
-                    .await?
-                {
-                    Some(file) => Ok(SiteFile::NotFound(Some(file.real_path.into()))),
-                    None => Ok(SiteFile::NotFound(None)),
-                },
-                false => Ok(SiteFile::Disabled(None)),
-            },
-        }
+            .ok_or(ServiceError::SubdomainWasNotFound)?;
+
+        let secret = match subdomain.share_secret.clone() {
+            Some(secret) => secret,
+            None => {
+                let secret = Self::share_secret();
+
+                let mut active_subdomain: SubdomainActiveModel = subdomain.clone().into();
+                active_subdomain.share_secret = Set(Some(secret.clone()));
+                active_subdomain.update(connection).await?;
+
+                secret
+            }
+        };
+
+        let expiration = provided_parameters.expires_at.timestamp();
+        let signature = Self::share_signature(&secret, subdomain.id, expiration)
+            .finalize()
+            .into_bytes();
+
+        Ok(format!("{expiration}.{}", URL_SAFE_NO_PAD.encode(signature)))
+    }
+
+    /// Invalidates all share links of the site by rotating its secret
+    #[tracing::instrument(skip(connection))]
+    pub async fn revoke<C, P>(parameters: P, connection: &C) -> Result<(), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ActionParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let subdomain = SubdomainEntity::find_by_id(provided_parameters.subdomain_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::SubdomainWasNotFound)?;
+
+        let mut active_subdomain: SubdomainActiveModel = subdomain.into();
+        active_subdomain.share_secret = Set(Some(Self::share_secret()));
+        active_subdomain.update(connection).await?;
+
+        Ok(())
+    }
+
+    /// Returns expiration of share link token
+    /// if it was minted for the site with its current secret and is not expired yet
+    pub fn shared_until<T>(subdomain: &SubdomainModel, token: T) -> Option<DateTime<Utc>>
+    where
+        T: AsRef<str>,
+    {
+        let secret = subdomain.share_secret.as_ref()?;
+
+        let (expiration, signature) = token.as_ref().split_once('.')?;
+        let expiration = expiration.parse::<i64>().ok()?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+
+        //? Comparison is done in constant time
+        Self::share_signature(secret, subdomain.id, expiration)
+            .verify_slice(&signature)
+            .ok()?;
+
+        DateTime::from_timestamp(expiration, 0).filter(|expires_at| *expires_at > Utc::now())
+    }
+
+    fn share_signature(secret: &str, subdomain_id: i64, expiration: i64) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(format!("{subdomain_id}.{expiration}").as_bytes());
+        mac
+    }
+
+    fn share_secret() -> String {
+        //? Two v4 uuids carry 244 random bits
+        format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
     }
 
     #[tracing::instrument(skip(connection))]
@@ -123,9 +737,14 @@ impl Service {
             None => Err(ServiceError::SubdomainWasNotFound),
         }?;
 
+        if subdomain.blocked {
+            return Err(ServiceError::SiteIsBlocked);
+        }
+
         let mut active_subdomain: SubdomainActiveModel = subdomain.into();
 
         active_subdomain.enabled = Set(true);
+        active_subdomain.updated_at = Set(Utc::now().into());
         active_subdomain.update(connection).await?;
 
         Ok(())
@@ -150,6 +769,7 @@ impl Service {
         let mut active_subdomain: SubdomainActiveModel = subdomain.into();
 
         active_subdomain.enabled = Set(false);
+        active_subdomain.updated_at = Set(Utc::now().into());
         active_subdomain.update(connection).await?;
 
         Ok(())
@@ -171,6 +791,10 @@ impl Service {
         {
             Some(subdomain) if subdomain.owner_id == provided_parameters.user_id => Ok(subdomain),
             Some(_) => Err(ServiceError::SubdomainIsOwnedByAnotherUser),
+            //? Such names are resolved as previews of other sites
+            None if host::preview(provided_parameters.subdomain_name.as_ref()).is_some() => Err(
+                ServiceError::SubdomainNameIsReserved(provided_parameters.subdomain_name.as_ref().to_owned()),
+            ),
             None => {
                 let subdomain_to_be_inserted = entity::subdomain::ActiveModel {
                     owner_id: Set(provided_parameters.user_id),
@@ -185,6 +809,135 @@ impl Service {
         }
     }
 
+    /// Sites of the owner in order of creation and total amount of them
+    #[tracing::instrument(skip(connection))]
+    pub async fn list<C, P>(parameters: P, connection: &C) -> Result<(Vec<SiteSummary>, u64), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ListParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let paginator = SubdomainEntity::find()
+            .filter(SubdomainColumn::OwnerId.eq(provided_parameters.owner_id))
+            .order_by_asc(SubdomainColumn::Id)
+            .paginate(connection, provided_parameters.per_page);
+
+        let total = paginator.num_items().await?;
+        let subdomains = paginator.fetch_page(provided_parameters.page.saturating_sub(1)).await?;
+
+        //? Sum of bigint is numeric in postgres
+        let statistics = FileEntity::find()
+            .select_only()
+            .column(FileColumn::SubdomainId)
+            .column_as(Expr::col(FileColumn::Id).count(), "files")
+            .column_as(
+                Expr::expr(Expr::col(FileColumn::Size).sum()).cast_as(Alias::new("bigint")),
+                "bytes",
+            )
+            .filter(FileColumn::DeploymentId.in_subquery(Self::active(subdomains.iter().map(|subdomain| subdomain.id))))
+            .filter(FileColumn::Encoding.is_null())
+            .group_by(FileColumn::SubdomainId)
+            .into_model::<FileStatistics>()
+            .all(connection)
+            .await?;
+
+        let mut summaries = Vec::with_capacity(subdomains.len());
+
+        for subdomain in subdomains {
+            let statistic = statistics
+                .iter()
+                .find(|statistic| statistic.subdomain_id == Some(subdomain.id));
+
+            let archive_size = match subdomain.archive_path.as_ref() {
+                Some(archive_path) => tokio::fs::metadata(archive_path)
+                    .await
+                    .ok()
+                    .map(|metadata| metadata.len()),
+                None => None,
+            };
+
+            summaries.push(SiteSummary {
+                files: statistic.map_or(0, |statistic| statistic.files.max(0) as u64),
+                bytes: statistic
+                    .and_then(|statistic| statistic.bytes)
+                    .map_or(0, |bytes| bytes.max(0) as u64),
+                archive_size,
+                subdomain,
+            });
+        }
+
+        Ok((summaries, total))
+    }
+
+    /// Deployed files of the subdomain in order of their paths and total amount of them.
+    /// Precompressed variants are not listed
+    #[tracing::instrument(skip(connection))]
+    pub async fn files<C, P>(parameters: P, connection: &C) -> Result<(Vec<FileModel>, u64), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<ListFilesParameters> + Debug,
+    {
+        let provided_parameters = parameters.into();
+
+        let mut query = FileEntity::find()
+            .filter(FileColumn::DeploymentId.in_subquery(Self::active([provided_parameters.subdomain_id])))
+            .filter(FileColumn::Encoding.is_null());
+
+        if let Some(prefix) = provided_parameters
+            .prefix
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty())
+        {
+            //? Wildcards of LIKE must match literally
+            let escaped = prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+            query =
+                query.filter(Expr::col(FileColumn::UserPath).like(LikeExpr::new(format!("{escaped}%")).escape('\\')));
+        }
+
+        let paginator = query
+            .order_by_asc(FileColumn::UserPath)
+            .paginate(connection, provided_parameters.per_page);
+
+        let total = paginator.num_items().await?;
+        let files = paginator.fetch_page(provided_parameters.page.saturating_sub(1)).await?;
+
+        Ok((files, total))
+    }
+
+    /// Deployed file of the subdomain by its exact user path.
+    /// Unlike `file` it does not take state of the site into account
+    #[tracing::instrument(skip(connection))]
+    pub async fn deployed<C, P, S>(parameters: P, connection: &C) -> Result<FileModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        P: Into<FileSearchParameters<S>> + Debug,
+        S: AsRef<str>,
+    {
+        let provided_parameters = parameters.into();
+        let path = provided_parameters.path.as_ref().trim_start_matches('/');
+
+        FileEntity::find()
+            .filter(FileColumn::DeploymentId.in_subquery(Self::active([provided_parameters.subdomain_id])))
+            .filter(FileColumn::Encoding.is_null())
+            .filter(FileColumn::UserPath.eq(path))
+            .one(connection)
+            .await?
+            .ok_or_else(|| ServiceError::FileWasNotFound(path.to_owned()))
+    }
+
+    //? Only files and rules of active deployment are served
+    fn active<I>(subdomain_ids: I) -> SelectStatement
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        Query::select()
+            .column(SubdomainColumn::ActiveDeploymentId)
+            .from(SubdomainEntity)
+            .and_where(SubdomainColumn::Id.is_in(subdomain_ids))
+            .to_owned()
+    }
+
     pub async fn obsolete<C>(connection: &C) -> Result<impl Stream<Item = Result<FileModel, DbErr>> + '_, ServiceError>
     where
         C: ConnectionTrait + StreamTrait,
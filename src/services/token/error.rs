@@ -0,0 +1,37 @@
+use axum::http::StatusCode;
+use sea_orm::DbErr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error(transparent)]
+    DatabaseError(#[from] DbErr),
+    #[error("Subdomain with name {0} was not found!")]
+    SubdomainWasNotFound(String),
+    #[error("Subdomain with name {0} is owned by another user!")]
+    SubdomainIsOwnedByAnotherUser(String),
+    #[error("At least one scope must be granted!")]
+    NoScopesWereGranted,
+    #[error("Token with id = {0} was not found!")]
+    TokenWasNotFound(i64),
+    #[error("Token with id = {0} does not belong to user with id {1}!")]
+    TokenDoesNotBelongToUser(i64, i64),
+    #[error("Token is invalid or was revoked!")]
+    TokenIsInvalid,
+    #[error("Token has expired!")]
+    TokenHasExpired,
+}
+
+impl From<ServiceError> for StatusCode {
+    fn from(value: ServiceError) -> Self {
+        match value {
+            ServiceError::DatabaseError(_) => Self::INTERNAL_SERVER_ERROR,
+            ServiceError::SubdomainWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::SubdomainIsOwnedByAnotherUser(_) => Self::FORBIDDEN,
+            ServiceError::NoScopesWereGranted => Self::BAD_REQUEST,
+            ServiceError::TokenWasNotFound(_) => Self::NOT_FOUND,
+            ServiceError::TokenDoesNotBelongToUser(_, _) => Self::FORBIDDEN,
+            ServiceError::TokenIsInvalid => Self::UNAUTHORIZED,
+            ServiceError::TokenHasExpired => Self::UNAUTHORIZED,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use axum::http::Method;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// What personal API token is allowed to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenScope {
+    /// Upload archives, manage previews, roll back and enable or disable sites
+    Deploy,
+    /// Manage allowed origins of sites
+    ManageOrigins,
+    /// Any GET request of management API except tokens and sessions
+    Read,
+}
+
+impl TokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::Deploy => "deploy",
+            TokenScope::ManageOrigins => "manage-origins",
+            TokenScope::Read => "read",
+        }
+    }
+
+    /// Endpoints which reveal or change more than a single site of the owner.
+    /// Tokens restricted to a site are rejected there whatever scopes they have
+    pub fn is_owner_wide<T>(path: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        let path = path.as_ref().trim_end_matches('/');
+        let is_under = |prefix: &str| path == prefix || path.starts_with(&format!("{prefix}/"));
+
+        ["/api/site/list", "/api/token", "/api/auth", "/api/admin"]
+            .into_iter()
+            .any(is_under)
+    }
+
+    /// Checks if the request is covered by the scope.
+    /// Expects full path of the request.
+    pub fn permits<T>(&self, method: &Method, path: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        let path = path.as_ref().trim_end_matches('/');
+        let is_under = |prefix: &str| path == prefix || path.starts_with(&format!("{prefix}/"));
+
+        match self {
+            TokenScope::Deploy => {
+                let segments = path.split('/').collect::<Vec<_>>();
+
+                matches!(
+                    (method, path),
+                    (&Method::POST, "/api/site" | "/api/site/previews")
+                        | (&Method::PATCH, "/api/site/enable" | "/api/site/disable")
+                ) || matches!(
+                    (method, segments.as_slice()),
+                    (&Method::PATCH, ["", "api", "site", "previews", id, "promote"])
+                        | (&Method::PATCH, ["", "api", "site", "deployments", id, "rollback"])
+                        | (&Method::DELETE, ["", "api", "site", "previews", id])
+                        if !id.is_empty()
+                )
+            }
+            TokenScope::ManageOrigins => is_under("/api/origin"),
+            //? Tokens can not be used to see other tokens or sessions
+            TokenScope::Read => {
+                method == Method::GET && is_under("/api") && !is_under("/api/token") && !is_under("/api/auth")
+            }
+        }
+    }
+}
+
+impl FromStr for TokenScope {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "deploy" => Ok(TokenScope::Deploy),
+            "manage-origins" => Ok(TokenScope::ManageOrigins),
+            "read" => Ok(TokenScope::Read),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use super::{error::ServiceError, models::TokenScope};
+use chrono::{DateTime, Utc};
+use entity::prelude::*;
+use sea_orm::{prelude::*, QueryOrder, Set, TransactionTrait};
+use sha2::{Digest, Sha256};
+use std::fmt::Debug;
+
+pub struct Service;
+
+impl Service {
+    /// Personal API tokens are told apart from JWTs by this prefix
+    pub const PREFIX: &'static str = "sero_";
+
+    /// Issues a new token and returns it with the only copy of its secret.
+    /// Only hash of the secret is stored.
+    #[tracing::instrument(skip(connection))]
+    pub async fn issue_for<C, N>(
+        owner_id: i64,
+        name: N,
+        scopes: &[TokenScope],
+        subdomain: Option<&str>,
+        expires_at: Option<DateTime<Utc>>,
+        connection: &C,
+    ) -> Result<(ApiTokenModel, String), ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        N: AsRef<str> + Debug,
+    {
+        if scopes.is_empty() {
+            return Err(ServiceError::NoScopesWereGranted);
+        }
+
+        let subdomain_id = match subdomain {
+            Some(name) => {
+                let subdomain = SubdomainEntity::find()
+                    .filter(SubdomainColumn::Name.eq(name))
+                    .one(connection)
+                    .await?
+                    .ok_or_else(|| ServiceError::SubdomainWasNotFound(name.to_owned()))?;
+
+                match subdomain.owner_id == owner_id {
+                    true => Some(subdomain.id),
+                    false => return Err(ServiceError::SubdomainIsOwnedByAnotherUser(name.to_owned())),
+                }
+            }
+            None => None,
+        };
+
+        //? Secret is as random as share secrets of sites
+        let token = format!("{}{}{}", Self::PREFIX, Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+        let mut granted = scopes.iter().map(TokenScope::as_str).collect::<Vec<_>>();
+        granted.sort_unstable();
+        granted.dedup();
+
+        let token_to_be_inserted = ApiTokenActiveModel {
+            owner_id: Set(owner_id),
+            name: Set(name.as_ref().to_owned()),
+            token_hash: Set(Self::hash(&token)),
+            scopes: Set(granted.join(",")),
+            subdomain_id: Set(subdomain_id),
+            expires_at: Set(expires_at.map(Into::into)),
+            ..Default::default()
+        };
+
+        let issued = ApiTokenEntity::insert(token_to_be_inserted)
+            .exec_with_returning(connection)
+            .await?;
+
+        Ok((issued, token))
+    }
+
+    /// Tokens of the user in order of creation with names of restricted sites
+    #[tracing::instrument(skip(connection))]
+    pub async fn retrieve_tokens_for<C>(
+        owner_id: i64,
+        connection: &C,
+    ) -> Result<Vec<(ApiTokenModel, Option<SubdomainModel>)>, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        Ok(ApiTokenEntity::find()
+            .find_also_related(SubdomainEntity)
+            .filter(ApiTokenColumn::OwnerId.eq(owner_id))
+            .order_by_asc(ApiTokenColumn::Id)
+            .all(connection)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(connection))]
+    pub async fn revoke_token_of<C>(owner_id: i64, token_id: i64, connection: &C) -> Result<u64, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let token = ApiTokenEntity::find_by_id(token_id)
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::TokenWasNotFound(token_id))?;
+
+        if token.owner_id != owner_id {
+            return Err(ServiceError::TokenDoesNotBelongToUser(token_id, owner_id));
+        }
+
+        Ok(ApiTokenEntity::delete_by_id(token_id)
+            .exec(connection)
+            .await?
+            .rows_affected)
+    }
+
+    /// Finds token by its secret and marks it as used
+    #[tracing::instrument(skip(token, connection))]
+    pub async fn authenticate<C, T>(token: T, connection: &C) -> Result<ApiTokenModel, ServiceError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        T: AsRef<str>,
+    {
+        let now = Utc::now();
+
+        let token = ApiTokenEntity::find()
+            .filter(ApiTokenColumn::TokenHash.eq(Self::hash(token)))
+            .one(connection)
+            .await?
+            .ok_or(ServiceError::TokenIsInvalid)?;
+
+        if token
+            .expires_at
+            .is_some_and(|expires_at| DateTime::<Utc>::from(expires_at) <= now)
+        {
+            return Err(ServiceError::TokenHasExpired);
+        }
+
+        let mut active_token: ApiTokenActiveModel = token.into();
+        active_token.last_used_at = Set(Some(now.into()));
+
+        Ok(active_token.update(connection).await?)
+    }
+
+    /// Scopes granted to the token. Unknown scopes are ignored
+    pub fn scopes_of(token: &ApiTokenModel) -> Vec<TokenScope> {
+        token.scopes.split(',').filter_map(|scope| scope.parse().ok()).collect()
+    }
+
+    //? Tokens carry enough entropy so a fast hash is fine
+    fn hash<T>(token: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        format!("{:x}", Sha256::digest(token.as_ref().as_bytes()))
+    }
+}
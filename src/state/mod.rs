@@ -1,17 +1,33 @@
-use crate::Configuration;
+use crate::{
+    limits::limiter::RateLimiter,
+    services::{acme::challenges::Challenges, auth::keys::JwtKeys, domain::challenge::Challenger},
+    tls::resolver::CertificateResolver,
+    Configuration,
+};
 use sea_orm::prelude::*;
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct State {
     connection: DatabaseConnection,
     configuration: Configuration,
+    challenger: Challenger,
+    certificates: Arc<CertificateResolver>,
+    challenges: Challenges,
+    limiter: RateLimiter,
+    keys: JwtKeys,
 }
 
 impl State {
-    pub fn new(connection: DatabaseConnection, configuration: Configuration) -> Self {
+    pub fn new(connection: DatabaseConnection, configuration: Configuration, keys: JwtKeys) -> Self {
         Self {
             connection,
             configuration,
+            challenger: Challenger::new(),
+            certificates: Arc::default(),
+            challenges: Challenges::default(),
+            limiter: RateLimiter::default(),
+            keys,
         }
     }
 
@@ -22,4 +38,28 @@ impl State {
     pub fn configuration(&self) -> &Configuration {
         &self.configuration
     }
+
+    pub fn challenger(&self) -> &Challenger {
+        &self.challenger
+    }
+
+    /// Certificates served by TLS listener
+    pub fn certificates(&self) -> &Arc<CertificateResolver> {
+        &self.certificates
+    }
+
+    /// Pending ACME challenges answered by the router
+    pub fn challenges(&self) -> &Challenges {
+        &self.challenges
+    }
+
+    /// Token buckets of clients
+    pub fn limiter(&self) -> &RateLimiter {
+        &self.limiter
+    }
+
+    /// Keys JWTs are signed and verified with
+    pub fn keys(&self) -> &JwtKeys {
+        &self.keys
+    }
 }
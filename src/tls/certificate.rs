@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use std::{path::Path, sync::Arc};
+use tokio_rustls::rustls::{crypto::ring::sign::any_supported_type, sign::CertifiedKey};
+use x509_parser::{extensions::GeneralName, parse_x509_certificate};
+
+#[derive(thiserror::Error, Debug)]
+pub enum CertificateError {
+    #[error("Certificate chain does not contain any certificate!")]
+    EmptyChain,
+    #[error("Private key was not found!")]
+    MissingPrivateKey,
+    #[error("Failed to read PEM: {0}")]
+    PemError(#[from] std::io::Error),
+    #[error("Failed to parse certificate: {0}")]
+    X509Error(String),
+    #[error("Private key is not supported: {0}")]
+    UnsupportedPrivateKey(#[from] tokio_rustls::rustls::Error),
+}
+
+/// Certificate chain with its private key ready to be served
+#[derive(Debug, Clone)]
+pub struct ParsedCertificate {
+    pub key: Arc<CertifiedKey>,
+    //* DNS names from subject alternative name extension
+    pub names: Vec<String>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl ParsedCertificate {
+    /// Checks if certificate is valid for the domain
+    /// Wildcard name covers exactly one leftmost label
+    pub fn covers<T>(&self, domain: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        let domain = domain.as_ref();
+        let parent = domain.split_once('.').map(|(_, parent)| parent);
+
+        self.names.iter().any(|name| match name.strip_prefix("*.") {
+            Some(wildcard) => parent == Some(wildcard),
+            None => name == domain,
+        })
+    }
+}
+
+/// Parses PEM encoded chain (leaf first) and private key
+pub fn parse<C, K>(chain: C, private_key: K) -> Result<ParsedCertificate, CertificateError>
+where
+    C: AsRef<[u8]>,
+    K: AsRef<[u8]>,
+{
+    let chain = rustls_pemfile::certs(&mut chain.as_ref()).collect::<Result<Vec<_>, _>>()?;
+    let leaf = chain.first().ok_or(CertificateError::EmptyChain)?;
+
+    let (_, certificate) =
+        parse_x509_certificate(leaf.as_ref()).map_err(|cause| CertificateError::X509Error(cause.to_string()))?;
+
+    let not_after = DateTime::from_timestamp(certificate.validity().not_after.timestamp(), 0).unwrap_or_default();
+
+    let names = certificate
+        .subject_alternative_name()
+        .map_err(|cause| CertificateError::X509Error(cause.to_string()))?
+        .map(|extension| {
+            extension
+                .value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(name) => Some(name.to_ascii_lowercase()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let private_key =
+        rustls_pemfile::private_key(&mut private_key.as_ref())?.ok_or(CertificateError::MissingPrivateKey)?;
+    let signing_key = any_supported_type(&private_key)?;
+
+    Ok(ParsedCertificate {
+        key: Arc::new(CertifiedKey::new(chain, signing_key)),
+        names,
+        not_after,
+    })
+}
+
+/// Reads PEM chain and private key from files
+pub async fn load<C, K>(chain: C, private_key: K) -> Result<ParsedCertificate, CertificateError>
+where
+    C: AsRef<Path>,
+    K: AsRef<Path>,
+{
+    let chain = tokio::fs::read(chain).await?;
+    let private_key = tokio::fs::read(private_key).await?;
+    parse(chain, private_key)
+}
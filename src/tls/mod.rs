@@ -0,0 +1,3 @@
+pub mod certificate;
+pub mod resolver;
+pub mod server;
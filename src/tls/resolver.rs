@@ -0,0 +1,72 @@
+use super::certificate::{self, CertificateError};
+use crate::Configuration;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tokio_rustls::rustls::{
+    server::{ClientHello, ResolvesServerCert},
+    sign::CertifiedKey,
+};
+
+/// Picks certificate by server name indication.
+///
+/// Certificates can be replaced at any time and are used
+/// for handshakes started after the change. Default certificate
+/// is used if client did not send SNI or no certificate matches it.
+#[derive(Debug, Default)]
+pub struct CertificateResolver {
+    default: RwLock<Option<Arc<CertifiedKey>>>,
+    certificates: RwLock<HashMap<String, Arc<CertifiedKey>>>,
+}
+
+impl CertificateResolver {
+    pub fn set_default(&self, key: Option<Arc<CertifiedKey>>) {
+        if let Ok(mut default) = self.default.write() {
+            *default = key;
+        }
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.default.read().is_ok_and(|default| default.is_some())
+    }
+
+    /// Reads default certificate from configured files.
+    /// Nothing is changed if files are not configured.
+    pub async fn load_default(&self, configuration: &Configuration) -> Result<(), CertificateError> {
+        if let (Some(chain), Some(private_key)) = (configuration.tls_certificate(), configuration.tls_private_key()) {
+            let parsed = certificate::load(chain, private_key).await?;
+            tracing::info!(names = ?parsed.names, not_after = %parsed.not_after, "Default certificate was loaded");
+            self.set_default(Some(parsed.key));
+        }
+        Ok(())
+    }
+
+    pub fn insert<T>(&self, name: T, key: Arc<CertifiedKey>)
+    where
+        T: AsRef<str>,
+    {
+        if let Ok(mut certificates) = self.certificates.write() {
+            certificates.insert(name.as_ref().to_ascii_lowercase(), key);
+        }
+    }
+
+    pub fn remove<T>(&self, name: T)
+    where
+        T: AsRef<str>,
+    {
+        if let Ok(mut certificates) = self.certificates.write() {
+            certificates.remove(&name.as_ref().to_ascii_lowercase());
+        }
+    }
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let by_name = client_hello
+            .server_name()
+            .and_then(|name| self.certificates.read().ok()?.get(&name.to_ascii_lowercase()).cloned());
+
+        by_name.or_else(|| self.default.read().ok()?.clone())
+    }
+}
@@ -0,0 +1,88 @@
+use super::resolver::CertificateResolver;
+use axum::{extract::connect_info::IntoMakeServiceWithConnectInfo, Router};
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto::Builder,
+    service::TowerToHyperService,
+};
+use std::{net::SocketAddr, sync::Arc};
+use tokio::{net::TcpListener, sync::watch, task::JoinSet};
+use tokio_rustls::{rustls::ServerConfig, TlsAcceptor};
+use tower::Service;
+
+/// Accepts TLS connections until shutdown is signaled.
+/// Established connections are shut down gracefully and waited for
+/// so requests which are in flight are not dropped.
+pub async fn serve(
+    listener: TcpListener,
+    resolver: Arc<CertificateResolver>,
+    mut make_service: IntoMakeServiceWithConnectInfo<Router, SocketAddr>,
+    mut shutdown: watch::Receiver<()>,
+) {
+    let mut configuration = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_cert_resolver(resolver);
+    configuration.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    let acceptor = TlsAcceptor::from(Arc::new(configuration));
+
+    let mut connections = JoinSet::new();
+
+    loop {
+        let (stream, peer) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(cause) => {
+                    tracing::warn!(%cause, "Failed to accept tcp connection!");
+                    continue;
+                }
+            },
+            _ = shutdown.changed() => break,
+            //? Finished connections are reaped so the set does not grow
+            Some(_) = connections.join_next() => continue,
+        };
+
+        let service = match make_service.call(peer).await {
+            Ok(service) => service,
+            Err(infallible) => match infallible {},
+        };
+        let acceptor = acceptor.clone();
+        let mut shutdown = shutdown.clone();
+
+        connections.spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(cause) => {
+                    tracing::debug!(%cause, %peer, "TLS handshake failed");
+                    return;
+                }
+            };
+
+            let builder = Builder::new(TokioExecutor::new());
+            let connection =
+                builder.serve_connection_with_upgrades(TokioIo::new(stream), TowerToHyperService::new(service));
+            tokio::pin!(connection);
+
+            //? Connection finishes requests in flight before it is closed
+            let result = tokio::select! {
+                result = connection.as_mut() => result,
+                _ = shutdown.changed() => {
+                    connection.as_mut().graceful_shutdown();
+                    connection.await
+                }
+            };
+
+            if let Err(cause) = result {
+                tracing::debug!(%cause, %peer, "Failed to serve TLS connection");
+            }
+        });
+    }
+
+    drop(listener);
+
+    tracing::info!(
+        amount = connections.len(),
+        "Waiting for TLS connections to be closed..."
+    );
+    while connections.join_next().await.is_some() {}
+}